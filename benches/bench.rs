@@ -2,7 +2,7 @@
 
 extern crate test;
 
-use byte_order::{ByteOrder, NumberReader, NumberWriter};
+use byte_order::{BigEndian, ByteOrder, LittleEndian, NumberReader, NumberWriter};
 use std::io::{repeat, sink};
 use test::{black_box, Bencher};
 
@@ -33,6 +33,26 @@ macro_rules! bench {
                 })
             }
 
+            #[bench]
+            fn read_big_endian_static(b: &mut Bencher) {
+                let mut reader = NumberReader::with_order(BigEndian, repeat(0xFF));
+                b.iter(|| {
+                    for _ in 0..N_ITER {
+                        black_box(reader.$read().unwrap());
+                    }
+                })
+            }
+
+            #[bench]
+            fn read_little_endian_static(b: &mut Bencher) {
+                let mut reader = NumberReader::with_order(LittleEndian, repeat(0xFF));
+                b.iter(|| {
+                    for _ in 0..N_ITER {
+                        black_box(reader.$read().unwrap());
+                    }
+                })
+            }
+
             #[bench]
             fn write_big_endian(b: &mut Bencher) {
                 let mut writer = NumberWriter::with_order(ByteOrder::BE, sink());
@@ -54,6 +74,28 @@ macro_rules! bench {
                     }
                 })
             }
+
+            #[bench]
+            fn write_big_endian_static(b: &mut Bencher) {
+                let mut writer = NumberWriter::with_order(BigEndian, sink());
+                let n = <$ty>::MAX;
+                b.iter(|| {
+                    for _ in 0..N_ITER {
+                        black_box(writer.$write(n).unwrap());
+                    }
+                })
+            }
+
+            #[bench]
+            fn write_little_endian_static(b: &mut Bencher) {
+                let mut writer = NumberWriter::with_order(LittleEndian, sink());
+                let n = <$ty>::MAX;
+                b.iter(|| {
+                    for _ in 0..N_ITER {
+                        black_box(writer.$write(n).unwrap());
+                    }
+                })
+            }
         }
     };
 }
@@ -0,0 +1,222 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{Attribute, Lit, LitByteStr, LitInt, LitStr, Result};
+
+/// A byte order that a field is read and written with instead of the one of
+/// the reader or writer.
+pub(crate) enum Order {
+    Big,
+    Little,
+    Native,
+}
+
+impl Order {
+    fn parse(lit: &LitStr) -> Result<Order> {
+        match lit.value().as_str() {
+            "BE" => Ok(Order::Big),
+            "LE" => Ok(Order::Little),
+            "NE" => Ok(Order::Native),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected one of \"BE\", \"LE\", or \"NE\"",
+            )),
+        }
+    }
+
+    pub(crate) fn to_tokens(&self) -> TokenStream {
+        match self {
+            Order::Big => quote!(::byte_order::BigEndian),
+            Order::Little => quote!(::byte_order::LittleEndian),
+            Order::Native => quote!(::byte_order::NativeEndian::default()),
+        }
+    }
+}
+
+/// The width of a length prefix or of an enum tag.
+#[derive(Clone, Copy)]
+pub(crate) enum Width {
+    U8,
+    U16,
+    U32,
+    U64,
+    Uleb128,
+}
+
+impl Width {
+    fn parse(lit: &LitStr) -> Result<Width> {
+        match lit.value().as_str() {
+            "u8" => Ok(Width::U8),
+            "u16" => Ok(Width::U16),
+            "u32" => Ok(Width::U32),
+            "u64" => Ok(Width::U64),
+            "uleb128" => Ok(Width::Uleb128),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected one of \"u8\", \"u16\", \"u32\", \"u64\", or \"uleb128\"",
+            )),
+        }
+    }
+
+    /// Returns the largest value that can be represented with this width.
+    pub(crate) fn max(self) -> u64 {
+        match self {
+            Width::U8 => u8::MAX.into(),
+            Width::U16 => u16::MAX.into(),
+            Width::U32 => u32::MAX.into(),
+            Width::U64 | Width::Uleb128 => u64::MAX,
+        }
+    }
+
+    /// Returns an expression reading a tag of this width from `__reader` as a
+    /// `u64`.
+    pub(crate) fn read_tag(self) -> TokenStream {
+        match self {
+            Width::U8 => quote!(__reader.read_u8().map(u64::from)),
+            Width::U16 => quote!(__reader.read_u16().map(u64::from)),
+            Width::U32 => quote!(__reader.read_u32().map(u64::from)),
+            Width::U64 => quote!(__reader.read_u64()),
+            Width::Uleb128 => quote!(__reader.read_uleb128_u64()),
+        }
+    }
+
+    /// Returns an expression writing `tag` with this width to `__writer`.
+    pub(crate) fn write_tag(self, tag: u64) -> TokenStream {
+        match self {
+            Width::U8 => {
+                let tag = Literal::u8_suffixed(tag as u8);
+                quote!(__writer.write_u8(#tag))
+            }
+            Width::U16 => {
+                let tag = Literal::u16_suffixed(tag as u16);
+                quote!(__writer.write_u16(#tag))
+            }
+            Width::U32 => {
+                let tag = Literal::u32_suffixed(tag as u32);
+                quote!(__writer.write_u32(#tag))
+            }
+            Width::U64 => {
+                let tag = Literal::u64_suffixed(tag);
+                quote!(__writer.write_u64(#tag))
+            }
+            Width::Uleb128 => {
+                let tag = Literal::u64_suffixed(tag);
+                quote!(__writer.write_uleb128_u64(#tag))
+            }
+        }
+    }
+
+    pub(crate) fn to_length_prefix(self) -> TokenStream {
+        match self {
+            Width::U8 => quote!(::byte_order::LengthPrefix::U8),
+            Width::U16 => quote!(::byte_order::LengthPrefix::U16),
+            Width::U32 => quote!(::byte_order::LengthPrefix::U32),
+            Width::U64 => quote!(::byte_order::LengthPrefix::U64),
+            Width::Uleb128 => quote!(::byte_order::LengthPrefix::Uleb128),
+        }
+    }
+}
+
+/// A value which must be present in the data.
+pub(crate) enum Magic {
+    Bytes(LitByteStr),
+    Int(LitInt),
+}
+
+impl Magic {
+    fn parse(lit: Lit) -> Result<Magic> {
+        match lit {
+            Lit::ByteStr(lit) => Ok(Magic::Bytes(lit)),
+            Lit::Int(lit) => Ok(Magic::Int(lit)),
+            lit => Err(syn::Error::new(
+                lit.span(),
+                "expected a byte string or an integer literal",
+            )),
+        }
+    }
+}
+
+/// The attributes of a struct or enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub(crate) magic: Option<Magic>,
+    pub(crate) tag: Option<Width>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<ContainerAttrs> {
+        let mut out = ContainerAttrs::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("byte_order"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("magic") {
+                    let magic = Magic::parse(meta.value()?.parse()?)?;
+                    if let Magic::Int(lit) = &magic {
+                        if lit.suffix().is_empty() {
+                            return Err(meta.error(
+                                "an integer magic on a struct or enum needs a type suffix, such as `0xCAFE_u16`",
+                            ));
+                        }
+                    }
+                    out.magic = Some(magic);
+                } else if meta.path.is_ident("tag") {
+                    out.tag = Some(Width::parse(&meta.value()?.parse()?)?);
+                } else {
+                    return Err(meta.error("unknown byte_order attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+/// The attributes of a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub(crate) order: Option<Order>,
+    pub(crate) length_prefix: Option<Width>,
+    pub(crate) pad_before: Option<LitInt>,
+    pub(crate) pad_after: Option<LitInt>,
+    pub(crate) magic: Option<Magic>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> Result<FieldAttrs> {
+        let mut out = FieldAttrs::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("byte_order"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    out.order = Some(Order::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("length_prefix") {
+                    out.length_prefix = Some(Width::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("pad_before") {
+                    out.pad_before = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("pad_after") {
+                    out.pad_after = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("magic") {
+                    out.magic = Some(Magic::parse(meta.value()?.parse()?)?);
+                } else {
+                    return Err(meta.error("unknown byte_order attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+/// Rejects `byte_order` attributes in places where none are supported.
+pub(crate) fn reject(attrs: &[Attribute]) -> Result<()> {
+    match attrs.iter().find(|attr| attr.path().is_ident("byte_order")) {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            "byte_order attributes are not supported on enum variants",
+        )),
+        None => Ok(()),
+    }
+}
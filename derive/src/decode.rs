@@ -0,0 +1,163 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Result};
+
+use crate::attr::{ContainerAttrs, Magic, Width};
+use crate::{collect_fields, variant_tags, Field};
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let name = &input.ident;
+    let path = name.to_string();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if attrs.tag.is_some() {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "the tag attribute is only supported on enums",
+                ));
+            }
+            let fields = collect_fields(&data.fields, name, None)?;
+            decode_fields(quote!(#name), &data.fields, &fields)
+        }
+        Data::Enum(data) => {
+            let width = attrs.tag.unwrap_or(Width::U32);
+            let tags = variant_tags(&data.variants, width)?;
+            let read_tag = width.read_tag();
+            let mut arms = Vec::new();
+            for (variant, tag) in data.variants.iter().zip(tags) {
+                let ident = &variant.ident;
+                let fields = collect_fields(&variant.fields, name, Some(ident))?;
+                let body = decode_fields(quote!(#name::#ident), &variant.fields, &fields);
+                arms.push(quote!(#tag => { #body }));
+            }
+            quote! {
+                let __tag = #read_tag.map_err(|e| ::byte_order::derive::field_error(e, #path))?;
+                match __tag {
+                    #(#arms)*
+                    __tag => ::byte_order::derive::Err(::byte_order::derive::field_error(
+                        ::byte_order::derive::tag_error(__tag),
+                        #path,
+                    )),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Decode cannot be derived for unions",
+            ))
+        }
+    };
+
+    let magic = attrs.magic.as_ref().map(|magic| {
+        let check = match magic {
+            Magic::Bytes(lit) => {
+                quote!(::byte_order::derive::decode_magic_bytes(__reader, #lit))
+            }
+            Magic::Int(lit) => quote!(::byte_order::derive::decode_magic(__reader, #lit)),
+        };
+        quote! {
+            #check.map_err(|e| ::byte_order::derive::field_error(e, #path))?;
+        }
+    });
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::byte_order::Decode));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::byte_order::Decode for #name #ty_generics #where_clause {
+            fn decode<__R: ::byte_order::derive::Read, __O: ::byte_order::Endianness>(
+                __reader: &mut ::byte_order::NumberReader<__R, __O>,
+            ) -> ::byte_order::derive::Result<Self> {
+                #magic
+                #body
+            }
+        }
+    })
+}
+
+/// Generates the code reading each of `fields` in turn, and then constructing
+/// `ctor` from them.
+fn decode_fields(ctor: TokenStream, shape: &Fields, fields: &[Field]) -> TokenStream {
+    let reads = fields.iter().map(decode_field);
+    let bindings = fields.iter().map(|field| &field.binding);
+    let value = match shape {
+        Fields::Named(_) => {
+            let members = fields.iter().map(|field| &field.member);
+            quote!(#ctor { #(#members: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#ctor(#(#bindings),*)),
+        Fields::Unit => quote!(#ctor),
+    };
+    quote! {
+        #(#reads)*
+        ::byte_order::derive::Ok(#value)
+    }
+}
+
+fn decode_field(field: &Field) -> TokenStream {
+    let Field {
+        binding,
+        ty,
+        name,
+        path,
+        attrs,
+        ..
+    } = field;
+
+    let reader = match &attrs.order {
+        Some(order) => {
+            let order = order.to_tokens();
+            quote!(&mut __reader.by_order(#order))
+        }
+        None => quote!(__reader),
+    };
+    let value = match attrs.length_prefix {
+        Some(width) => {
+            let length_prefix = width.to_length_prefix();
+            quote!(::byte_order::derive::decode_with_prefix::<#ty, _, _>(#reader, #length_prefix)?)
+        }
+        None => quote!(<#ty as ::byte_order::Decode>::decode(#reader)?),
+    };
+    let pad_before = attrs
+        .pad_before
+        .as_ref()
+        .map(|len| quote!(::byte_order::derive::skip(__reader, #len)?;));
+    let pad_after = attrs
+        .pad_after
+        .as_ref()
+        .map(|len| quote!(::byte_order::derive::skip(__reader, #len)?;));
+    let magic = attrs.magic.as_ref().map(|magic| {
+        let (expected, found) = match magic {
+            Magic::Bytes(lit) => (quote!(#lit[..]), quote!(__value[..])),
+            Magic::Int(lit) => (quote!(#lit), quote!(__value)),
+        };
+        quote! {
+            if #found != #expected {
+                return ::byte_order::derive::Err(
+                    ::byte_order::derive::magic_error(&#expected, &#found),
+                );
+            }
+        }
+    });
+
+    quote! {
+        let #binding = ::byte_order::derive::decode_field(
+            __reader,
+            #name,
+            #path,
+            |__reader| -> ::byte_order::derive::Result<#ty> {
+                #pad_before
+                let __value = #value;
+                #magic
+                #pad_after
+                ::byte_order::derive::Ok(__value)
+            },
+        )?;
+    }
+}
@@ -0,0 +1,163 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Fields, Result};
+
+use crate::attr::{ContainerAttrs, Magic, Width};
+use crate::{collect_fields, variant_tags, Field};
+
+pub(crate) fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+    let name = &input.ident;
+    let path = name.to_string();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if attrs.tag.is_some() {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "the tag attribute is only supported on enums",
+                ));
+            }
+            let fields = collect_fields(&data.fields, name, None)?;
+            let pattern = bind_fields(quote!(#name), &data.fields, &fields);
+            let writes = fields.iter().map(encode_field);
+            quote! {
+                let #pattern = self;
+                #(#writes)*
+                ::byte_order::derive::Ok(())
+            }
+        }
+        Data::Enum(data) => {
+            let width = attrs.tag.unwrap_or(Width::U32);
+            let tags = variant_tags(&data.variants, width)?;
+            let mut arms = Vec::new();
+            for (variant, tag) in data.variants.iter().zip(tags) {
+                let ident = &variant.ident;
+                let fields = collect_fields(&variant.fields, name, Some(ident))?;
+                let pattern = bind_fields(quote!(#name::#ident), &variant.fields, &fields);
+                let write_tag = width.write_tag(tag);
+                let writes = fields.iter().map(encode_field);
+                arms.push(quote! {
+                    #pattern => {
+                        #write_tag.map_err(|e| ::byte_order::derive::field_error(e, #path))?;
+                        #(#writes)*
+                    }
+                });
+            }
+            if arms.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                    ::byte_order::derive::Ok(())
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Encode cannot be derived for unions",
+            ))
+        }
+    };
+
+    let magic = attrs.magic.as_ref().map(|magic| {
+        let write = match magic {
+            Magic::Bytes(lit) => quote!(::byte_order::derive::Write::write_all(__writer, #lit)),
+            Magic::Int(lit) => quote!(__writer.encode(&#lit)),
+        };
+        quote! {
+            #write.map_err(|e| ::byte_order::derive::field_error(e, #path))?;
+        }
+    });
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::byte_order::Encode));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::byte_order::Encode for #name #ty_generics #where_clause {
+            fn encode<__W: ::byte_order::derive::Write, __O: ::byte_order::Endianness>(
+                &self,
+                __writer: &mut ::byte_order::NumberWriter<__W, __O>,
+            ) -> ::byte_order::derive::Result<()> {
+                #magic
+                #body
+            }
+        }
+    })
+}
+
+/// Generates a pattern destructuring `ctor`, binding each of `fields` to its
+/// local variable.
+fn bind_fields(ctor: TokenStream, shape: &Fields, fields: &[Field]) -> TokenStream {
+    let bindings = fields.iter().map(|field| &field.binding);
+    match shape {
+        Fields::Named(_) => {
+            let members = fields.iter().map(|field| &field.member);
+            quote!(#ctor { #(#members: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#ctor(#(#bindings),*)),
+        Fields::Unit => quote!(#ctor),
+    }
+}
+
+fn encode_field(field: &Field) -> TokenStream {
+    let Field {
+        binding,
+        path,
+        attrs,
+        ..
+    } = field;
+
+    let writer = match &attrs.order {
+        Some(order) => {
+            let order = order.to_tokens();
+            quote!(&mut __writer.by_order(#order))
+        }
+        None => quote!(__writer),
+    };
+    let write = match attrs.length_prefix {
+        Some(width) => {
+            let length_prefix = width.to_length_prefix();
+            quote!(::byte_order::derive::encode_with_prefix(#writer, #length_prefix, #binding)?;)
+        }
+        None => quote!(::byte_order::Encode::encode(#binding, #writer)?;),
+    };
+    let pad_before = attrs
+        .pad_before
+        .as_ref()
+        .map(|len| quote!(::byte_order::derive::pad(__writer, #len)?;));
+    let pad_after = attrs
+        .pad_after
+        .as_ref()
+        .map(|len| quote!(::byte_order::derive::pad(__writer, #len)?;));
+    let magic = attrs.magic.as_ref().map(|magic| {
+        let (expected, found) = match magic {
+            Magic::Bytes(lit) => (quote!(#lit[..]), quote!(#binding[..])),
+            Magic::Int(lit) => (quote!(#lit), quote!(*#binding)),
+        };
+        quote! {
+            if #found != #expected {
+                return ::byte_order::derive::Err(
+                    ::byte_order::derive::magic_input_error(&#expected, &#found),
+                );
+            }
+        }
+    });
+
+    quote! {
+        (|| -> ::byte_order::derive::Result<()> {
+            #magic
+            #pad_before
+            #write
+            #pad_after
+            ::byte_order::derive::Ok(())
+        })()
+        .map_err(|e| ::byte_order::derive::field_error(e, #path))?;
+    }
+}
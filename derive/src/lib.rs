@@ -0,0 +1,223 @@
+//! Derive macros for the `Decode` and `Encode` traits of the `byte_order`
+//! crate.
+//!
+//! These macros are re-exported by `byte_order` when its `derive` feature is
+//! enabled, and should be used through that crate rather than this one.
+
+extern crate proc_macro;
+
+mod attr;
+mod decode;
+mod encode;
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::format_ident;
+use syn::{parse_macro_input, DeriveInput, Expr, Fields, Lit, Member, Result, Type, Variant};
+
+use crate::attr::{FieldAttrs, Width};
+
+/// Derives `Decode` for a struct or enum by reading each of its fields in
+/// order.
+///
+/// # Attributes
+///
+/// On a struct or enum:
+///
+/// - `#[byte_order(magic = b"...")]` or `#[byte_order(magic = 0xCAFE_u16)]`
+///   reads the given bytes or number before any field, failing if they do not
+///   match.
+/// - `#[byte_order(tag = "u8")]` sets the width of the tag that identifies
+///   the variant of an enum. It may be one of `"u8"`, `"u16"`, `"u32"`,
+///   `"u64"`, or `"uleb128"`, and is `"u32"` by default. The tag of a variant
+///   is its discriminant, which may be given explicitly.
+///
+/// On a field:
+///
+/// - `#[byte_order(order = "BE")]` reads the field with the given byte order,
+///   one of `"BE"`, `"LE"`, or `"NE"`, instead of the one of the reader.
+/// - `#[byte_order(length_prefix = "u16")]` reads the field with the given
+///   length prefix instead of the one of the reader.
+/// - `#[byte_order(pad_before = 2)]` and `#[byte_order(pad_after = 2)]` skip
+///   the given number of bytes before or after the field.
+/// - `#[byte_order(magic = 1)]` fails if the field does not have the given
+///   value once read.
+///
+/// Every error returned by the derived implementation names the struct or
+/// enum and the field that could not be read, such as `Header.len`. When the
+/// reader has error context enabled, each field is instead read within
+/// `NumberReader::with_field`, so the `DecodeError` of a failed read gives the
+/// offset and type of the number that could not be read, and the names of the
+/// fields that contain it, such as `len`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::{ByteOrder, Decode, NumberReader};
+///
+/// #[derive(Debug, Decode, PartialEq)]
+/// #[byte_order(magic = b"HDR")]
+/// struct Header {
+///     #[byte_order(pad_after = 1)]
+///     version: u8,
+///     #[byte_order(order = "LE")]
+///     flags: u16,
+///     #[byte_order(length_prefix = "u8")]
+///     name: String,
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let src = Cursor::new(vec![b'H', b'D', b'R', 0x01, 0x00, 0x34, 0x12, 0x02, b'h', b'i']);
+///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+///
+///     let header: Header = reader.decode()?;
+///     assert_eq!(
+///         Header {
+///             version: 1,
+///             flags: 0x1234,
+///             name: String::from("hi"),
+///         },
+///         header
+///     );
+///
+///     let src = Cursor::new(vec![b'H', b'D', b'R', 0x01, 0x00, 0x34]);
+///     let err = NumberReader::new(src).decode::<Header>().unwrap_err();
+///     assert!(err.to_string().starts_with("Header.flags"));
+///
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_derive(Decode, attributes(byte_order))]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    decode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Encode` for a struct or enum by writing each of its fields in
+/// order.
+///
+/// This macro accepts the same attributes as the `Decode` derive macro, and
+/// writes data that the derived `Decode` implementation reads back. Padding
+/// is written as zero bytes, and the magic of a struct or enum is written
+/// before its first field. A field with a magic is written as it is, and
+/// fails with an error of kind `InvalidInput` if it does not have the given
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, Encode, NumberWriter};
+///
+/// #[derive(Encode)]
+/// #[byte_order(tag = "u8")]
+/// enum Shape {
+///     Point,
+///     Circle { radius: u16 },
+///     Rect(u8, u8),
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+///     writer.encode(&Shape::Point)?;
+///     writer.encode(&Shape::Circle { radius: 0x1234 })?;
+///     writer.encode(&Shape::Rect(2, 3))?;
+///     assert_eq!(
+///         writer.into_inner(),
+///         vec![0x00, 0x01, 0x12, 0x34, 0x02, 0x02, 0x03]
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_derive(Encode, attributes(byte_order))]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    encode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of a struct or enum variant.
+struct Field<'a> {
+    /// How the field is accessed on a value, such as `len` or `0`.
+    member: Member,
+    /// The name of the local variable the field is bound to.
+    binding: Ident,
+    ty: &'a Type,
+    /// The name of the field within a value of its container, such as `len`
+    /// or, in a variant of an enum, `Circle.radius`.
+    name: String,
+    /// The path of the field within its container, such as `Header.len`.
+    path: String,
+    attrs: FieldAttrs,
+}
+
+fn collect_fields<'a>(
+    fields: &'a Fields,
+    container: &Ident,
+    variant: Option<&Ident>,
+) -> Result<Vec<Field<'a>>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (member, name) = match &field.ident {
+                Some(ident) => (Member::Named(ident.clone()), ident.to_string()),
+                None => (Member::Unnamed(i.into()), i.to_string()),
+            };
+            let (name, path) = match variant {
+                Some(variant) => (
+                    format!("{}.{}", variant, name),
+                    format!("{}::{}.{}", container, variant, name),
+                ),
+                None => (name.clone(), format!("{}.{}", container, name)),
+            };
+            Ok(Field {
+                member,
+                binding: format_ident!("__field{}", i),
+                ty: &field.ty,
+                name,
+                path,
+                attrs: FieldAttrs::parse(&field.attrs)?,
+            })
+        })
+        .collect()
+}
+
+/// Computes the tag of each variant of an enum, which is its discriminant.
+fn variant_tags<'a, I>(variants: I, width: Width) -> Result<Vec<u64>>
+where
+    I: IntoIterator<Item = &'a Variant>,
+{
+    let mut next = 0u64;
+    let mut tags = Vec::new();
+    for variant in variants {
+        attr::reject(&variant.attrs)?;
+        let tag = match &variant.discriminant {
+            Some((_, Expr::Lit(expr))) => match &expr.lit {
+                Lit::Int(lit) => lit.base10_parse::<u64>()?,
+                lit => return Err(syn::Error::new_spanned(lit, "expected an integer")),
+            },
+            Some((_, expr)) => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "discriminants of derived enums must be integer literals",
+                ))
+            }
+            None => next,
+        };
+        if tag > width.max() {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "discriminant does not fit in the width of the tag",
+            ));
+        }
+        tags.push(tag);
+        next = tag.wrapping_add(1);
+    }
+    Ok(tags)
+}
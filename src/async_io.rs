@@ -0,0 +1,950 @@
+//! The machinery shared by the asynchronous readers and writers.
+//!
+//! Each async runtime has its own `AsyncRead` and `AsyncWrite` traits, so the
+//! readers and writers are generated once per runtime by [`async_reader`] and
+//! [`async_writer`]. The module which invokes these macros must define the
+//! functions `poll_read_some` and `poll_write_some`, which adapt the traits of
+//! its runtime to plain byte slices.
+//!
+//! Numbers are read into, and written from, a small buffer which outlives the
+//! future of each operation. This is what makes the operations on single
+//! numbers cancel safe: the bytes of a number that was only partially read or
+//! written when its future was dropped are still in the buffer for the next
+//! operation to pick up.
+
+use core::mem;
+
+use crate::io;
+
+use crate::number::Number;
+use crate::order::ByteOrder;
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::read::NumberReader;
+use crate::write::NumberWriter;
+
+/// The size of the buffers that hold the bytes of a number while it is read or
+/// written, which is enough for any single number.
+pub(crate) const BUF_LEN: usize = 64;
+
+/// The bytes an asynchronous reader has read from its source which have not
+/// been consumed by a completed read yet.
+pub(crate) struct ReadBuffer {
+    bytes: [u8; BUF_LEN],
+    filled: usize,
+}
+
+impl ReadBuffer {
+    #[inline]
+    pub(crate) fn new() -> ReadBuffer {
+        ReadBuffer {
+            bytes: [0; BUF_LEN],
+            filled: 0,
+        }
+    }
+
+    /// Returns the number of bytes which are buffered.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns `true` if no bytes are buffered.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Returns the bytes which are buffered.
+    #[inline]
+    pub(crate) fn filled(&self) -> &[u8] {
+        &self.bytes[..self.filled]
+    }
+
+    /// Returns the space after the buffered bytes, up to a total of `len`
+    /// bytes.
+    #[inline]
+    pub(crate) fn unfilled(&mut self, len: usize) -> &mut [u8] {
+        &mut self.bytes[self.filled..len]
+    }
+
+    /// Marks `n` more bytes as buffered.
+    #[inline]
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.filled += n;
+    }
+
+    /// Removes the first `n` buffered bytes.
+    #[inline]
+    pub(crate) fn consume(&mut self, n: usize) {
+        self.bytes.copy_within(n..self.filled, 0);
+        self.filled -= n;
+    }
+
+    /// Moves as many buffered bytes as fit to the front of `dst`, returning
+    /// how many were moved.
+    #[inline]
+    pub(crate) fn read_buffered(&mut self, dst: &mut [u8]) -> usize {
+        let n = self.filled.min(dst.len());
+        dst[..n].copy_from_slice(&self.bytes[..n]);
+        self.consume(n);
+        n
+    }
+
+    /// Consumes the first `len` buffered bytes, which must all be filled, by
+    /// reading them with `f`.
+    #[inline]
+    pub(crate) fn read_with<T, F>(&mut self, len: usize, order: ByteOrder, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut NumberReader<&[u8]>) -> io::Result<T>,
+    {
+        let result = f(&mut NumberReader::with_order(order, &self.bytes[..len]));
+        self.consume(len);
+        result
+    }
+}
+
+/// The bytes an asynchronous writer has yet to write to its sink.
+pub(crate) struct WriteBuffer {
+    bytes: [u8; BUF_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl WriteBuffer {
+    #[inline]
+    pub(crate) fn new() -> WriteBuffer {
+        WriteBuffer {
+            bytes: [0; BUF_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns `true` if every staged byte has been written.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pos == self.len
+    }
+
+    /// Returns the staged bytes which have not been written yet.
+    #[inline]
+    pub(crate) fn pending(&self) -> &[u8] {
+        &self.bytes[self.pos..self.len]
+    }
+
+    /// Marks `n` more of the staged bytes as written.
+    #[inline]
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.pos += n;
+    }
+
+    /// Stages the bytes that `f` writes, replacing any bytes which have
+    /// already been written.
+    ///
+    /// If `f` fails, nothing is staged.
+    #[inline]
+    pub(crate) fn stage<F>(
+        &mut self,
+        order: ByteOrder,
+        length_prefix: LengthPrefix,
+        f: F,
+    ) -> io::Result<()>
+    where
+        F: FnOnce(&mut NumberWriter<&mut [u8]>) -> io::Result<()>,
+    {
+        debug_assert!(self.is_empty());
+        let mut writer = NumberWriter::with_order(order, &mut self.bytes[..]);
+        writer.set_length_prefix(length_prefix);
+        f(&mut writer)?;
+        let unused = writer.into_inner().len();
+        self.pos = 0;
+        self.len = BUF_LEN - unused;
+        Ok(())
+    }
+}
+
+/// Reverses the byte order of each number in `dst` if `order` is not the
+/// native byte order.
+#[inline]
+pub(crate) fn swap_in_place<T: Number>(dst: &mut [T], order: ByteOrder) {
+    if order != ByteOrder::NE {
+        for n in dst {
+            *n = n.swap_bytes();
+        }
+    }
+}
+
+/// Returns the bytes of `dst` which hold the 16-bit floating point numbers
+/// that are widened into it.
+#[inline]
+pub(crate) fn half_bytes(dst: &mut [f32]) -> &mut [u8] {
+    let len = dst.len();
+    &mut pod::as_bytes_mut(dst)[..2 * len]
+}
+
+/// Returns the number of numbers of type `T` which fit in a [`WriteBuffer`].
+#[inline]
+pub(crate) const fn chunk_len<T>() -> usize {
+    BUF_LEN / mem::size_of::<T>()
+}
+
+/// Panics with the same message as the synchronous readers if `nbytes` is not
+/// in the range `1..=max`.
+#[inline]
+pub(crate) fn check_nbytes(nbytes: usize, max: usize) {
+    assert!(
+        (1..=max).contains(&nbytes),
+        "nbytes must be in the range 1..={}, but was {}",
+        max,
+        nbytes
+    );
+}
+
+#[inline]
+pub(crate) fn eof() -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")
+}
+
+#[inline]
+pub(crate) fn write_zero() -> io::Error {
+    io::Error::new(io::ErrorKind::WriteZero, "failed to write whole buffer")
+}
+
+macro_rules! read_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the underlying reader.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            pub async fn $name(&mut self) -> $crate::io::Result<$ty> {
+                self.read_number().await
+            }
+        )*
+    };
+}
+
+macro_rules! read_numbers_into {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads a sequence of ", $desc, " from the underlying reader, filling `dst` entirely.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            pub async fn $name(&mut self, dst: &mut [$ty]) -> $crate::io::Result<()> {
+                self.read_into(dst).await
+            }
+        )*
+    };
+}
+
+macro_rules! read_leb128 {
+    ($($name:ident: $ty:ty, $decoder:ident($bits:expr), $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the underlying reader.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            pub async fn $name(&mut self) -> $crate::io::Result<$ty> {
+                let decoder = $crate::leb128::Decoder::$decoder($bits, self.strict);
+                Ok(self.read_leb128(decoder).await? as $ty)
+            }
+        )*
+    };
+}
+
+macro_rules! read_fixed {
+    ($($name:ident: $ty:ty, $len:expr, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the underlying reader.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            pub async fn $name(&mut self) -> $crate::io::Result<$ty> {
+                self.read_buffered($len, |reader| reader.$name()).await
+            }
+        )*
+    };
+}
+
+macro_rules! read_sized {
+    ($($name:ident: $ty:ty, $max:expr, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " that is `nbytes` bytes wide from the underlying reader.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("This method panics if `nbytes` is not in the range `1..=", stringify!($max), "`.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            pub async fn $name(&mut self, nbytes: usize) -> $crate::io::Result<$ty> {
+                $crate::async_io::check_nbytes(nbytes, $max);
+                self.read_buffered(nbytes, |reader| reader.$name(nbytes)).await
+            }
+        )*
+    };
+}
+
+macro_rules! write_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the underlying writer.")]
+            ///
+            #[doc = concat!("See [`NumberWriter::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberWriter::", stringify!($name), "`]: crate::NumberWriter::", stringify!($name))]
+            pub async fn $name(&mut self, n: $ty) -> $crate::io::Result<()> {
+                self.write_number(n).await
+            }
+        )*
+    };
+}
+
+macro_rules! write_numbers_slice {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a sequence of ", $desc, " to the underlying writer.")]
+            ///
+            #[doc = concat!("See [`NumberWriter::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberWriter::", stringify!($name), "`]: crate::NumberWriter::", stringify!($name))]
+            pub async fn $name(&mut self, src: &[$ty]) -> $crate::io::Result<()> {
+                self.write_slice(src).await
+            }
+        )*
+    };
+}
+
+macro_rules! write_staged {
+    ($($name:ident($($arg:ident: $ty:ty),*), $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the underlying writer.")]
+            ///
+            #[doc = concat!("See [`NumberWriter::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberWriter::", stringify!($name), "`]: crate::NumberWriter::", stringify!($name))]
+            pub async fn $name(&mut self, $($arg: $ty),*) -> $crate::io::Result<()> {
+                self.write_staged(|writer| writer.$name($($arg),*)).await
+            }
+        )*
+    };
+}
+
+macro_rules! write_half_slice {
+    ($($name:ident, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a sequence of `f32` values to the underlying writer, narrowing each of them to ", $desc, ".")]
+            ///
+            #[doc = concat!("See [`NumberWriter::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberWriter::", stringify!($name), "`]: crate::NumberWriter::", stringify!($name))]
+            pub async fn $name(&mut self, src: &[f32]) -> $crate::io::Result<()> {
+                for chunk in src.chunks($crate::async_io::chunk_len::<u16>()) {
+                    self.write_staged(|writer| writer.$name(chunk)).await?;
+                }
+                Ok(())
+            }
+        )*
+    };
+}
+
+/// Generates an `AsyncNumberReader` for the `AsyncRead` trait of a runtime.
+macro_rules! async_reader {
+    ($(#[$attr:meta])* $Read:path) => {
+        $(#[$attr])*
+        ///
+        /// # Cancel safety
+        ///
+        /// Every method which reads a single number is cancel safe. If its
+        /// future is dropped before it completes, the bytes it has read so far
+        /// are kept, and the next read starts with them, so no data is lost.
+        ///
+        /// The `read_*_into` methods are not cancel safe, as the bytes they
+        /// have read so far are only held in `dst`.
+        pub struct AsyncNumberReader<R, O: $crate::Endianness = $crate::ByteOrder> {
+            inner: R,
+            order: O,
+            strict: bool,
+            length_prefix: $crate::LengthPrefix,
+            pending: $crate::async_io::ReadBuffer,
+        }
+
+        impl<R: $Read + Unpin> AsyncNumberReader<R> {
+            /// Creates a new `AsyncNumberReader` by wrapping the given reader.
+            ///
+            /// Since the target platform's native endianness is used, portable
+            /// code should use [`with_order`], as appropriate, instead.
+            ///
+            /// [`with_order`]: AsyncNumberReader::with_order
+            #[inline]
+            pub fn new(src: R) -> AsyncNumberReader<R> {
+                AsyncNumberReader::with_order($crate::ByteOrder::NE, src)
+            }
+        }
+
+        impl<R: $Read + Unpin, O: $crate::Endianness> AsyncNumberReader<R, O> {
+            /// Creates a new `AsyncNumberReader` by wrapping the given reader
+            /// with the specified byte order.
+            #[inline]
+            pub fn with_order(order: O, src: R) -> AsyncNumberReader<R, O> {
+                AsyncNumberReader {
+                    inner: src,
+                    order,
+                    strict: false,
+                    length_prefix: $crate::LengthPrefix::default(),
+                    pending: $crate::async_io::ReadBuffer::new(),
+                }
+            }
+
+            /// Consumes this `AsyncNumberReader`, returning the underlying
+            /// value.
+            ///
+            /// Any bytes which were read by a cancelled read, and have not been
+            /// consumed since, are lost.
+            #[inline]
+            pub fn into_inner(self) -> R {
+                self.inner
+            }
+
+            /// Gets a reference to the underlying value in this
+            /// `AsyncNumberReader`.
+            #[inline]
+            pub fn get_ref(&self) -> &R {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying value in this
+            /// `AsyncNumberReader`.
+            ///
+            /// Reading from the underlying value directly skips any bytes
+            /// which were read by a cancelled read.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut R {
+                &mut self.inner
+            }
+
+            /// Returns `true` if this `AsyncNumberReader` rejects non-canonical
+            /// encodings of variable-length integers.
+            #[inline]
+            pub fn is_strict(&self) -> bool {
+                self.strict
+            }
+
+            /// Sets whether this `AsyncNumberReader` rejects non-canonical
+            /// encodings of variable-length integers.
+            ///
+            /// See [`NumberReader::set_strict`] for details.
+            ///
+            /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+            #[inline]
+            pub fn set_strict(&mut self, strict: bool) {
+                self.strict = strict;
+            }
+
+            /// Returns the encoding of the length that prefixes variable-sized
+            /// values read by this `AsyncNumberReader`.
+            #[inline]
+            pub fn length_prefix(&self) -> $crate::LengthPrefix {
+                self.length_prefix
+            }
+
+            /// Sets the encoding of the length that prefixes variable-sized
+            /// values read by this `AsyncNumberReader`.
+            #[inline]
+            pub fn set_length_prefix(&mut self, length_prefix: $crate::LengthPrefix) {
+                self.length_prefix = length_prefix;
+            }
+
+            /// Reads a number of any primitive type from the underlying reader.
+            ///
+            /// See [`NumberReader::read_number`] for details.
+            ///
+            /// [`NumberReader::read_number`]: crate::NumberReader::read_number
+            pub async fn read_number<T: $crate::Number>(&mut self) -> $crate::io::Result<T> {
+                self.read_buffered(::core::mem::size_of::<T>(), |reader| reader.read_number())
+                    .await
+            }
+
+            /// Reads a sequence of numbers of any primitive type from the
+            /// underlying reader, filling `dst` entirely.
+            ///
+            /// See [`NumberReader::read_into`] for details.
+            ///
+            /// [`NumberReader::read_into`]: crate::NumberReader::read_into
+            pub async fn read_into<T: $crate::Number>(
+                &mut self,
+                dst: &mut [T],
+            ) -> $crate::io::Result<()> {
+                self.read_exact($crate::pod::as_bytes_mut(dst)).await?;
+                $crate::async_io::swap_in_place(dst, self.order.byte_order());
+                Ok(())
+            }
+
+            /// Reads the length that prefixes a variable-sized value from the
+            /// underlying reader, using the encoding set by
+            /// [`set_length_prefix`].
+            ///
+            /// See [`NumberReader::read_length`] for details.
+            ///
+            /// [`set_length_prefix`]: AsyncNumberReader::set_length_prefix
+            /// [`NumberReader::read_length`]: crate::NumberReader::read_length
+            pub async fn read_length(&mut self) -> $crate::io::Result<usize> {
+                let len = match self.length_prefix {
+                    $crate::LengthPrefix::U8 => self.read_u8().await?.into(),
+                    $crate::LengthPrefix::U16 => self.read_u16().await?.into(),
+                    $crate::LengthPrefix::U32 => self.read_u32().await?.into(),
+                    $crate::LengthPrefix::U64 => self.read_u64().await?,
+                    $crate::LengthPrefix::Uleb128 => self.read_uleb128_u64().await?,
+                };
+                <usize as ::core::convert::TryFrom<u64>>::try_from(len).map_err(|_| {
+                    $crate::io::Error::new(
+                        $crate::io::ErrorKind::InvalidData,
+                        "length prefix does not fit in usize",
+                    )
+                })
+            }
+
+            $crate::async_io::read_numbers! {
+                read_u8: u8, "an unsigned 8-bit integer";
+                read_i8: i8, "a signed 8-bit integer";
+                read_u16: u16, "an unsigned 16-bit integer";
+                read_i16: i16, "a signed 16-bit integer";
+                read_u32: u32, "an unsigned 32-bit integer";
+                read_i32: i32, "a signed 32-bit integer";
+                read_u64: u64, "an unsigned 64-bit integer";
+                read_i64: i64, "a signed 64-bit integer";
+                read_u128: u128, "an unsigned 128-bit integer";
+                read_i128: i128, "a signed 128-bit integer";
+                read_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+                read_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+            }
+
+            $crate::async_io::read_numbers_into! {
+                read_u16_into: u16, "unsigned 16-bit integers";
+                read_i16_into: i16, "signed 16-bit integers";
+                read_u32_into: u32, "unsigned 32-bit integers";
+                read_i32_into: i32, "signed 32-bit integers";
+                read_u64_into: u64, "unsigned 64-bit integers";
+                read_i64_into: i64, "signed 64-bit integers";
+                read_u128_into: u128, "unsigned 128-bit integers";
+                read_i128_into: i128, "signed 128-bit integers";
+                read_f32_into: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+                read_f64_into: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+            }
+
+            $crate::async_io::read_leb128! {
+                read_uleb128_u32: u32, unsigned(32), "an unsigned 32-bit integer encoded as unsigned LEB128";
+                read_uleb128_u64: u64, unsigned(64), "an unsigned 64-bit integer encoded as unsigned LEB128";
+                read_uleb128_u128: u128, unsigned(128), "an unsigned 128-bit integer encoded as unsigned LEB128";
+                read_sleb128_i32: i32, signed(32), "a signed 32-bit integer encoded as signed LEB128";
+                read_sleb128_i64: i64, signed(64), "a signed 64-bit integer encoded as signed LEB128";
+                read_sleb128_i128: i128, signed(128), "a signed 128-bit integer encoded as signed LEB128";
+            }
+
+            /// Reads a signed 32-bit integer encoded as zigzag unsigned LEB128
+            /// from the underlying reader.
+            ///
+            /// See [`NumberReader::read_zigzag_i32`] for details.
+            ///
+            /// [`NumberReader::read_zigzag_i32`]: crate::NumberReader::read_zigzag_i32
+            pub async fn read_zigzag_i32(&mut self) -> $crate::io::Result<i32> {
+                Ok($crate::zigzag::decode_i32(self.read_uleb128_u32().await?))
+            }
+
+            /// Reads a signed 64-bit integer encoded as zigzag unsigned LEB128
+            /// from the underlying reader.
+            ///
+            /// See [`NumberReader::read_zigzag_i64`] for details.
+            ///
+            /// [`NumberReader::read_zigzag_i64`]: crate::NumberReader::read_zigzag_i64
+            pub async fn read_zigzag_i64(&mut self) -> $crate::io::Result<i64> {
+                Ok($crate::zigzag::decode_i64(self.read_uleb128_u64().await?))
+            }
+
+            /// Reads an unsigned integer encoded with a variable-length
+            /// `scheme` from the underlying reader.
+            ///
+            /// The bytes of the encoding are buffered one at a time until
+            /// `scheme` can decode them, so a cancelled read is picked up by
+            /// the next one.
+            ///
+            /// See [`NumberReader::read_varint`] for details.
+            ///
+            /// [`NumberReader::read_varint`]: crate::NumberReader::read_varint
+            pub async fn read_varint<S: $crate::varint::VarintScheme>(
+                &mut self,
+                scheme: S,
+            ) -> $crate::io::Result<u64> {
+                let mut len = 0;
+                loop {
+                    ::core::future::poll_fn(|cx| self.poll_fill(cx, len + 1)).await?;
+                    len += 1;
+                    let mut src = &self.pending.filled()[..len];
+                    match scheme.decode(&mut src, self.strict) {
+                        Err(ref e)
+                            if e.kind() == $crate::io::ErrorKind::UnexpectedEof
+                                && len < scheme.max_len() => {}
+                        result => {
+                            self.pending.consume(len);
+                            return result;
+                        }
+                    }
+                }
+            }
+
+            $crate::async_io::read_fixed! {
+                read_f16_as_f32: f32, 2, "a half-precision floating point number, widening it to an `f32`,";
+                read_bf16_as_f32: f32, 2, "a brain floating point number (bfloat16), widening it to an `f32`,";
+                read_u24: u32, 3, "an unsigned 24-bit integer";
+                read_i24: i32, 3, "a signed 24-bit integer";
+                read_u48: u64, 6, "an unsigned 48-bit integer";
+                read_i48: i64, 6, "a signed 48-bit integer";
+            }
+
+            /// Reads a sequence of half-precision floating point numbers from
+            /// the underlying reader, widening each of them to an `f32` and
+            /// filling `dst` entirely.
+            ///
+            /// See [`NumberReader::read_f16_as_f32_into`] for details.
+            ///
+            /// [`NumberReader::read_f16_as_f32_into`]: crate::NumberReader::read_f16_as_f32_into
+            pub async fn read_f16_as_f32_into(&mut self, dst: &mut [f32]) -> $crate::io::Result<()> {
+                self.read_half_into(dst, $crate::float::f16_to_f32).await
+            }
+
+            /// Reads a sequence of brain floating point numbers (bfloat16) from
+            /// the underlying reader, widening each of them to an `f32` and
+            /// filling `dst` entirely.
+            ///
+            /// See [`NumberReader::read_bf16_as_f32_into`] for details.
+            ///
+            /// [`NumberReader::read_bf16_as_f32_into`]: crate::NumberReader::read_bf16_as_f32_into
+            pub async fn read_bf16_as_f32_into(&mut self, dst: &mut [f32]) -> $crate::io::Result<()> {
+                self.read_half_into(dst, $crate::float::bf16_to_f32).await
+            }
+
+            $crate::async_io::read_sized! {
+                read_uint: u64, 8, "an unsigned integer";
+                read_int: i64, 8, "a signed integer";
+                read_uint128: u128, 16, "an unsigned 128-bit integer";
+                read_int128: i128, 16, "a signed 128-bit integer";
+            }
+
+            /// Reads `len` bytes, which may include bytes left over by a
+            /// cancelled read, and consumes them with `f`.
+            async fn read_buffered<T, F>(&mut self, len: usize, f: F) -> $crate::io::Result<T>
+            where
+                F: FnOnce(&mut $crate::NumberReader<&[u8]>) -> $crate::io::Result<T>,
+            {
+                ::core::future::poll_fn(|cx| self.poll_fill(cx, len)).await?;
+                self.pending.read_with(len, self.order.byte_order(), f)
+            }
+
+            /// Reads bytes from the underlying reader until `decoder` yields a
+            /// value, keeping them buffered until then.
+            async fn read_leb128(
+                &mut self,
+                mut decoder: $crate::leb128::Decoder,
+            ) -> $crate::io::Result<u128> {
+                let mut len = 0;
+                loop {
+                    ::core::future::poll_fn(|cx| self.poll_fill(cx, len + 1)).await?;
+                    let byte = self.pending.filled()[len];
+                    len += 1;
+                    match decoder.push(byte) {
+                        Ok(None) => {}
+                        Ok(Some(n)) => {
+                            self.pending.consume(len);
+                            return Ok(n);
+                        }
+                        Err(e) => {
+                            self.pending.consume(len);
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+
+            /// Reads 16-bit floating point numbers into the front of `dst`,
+            /// widening them in place with `to_f32`.
+            async fn read_half_into(
+                &mut self,
+                dst: &mut [f32],
+                to_f32: fn(u16) -> f32,
+            ) -> $crate::io::Result<()> {
+                self.read_exact($crate::async_io::half_bytes(dst)).await?;
+                $crate::float::widen_in_place(dst, self.order.byte_order(), to_f32);
+                Ok(())
+            }
+
+            /// Reads the exact number of bytes required to fill `dst`, starting
+            /// with any bytes left over by a cancelled read.
+            async fn read_exact(&mut self, dst: &mut [u8]) -> $crate::io::Result<()> {
+                let n = self.pending.read_buffered(dst);
+                let mut dst = &mut dst[n..];
+                ::core::future::poll_fn(|cx| {
+                    while !dst.is_empty() {
+                        match ::core::task::ready!(poll_read_some(&mut self.inner, cx, dst)) {
+                            Ok(0) => return ::core::task::Poll::Ready(Err($crate::async_io::eof())),
+                            Ok(n) => dst = &mut ::core::mem::take(&mut dst)[n..],
+                            Err(ref e) if e.kind() == $crate::io::ErrorKind::Interrupted => {}
+                            Err(e) => return ::core::task::Poll::Ready(Err(e)),
+                        }
+                    }
+                    ::core::task::Poll::Ready(Ok(()))
+                })
+                .await
+            }
+
+            /// Reads from the underlying reader until at least `len` bytes are
+            /// buffered.
+            fn poll_fill(
+                &mut self,
+                cx: &mut ::core::task::Context<'_>,
+                len: usize,
+            ) -> ::core::task::Poll<$crate::io::Result<()>> {
+                while self.pending.len() < len {
+                    let unfilled = self.pending.unfilled(len);
+                    match ::core::task::ready!(poll_read_some(&mut self.inner, cx, unfilled)) {
+                        Ok(0) => return ::core::task::Poll::Ready(Err($crate::async_io::eof())),
+                        Ok(n) => self.pending.advance(n),
+                        Err(ref e) if e.kind() == $crate::io::ErrorKind::Interrupted => {}
+                        Err(e) => return ::core::task::Poll::Ready(Err(e)),
+                    }
+                }
+                ::core::task::Poll::Ready(Ok(()))
+            }
+        }
+    };
+}
+
+/// Generates an `AsyncNumberWriter` for the `AsyncWrite` trait of a runtime.
+macro_rules! async_writer {
+    ($(#[$attr:meta])* $Write:path) => {
+        $(#[$attr])*
+        ///
+        /// # Cancel safety
+        ///
+        /// Every method which writes a single number is cancel safe, in that
+        /// the underlying writer never ends up with part of a number. Once a
+        /// number has been staged to be written, its remaining bytes are
+        /// written before anything else if its future is dropped, by the next
+        /// write or flush.
+        ///
+        /// The `write_*_slice` methods are not cancel safe, as some of the
+        /// numbers in `src` may have been written when their future is
+        /// dropped.
+        pub struct AsyncNumberWriter<W, O: $crate::Endianness = $crate::ByteOrder> {
+            inner: W,
+            order: O,
+            length_prefix: $crate::LengthPrefix,
+            pending: $crate::async_io::WriteBuffer,
+        }
+
+        impl<W: $Write + Unpin> AsyncNumberWriter<W> {
+            /// Creates a new `AsyncNumberWriter` by wrapping the given writer.
+            ///
+            /// Since the target platform's native endianness is used, portable
+            /// code should use [`with_order`], as appropriate, instead.
+            ///
+            /// [`with_order`]: AsyncNumberWriter::with_order
+            #[inline]
+            pub fn new(dst: W) -> AsyncNumberWriter<W> {
+                AsyncNumberWriter::with_order($crate::ByteOrder::NE, dst)
+            }
+        }
+
+        impl<W: $Write + Unpin, O: $crate::Endianness> AsyncNumberWriter<W, O> {
+            /// Creates a new `AsyncNumberWriter` by wrapping the given writer
+            /// with the specified byte order.
+            #[inline]
+            pub fn with_order(order: O, dst: W) -> AsyncNumberWriter<W, O> {
+                AsyncNumberWriter {
+                    inner: dst,
+                    order,
+                    length_prefix: $crate::LengthPrefix::default(),
+                    pending: $crate::async_io::WriteBuffer::new(),
+                }
+            }
+
+            /// Consumes this `AsyncNumberWriter`, returning the underlying
+            /// value.
+            ///
+            /// Any bytes of a cancelled write which have not been written yet
+            /// are lost. Flushing the writer first ensures there are none.
+            #[inline]
+            pub fn into_inner(self) -> W {
+                self.inner
+            }
+
+            /// Gets a reference to the underlying value in this
+            /// `AsyncNumberWriter`.
+            #[inline]
+            pub fn get_ref(&self) -> &W {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying value in this
+            /// `AsyncNumberWriter`.
+            ///
+            /// Writing to the underlying value directly may interleave with the
+            /// bytes of a cancelled write which have not been written yet.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut W {
+                &mut self.inner
+            }
+
+            /// Returns the encoding of the length that prefixes variable-sized
+            /// values written by this `AsyncNumberWriter`.
+            #[inline]
+            pub fn length_prefix(&self) -> $crate::LengthPrefix {
+                self.length_prefix
+            }
+
+            /// Sets the encoding of the length that prefixes variable-sized
+            /// values written by this `AsyncNumberWriter`.
+            #[inline]
+            pub fn set_length_prefix(&mut self, length_prefix: $crate::LengthPrefix) {
+                self.length_prefix = length_prefix;
+            }
+
+            /// Writes a number of any primitive type to the underlying writer.
+            ///
+            /// See [`NumberWriter::write_number`] for details.
+            ///
+            /// [`NumberWriter::write_number`]: crate::NumberWriter::write_number
+            pub async fn write_number<T: $crate::Number>(&mut self, n: T) -> $crate::io::Result<()> {
+                self.write_staged(|writer| writer.write_number(n)).await
+            }
+
+            /// Writes a sequence of numbers of any primitive type to the
+            /// underlying writer.
+            ///
+            /// See [`NumberWriter::write_slice`] for details.
+            ///
+            /// [`NumberWriter::write_slice`]: crate::NumberWriter::write_slice
+            pub async fn write_slice<T: $crate::Number>(
+                &mut self,
+                src: &[T],
+            ) -> $crate::io::Result<()> {
+                for chunk in src.chunks($crate::async_io::chunk_len::<T>()) {
+                    self.write_staged(|writer| writer.write_slice(chunk)).await?;
+                }
+                Ok(())
+            }
+
+            $crate::async_io::write_staged! {
+                write_length(len: usize), "the length that prefixes a variable-sized value";
+            }
+
+            $crate::async_io::write_numbers! {
+                write_u8: u8, "an unsigned 8-bit integer";
+                write_i8: i8, "a signed 8-bit integer";
+                write_u16: u16, "an unsigned 16-bit integer";
+                write_i16: i16, "a signed 16-bit integer";
+                write_u32: u32, "an unsigned 32-bit integer";
+                write_i32: i32, "a signed 32-bit integer";
+                write_u64: u64, "an unsigned 64-bit integer";
+                write_i64: i64, "a signed 64-bit integer";
+                write_u128: u128, "an unsigned 128-bit integer";
+                write_i128: i128, "a signed 128-bit integer";
+                write_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+                write_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+            }
+
+            $crate::async_io::write_numbers_slice! {
+                write_u16_slice: u16, "unsigned 16-bit integers";
+                write_i16_slice: i16, "signed 16-bit integers";
+                write_u32_slice: u32, "unsigned 32-bit integers";
+                write_i32_slice: i32, "signed 32-bit integers";
+                write_u64_slice: u64, "unsigned 64-bit integers";
+                write_i64_slice: i64, "signed 64-bit integers";
+                write_u128_slice: u128, "unsigned 128-bit integers";
+                write_i128_slice: i128, "signed 128-bit integers";
+                write_f32_slice: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+                write_f64_slice: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+            }
+
+            $crate::async_io::write_staged! {
+                write_uleb128_u32(n: u32), "an unsigned 32-bit integer, encoded as unsigned LEB128,";
+                write_uleb128_u64(n: u64), "an unsigned 64-bit integer, encoded as unsigned LEB128,";
+                write_uleb128_u128(n: u128), "an unsigned 128-bit integer, encoded as unsigned LEB128,";
+                write_sleb128_i32(n: i32), "a signed 32-bit integer, encoded as signed LEB128,";
+                write_sleb128_i64(n: i64), "a signed 64-bit integer, encoded as signed LEB128,";
+                write_sleb128_i128(n: i128), "a signed 128-bit integer, encoded as signed LEB128,";
+                write_zigzag_i32(n: i32), "a signed 32-bit integer, encoded as zigzag unsigned LEB128,";
+                write_zigzag_i64(n: i64), "a signed 64-bit integer, encoded as zigzag unsigned LEB128,";
+                write_f32_as_f16(n: f32), "an `f32`, narrowed to a half-precision floating point number,";
+                write_f32_as_bf16(n: f32), "an `f32`, narrowed to a brain floating point number (bfloat16),";
+                write_uint(n: u64, nbytes: usize), "an unsigned integer using only `nbytes` bytes";
+                write_int(n: i64, nbytes: usize), "a signed integer using only `nbytes` bytes";
+                write_uint128(n: u128, nbytes: usize), "an unsigned 128-bit integer using only `nbytes` bytes";
+                write_int128(n: i128, nbytes: usize), "a signed 128-bit integer using only `nbytes` bytes";
+                write_u24(n: u32), "an unsigned 24-bit integer";
+                write_i24(n: i32), "a signed 24-bit integer";
+                write_u48(n: u64), "an unsigned 48-bit integer";
+                write_i48(n: i64), "a signed 48-bit integer";
+            }
+
+            $crate::async_io::write_half_slice! {
+                write_f32_as_f16_slice, "a half-precision floating point number";
+                write_f32_as_bf16_slice, "a brain floating point number (bfloat16)";
+            }
+
+            /// Writes an unsigned integer to the underlying writer, encoded
+            /// with a variable-length `scheme`.
+            ///
+            /// See [`NumberWriter::write_varint`] for details.
+            ///
+            /// [`NumberWriter::write_varint`]: crate::NumberWriter::write_varint
+            pub async fn write_varint<S: $crate::varint::VarintScheme>(
+                &mut self,
+                scheme: S,
+                n: u64,
+            ) -> $crate::io::Result<()> {
+                self.write_staged(|writer| writer.write_varint(scheme, n)).await
+            }
+
+            /// Writes any bytes left over by a cancelled write, then stages the
+            /// bytes that `f` writes and writes them.
+            async fn write_staged<F>(&mut self, f: F) -> $crate::io::Result<()>
+            where
+                F: FnOnce(&mut $crate::NumberWriter<&mut [u8]>) -> $crate::io::Result<()>,
+            {
+                ::core::future::poll_fn(|cx| self.poll_drain(cx)).await?;
+                self.pending
+                    .stage(self.order.byte_order(), self.length_prefix, f)?;
+                ::core::future::poll_fn(|cx| self.poll_drain(cx)).await
+            }
+
+            /// Writes to the underlying writer until no staged bytes remain.
+            fn poll_drain(
+                &mut self,
+                cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<$crate::io::Result<()>> {
+                while !self.pending.is_empty() {
+                    let pending = self.pending.pending();
+                    match ::core::task::ready!(poll_write_some(&mut self.inner, cx, pending)) {
+                        Ok(0) => return ::core::task::Poll::Ready(Err($crate::async_io::write_zero())),
+                        Ok(n) => self.pending.advance(n),
+                        Err(ref e) if e.kind() == $crate::io::ErrorKind::Interrupted => {}
+                        Err(e) => return ::core::task::Poll::Ready(Err(e)),
+                    }
+                }
+                ::core::task::Poll::Ready(Ok(()))
+            }
+        }
+    };
+}
+
+pub(crate) use {
+    async_reader, async_writer, read_fixed, read_leb128, read_numbers, read_numbers_into,
+    read_sized, write_half_slice, write_numbers, write_numbers_slice, write_staged,
+};
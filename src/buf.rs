@@ -0,0 +1,959 @@
+use core::mem;
+
+use bytes::{Buf, BufMut, Bytes};
+
+use crate::io;
+#[cfg(not(feature = "std"))]
+use crate::io::{Read, Write};
+
+use crate::decode::Decode;
+use crate::encode::{self, Encode};
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::read::NumberReader;
+use crate::varint::VarintScheme;
+use crate::write::NumberWriter;
+
+/// The size of the buffer that variable-length values are encoded into before
+/// they are put into the buffer, which is enough for any of them.
+const STAGE_LEN: usize = 32;
+
+/// A `NumberBuf` reads numbers from any [`Buf`], such as a [`Bytes`] frame.
+///
+/// It provides the same `read_*` methods as a [`NumberReader`], with the
+/// byte order decided once for the whole buffer, and without going through
+/// [`Read`]. Payloads can be split off with [`read_bytes`], which doesn't
+/// copy them when the underlying buffer is a [`Bytes`].
+///
+/// If a number, or a sequence of numbers, does not fit in the bytes that
+/// remain, an error of the kind [`ErrorKind::UnexpectedEof`] is returned and
+/// nothing is consumed. Variable-length integers are consumed one byte at a
+/// time, so an encoding which turns out to be invalid may be partially
+/// consumed.
+///
+/// This type is only available with the `bytes` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, NumberBuf};
+/// use bytes::Bytes;
+///
+/// fn main() -> io::Result<()> {
+///     let frame = Bytes::from_static(&[0x00, 0x04, b'p', b'i', b'n', b'g', 0x12, 0x34]);
+///     let mut buf = NumberBuf::with_order(ByteOrder::BE, frame);
+///
+///     let len = buf.read_u16()?;
+///     let payload = buf.read_bytes(len.into())?;
+///     assert_eq!(&b"ping"[..], payload);
+///     assert_eq!(0x1234, buf.read_u16()?);
+///     assert!(!buf.has_remaining());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Buf`]: bytes::Buf
+/// [`Bytes`]: bytes::Bytes
+/// [`NumberReader`]: crate::NumberReader
+/// [`Read`]: crate::io::Read
+/// [`read_bytes`]: NumberBuf::read_bytes
+/// [`ErrorKind::UnexpectedEof`]: crate::io::ErrorKind::UnexpectedEof
+pub struct NumberBuf<B: Buf, O: Endianness = ByteOrder> {
+    inner: B,
+    order: O,
+    strict: bool,
+    length_prefix: LengthPrefix,
+}
+
+impl<B: Buf> NumberBuf<B> {
+    /// Creates a new `NumberBuf` which reads from `buf` with the target
+    /// platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: NumberBuf::with_order
+    #[inline]
+    pub fn new(buf: B) -> NumberBuf<B> {
+        NumberBuf::with_order(ByteOrder::NE, buf)
+    }
+}
+
+macro_rules! read_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the buffer.")]
+            ///
+            /// # Errors
+            ///
+            /// If too few bytes remain, an error of the kind
+            /// [`ErrorKind::UnexpectedEof`] is returned and nothing is
+            /// consumed.
+            ///
+            /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+            #[inline]
+            pub fn $name(&mut self) -> io::Result<$ty> {
+                self.read_number()
+            }
+        )*
+    };
+}
+
+macro_rules! read_numbers_into {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads a sequence of ", $desc, " from the buffer, filling `dst` entirely.")]
+            ///
+            /// # Errors
+            ///
+            /// If too few bytes remain to fill `dst`, an error of the kind
+            /// [`ErrorKind::UnexpectedEof`] is returned, nothing is consumed,
+            /// and `dst` is left unchanged.
+            ///
+            /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+            #[inline]
+            pub fn $name(&mut self, dst: &mut [$ty]) -> io::Result<()> {
+                self.read_into(dst)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_to_reader {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the buffer.")]
+            ///
+            #[doc = concat!("See [`NumberReader::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberReader::", stringify!($name), "`]: crate::NumberReader::", stringify!($name))]
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) -> io::Result<$ret> {
+                self.with_reader(|reader| reader.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl<B: Buf, O: Endianness> NumberBuf<B, O> {
+    /// Creates a new `NumberBuf` which reads from `buf` with the given byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, NumberBuf};
+    ///
+    /// let be_buf = NumberBuf::with_order(ByteOrder::BE, &[0x12, 0x34][..]);
+    /// let static_buf = NumberBuf::with_order(BigEndian, &[0x12, 0x34][..]);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, buf: B) -> NumberBuf<B, O> {
+        NumberBuf {
+            inner: buf,
+            order,
+            strict: false,
+            length_prefix: LengthPrefix::default(),
+        }
+    }
+
+    /// Consumes this `NumberBuf`, returning the underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying buffer.
+    #[inline]
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying buffer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Returns the number of bytes which remain to be read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.inner.remaining()
+    }
+
+    /// Returns `true` if any bytes remain to be read.
+    #[inline]
+    pub fn has_remaining(&self) -> bool {
+        self.inner.has_remaining()
+    }
+
+    /// Returns `true` if this `NumberBuf` rejects non-canonical encodings of
+    /// variable-length integers.
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether this `NumberBuf` rejects non-canonical encodings of
+    /// variable-length integers.
+    ///
+    /// See [`NumberReader::set_strict`] for details.
+    ///
+    /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// read by this `NumberBuf`.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values
+    /// read by this `NumberBuf`.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Returns an error unless at least `len` bytes remain.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn ensure_remaining(&self, len: usize) -> io::Result<()> {
+        if self.inner.remaining() < len {
+            return Err(eof());
+        }
+        Ok(())
+    }
+
+    /// Advances past the next `len` bytes without reading them.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned and nothing is consumed.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> io::Result<()> {
+        self.ensure_remaining(len)?;
+        self.inner.advance(len);
+        Ok(())
+    }
+
+    /// Splits the next `len` bytes off the buffer as a [`Bytes`].
+    ///
+    /// When the underlying buffer is a [`Bytes`], the returned value shares
+    /// its memory instead of copying it.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned and nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::NumberBuf;
+    /// use bytes::Bytes;
+    ///
+    /// let frame = Bytes::from_static(b"headbody");
+    /// let mut buf = NumberBuf::new(frame);
+    /// assert_eq!(&b"head"[..], buf.read_bytes(4).unwrap());
+    /// assert!(buf.read_bytes(5).is_err());
+    /// assert_eq!(&b"body"[..], buf.read_bytes(4).unwrap());
+    /// ```
+    ///
+    /// [`Bytes`]: bytes::Bytes
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> io::Result<Bytes> {
+        self.ensure_remaining(len)?;
+        Ok(self.inner.copy_to_bytes(len))
+    }
+
+    /// Reads a length, using the encoding set by [`set_length_prefix`], and
+    /// then splits that many bytes off the buffer as a [`Bytes`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`read_length`] or
+    /// [`read_bytes`]. If the payload is incomplete, the length that
+    /// prefixes it has already been consumed.
+    ///
+    /// [`set_length_prefix`]: NumberBuf::set_length_prefix
+    /// [`Bytes`]: bytes::Bytes
+    /// [`read_length`]: NumberBuf::read_length
+    /// [`read_bytes`]: NumberBuf::read_bytes
+    #[inline]
+    pub fn read_prefixed_bytes(&mut self) -> io::Result<Bytes> {
+        let len = self.read_length()?;
+        self.read_bytes(len)
+    }
+
+    /// Reads a number of any primitive type from the buffer.
+    ///
+    /// Each of the named `read_*` methods, such as [`read_u32`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned and nothing is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, NumberBuf};
+    ///
+    /// let mut buf = NumberBuf::with_order(ByteOrder::LE, &[0x12, 0x34, 0x56][..]);
+    /// assert_eq!(0x3412u16, buf.read_number().unwrap());
+    /// assert!(buf.read_number::<u16>().is_err());
+    /// assert_eq!(0x56u8, buf.read_number().unwrap());
+    /// ```
+    ///
+    /// [`read_u32`]: NumberBuf::read_u32
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_number<T: Number>(&mut self) -> io::Result<T> {
+        let mut bytes = T::Bytes::default();
+        self.ensure_remaining(bytes.as_ref().len())?;
+        self.inner.copy_to_slice(bytes.as_mut());
+        Ok(T::from_bytes(bytes, self.order.byte_order()))
+    }
+
+    /// Reads a sequence of numbers of any primitive type from the buffer,
+    /// filling `dst` entirely.
+    ///
+    /// Each of the named `read_*_into` methods, such as [`read_u32_into`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain to fill `dst`, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned, nothing is consumed, and
+    /// `dst` is left unchanged.
+    ///
+    /// [`read_u32_into`]: NumberBuf::read_u32_into
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_into<T: Number>(&mut self, dst: &mut [T]) -> io::Result<()> {
+        self.ensure_remaining(mem::size_of_val(dst))?;
+        self.inner.copy_to_slice(pod::as_bytes_mut(dst));
+        if self.order.byte_order() != ByteOrder::NE {
+            for n in dst {
+                *n = n.swap_bytes();
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a value of any type that implements [`Decode`] from the buffer.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Decode::decode`]. If an
+    /// error is returned, the bytes of the value read before it are consumed.
+    ///
+    /// [`Decode`]: crate::Decode
+    /// [`Decode::decode`]: crate::Decode::decode
+    #[inline]
+    pub fn decode<T: Decode>(&mut self) -> io::Result<T> {
+        self.with_reader(|reader| reader.decode())
+    }
+
+    delegate_to_reader! {
+        read_length() -> usize, "the length that prefixes a variable-sized value";
+    }
+
+    read_numbers! {
+        read_u8: u8, "an unsigned 8-bit integer";
+        read_i8: i8, "a signed 8-bit integer";
+        read_u16: u16, "an unsigned 16-bit integer";
+        read_i16: i16, "a signed 16-bit integer";
+        read_u32: u32, "an unsigned 32-bit integer";
+        read_i32: i32, "a signed 32-bit integer";
+        read_u64: u64, "an unsigned 64-bit integer";
+        read_i64: i64, "a signed 64-bit integer";
+        read_u128: u128, "an unsigned 128-bit integer";
+        read_i128: i128, "a signed 128-bit integer";
+        read_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        read_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    read_numbers_into! {
+        read_u16_into: u16, "unsigned 16-bit integers";
+        read_i16_into: i16, "signed 16-bit integers";
+        read_u32_into: u32, "unsigned 32-bit integers";
+        read_i32_into: i32, "signed 32-bit integers";
+        read_u64_into: u64, "unsigned 64-bit integers";
+        read_i64_into: i64, "signed 64-bit integers";
+        read_u128_into: u128, "unsigned 128-bit integers";
+        read_i128_into: i128, "signed 128-bit integers";
+        read_f32_into: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+        read_f64_into: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+    }
+
+    delegate_to_reader! {
+        read_uleb128_u32() -> u32, "an unsigned 32-bit integer encoded as unsigned LEB128";
+        read_uleb128_u64() -> u64, "an unsigned 64-bit integer encoded as unsigned LEB128";
+        read_uleb128_u128() -> u128, "an unsigned 128-bit integer encoded as unsigned LEB128";
+        read_sleb128_i32() -> i32, "a signed 32-bit integer encoded as signed LEB128";
+        read_sleb128_i64() -> i64, "a signed 64-bit integer encoded as signed LEB128";
+        read_sleb128_i128() -> i128, "a signed 128-bit integer encoded as signed LEB128";
+        read_zigzag_i32() -> i32, "a signed 32-bit integer encoded as zigzag unsigned LEB128";
+        read_zigzag_i64() -> i64, "a signed 64-bit integer encoded as zigzag unsigned LEB128";
+        read_f16_as_f32() -> f32, "a half-precision floating point number, widening it to an `f32`,";
+        read_f16_as_f32_into(dst: &mut [f32]) -> (), "a sequence of half-precision floating point numbers, widening each of them to an `f32`,";
+        read_bf16_as_f32() -> f32, "a brain floating point number (bfloat16), widening it to an `f32`,";
+        read_bf16_as_f32_into(dst: &mut [f32]) -> (), "a sequence of brain floating point numbers (bfloat16), widening each of them to an `f32`,";
+        read_uint(nbytes: usize) -> u64, "an unsigned integer that is `nbytes` bytes wide";
+        read_int(nbytes: usize) -> i64, "a signed integer that is `nbytes` bytes wide";
+        read_uint128(nbytes: usize) -> u128, "an unsigned 128-bit integer that is `nbytes` bytes wide";
+        read_int128(nbytes: usize) -> i128, "a signed 128-bit integer that is `nbytes` bytes wide";
+        read_u24() -> u32, "an unsigned 24-bit integer";
+        read_i24() -> i32, "a signed 24-bit integer";
+        read_u48() -> u64, "an unsigned 48-bit integer";
+        read_i48() -> i64, "a signed 48-bit integer";
+    }
+
+    /// Reads an unsigned integer encoded with a variable-length `scheme` from
+    /// the buffer.
+    ///
+    /// See [`NumberReader::read_varint`] for details.
+    ///
+    /// [`NumberReader::read_varint`]: crate::NumberReader::read_varint
+    #[inline]
+    pub fn read_varint<S: VarintScheme>(&mut self, scheme: S) -> io::Result<u64> {
+        self.with_reader(|reader| reader.read_varint(scheme))
+    }
+
+    /// Runs `f` with a [`NumberReader`] over this `NumberBuf`, with the same
+    /// settings.
+    #[inline]
+    fn with_reader<T, F>(&mut self, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut NumberReader<&mut Self>) -> io::Result<T>,
+    {
+        let order = self.order.byte_order();
+        let strict = self.strict;
+        let length_prefix = self.length_prefix;
+        let mut reader = NumberReader::with_order(order, self);
+        reader.set_strict(strict);
+        reader.set_length_prefix(length_prefix);
+        f(&mut reader)
+    }
+
+    /// Moves as many bytes as fit into `dst`, returning how many were moved.
+    #[inline]
+    fn read_some(&mut self, dst: &mut [u8]) -> usize {
+        let len = dst.len().min(self.inner.remaining());
+        self.inner.copy_to_slice(&mut dst[..len]);
+        len
+    }
+
+    /// Fills `dst` entirely, or consumes nothing if too few bytes remain.
+    #[inline]
+    fn read_all(&mut self, dst: &mut [u8]) -> io::Result<()> {
+        self.ensure_remaining(dst.len())?;
+        self.inner.copy_to_slice(dst);
+        Ok(())
+    }
+}
+
+/// A `NumberBufMut` writes numbers into any [`BufMut`], such as a
+/// [`BytesMut`].
+///
+/// It provides the same `write_*` methods as a [`NumberWriter`], with the
+/// byte order decided once for the whole buffer, and without going through
+/// [`Write`]. When a value does not fit in the space that remains, an error of
+/// the kind [`ErrorKind::WriteZero`] is returned, and none of the value is
+/// written. Buffers which grow on demand, like [`BytesMut`] and `Vec<u8>`, are
+/// never full.
+///
+/// This type is only available with the `bytes` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, NumberBufMut};
+/// use bytes::BytesMut;
+///
+/// fn main() -> io::Result<()> {
+///     let mut buf = NumberBufMut::with_order(ByteOrder::BE, BytesMut::new());
+///     buf.write_u16(4)?;
+///     buf.write_bytes(b"ping")?;
+///     buf.write_u16(0x1234)?;
+///
+///     let frame = buf.into_inner().freeze();
+///     assert_eq!(&[0x00, 0x04, b'p', b'i', b'n', b'g', 0x12, 0x34][..], frame);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`BufMut`]: bytes::BufMut
+/// [`BytesMut`]: bytes::BytesMut
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`Write`]: crate::io::Write
+/// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+pub struct NumberBufMut<B: BufMut, O: Endianness = ByteOrder> {
+    inner: B,
+    order: O,
+    length_prefix: LengthPrefix,
+}
+
+impl<B: BufMut> NumberBufMut<B> {
+    /// Creates a new `NumberBufMut` which writes to `buf` with the target
+    /// platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: NumberBufMut::with_order
+    #[inline]
+    pub fn new(buf: B) -> NumberBufMut<B> {
+        NumberBufMut::with_order(ByteOrder::NE, buf)
+    }
+}
+
+macro_rules! write_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the buffer.")]
+            ///
+            /// # Errors
+            ///
+            /// If too little space remains, an error of the kind
+            /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+            ///
+            /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+            #[inline]
+            pub fn $name(&mut self, n: $ty) -> io::Result<()> {
+                self.write_number(n)
+            }
+        )*
+    };
+}
+
+macro_rules! write_numbers_slice {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a sequence of ", $desc, " to the buffer.")]
+            ///
+            /// # Errors
+            ///
+            /// If too little space remains for the whole of `src`, an error of
+            /// the kind [`ErrorKind::WriteZero`] is returned and nothing is
+            /// written.
+            ///
+            /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+            #[inline]
+            pub fn $name(&mut self, src: &[$ty]) -> io::Result<()> {
+                self.write_slice(src)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_to_writer {
+    ($($name:ident($($arg:ident: $ty:ty),*), $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the buffer.")]
+            ///
+            #[doc = concat!("See [`NumberWriter::", stringify!($name), "`] for details.")]
+            ///
+            #[doc = concat!("[`NumberWriter::", stringify!($name), "`]: crate::NumberWriter::", stringify!($name))]
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) -> io::Result<()> {
+                self.staged(|writer| writer.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl<B: BufMut, O: Endianness> NumberBufMut<B, O> {
+    /// Creates a new `NumberBufMut` which writes to `buf` with the given byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, NumberBufMut};
+    /// use bytes::BytesMut;
+    ///
+    /// let be_buf = NumberBufMut::with_order(ByteOrder::BE, BytesMut::new());
+    /// let static_buf = NumberBufMut::with_order(BigEndian, BytesMut::new());
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, buf: B) -> NumberBufMut<B, O> {
+        NumberBufMut {
+            inner: buf,
+            order,
+            length_prefix: LengthPrefix::default(),
+        }
+    }
+
+    /// Consumes this `NumberBufMut`, returning the underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying buffer.
+    #[inline]
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying buffer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Returns the number of bytes which can still be written.
+    #[inline]
+    pub fn remaining_mut(&self) -> usize {
+        self.inner.remaining_mut()
+    }
+
+    /// Returns `true` if any more bytes can be written.
+    #[inline]
+    pub fn has_remaining_mut(&self) -> bool {
+        self.inner.has_remaining_mut()
+    }
+
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// written by this `NumberBufMut`.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values
+    /// written by this `NumberBufMut`.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Writes all of `bytes` to the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.ensure_remaining_mut(bytes.len())?;
+        self.inner.put_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes a number of any primitive type to the buffer.
+    ///
+    /// Each of the named `write_*` methods, such as [`write_u32`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, NumberBufMut};
+    ///
+    /// let mut bytes = [0u8; 3];
+    /// let mut buf = NumberBufMut::with_order(BigEndian, &mut bytes[..]);
+    /// buf.write_number(0x1234u16).unwrap();
+    /// assert!(buf.write_number(0x5678u16).is_err());
+    /// buf.write_number(0x56u8).unwrap();
+    /// assert_eq!([0x12, 0x34, 0x56], bytes);
+    /// ```
+    ///
+    /// [`write_u32`]: NumberBufMut::write_u32
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_number<T: Number>(&mut self, n: T) -> io::Result<()> {
+        self.write_bytes(n.to_bytes(self.order.byte_order()).as_ref())
+    }
+
+    /// Writes a sequence of numbers of any primitive type to the buffer.
+    ///
+    /// Each of the named `write_*_slice` methods, such as
+    /// [`write_u32_slice`], is equivalent to calling this method with the
+    /// corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`write_u32_slice`]: NumberBufMut::write_u32_slice
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_slice<T: Number>(&mut self, src: &[T]) -> io::Result<()> {
+        self.ensure_remaining_mut(mem::size_of_val(src))?;
+        match self.order.byte_order() {
+            order if order == ByteOrder::NE => self.inner.put_slice(pod::as_bytes(src)),
+            ByteOrder::BE => put(&mut self.inner, src, |n: T| n.to_bytes(ByteOrder::BE)),
+            ByteOrder::LE => put(&mut self.inner, src, |n: T| n.to_bytes(ByteOrder::LE)),
+        }
+        Ok(())
+    }
+
+    /// Writes a value of any type that implements [`Encode`] to the buffer.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Encode::encode`]. If too
+    /// little space remains for the whole value, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// The value is encoded twice, first to measure it and then into the
+    /// buffer, so [`Encode::encode`] must write the same bytes each time.
+    ///
+    /// [`Encode`]: crate::Encode
+    /// [`Encode::encode`]: crate::Encode::encode
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) -> io::Result<()> {
+        let len = encode::encoded_len(value, self.order.byte_order(), self.length_prefix)?;
+        self.ensure_remaining_mut(len)?;
+        self.unstaged(|writer| writer.encode(value))
+    }
+
+    delegate_to_writer! {
+        write_length(len: usize), "the length that prefixes a variable-sized value";
+    }
+
+    write_numbers! {
+        write_u8: u8, "an unsigned 8-bit integer";
+        write_i8: i8, "a signed 8-bit integer";
+        write_u16: u16, "an unsigned 16-bit integer";
+        write_i16: i16, "a signed 16-bit integer";
+        write_u32: u32, "an unsigned 32-bit integer";
+        write_i32: i32, "a signed 32-bit integer";
+        write_u64: u64, "an unsigned 64-bit integer";
+        write_i64: i64, "a signed 64-bit integer";
+        write_u128: u128, "an unsigned 128-bit integer";
+        write_i128: i128, "a signed 128-bit integer";
+        write_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        write_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    write_numbers_slice! {
+        write_u16_slice: u16, "unsigned 16-bit integers";
+        write_i16_slice: i16, "signed 16-bit integers";
+        write_u32_slice: u32, "unsigned 32-bit integers";
+        write_i32_slice: i32, "signed 32-bit integers";
+        write_u64_slice: u64, "unsigned 64-bit integers";
+        write_i64_slice: i64, "signed 64-bit integers";
+        write_u128_slice: u128, "unsigned 128-bit integers";
+        write_i128_slice: i128, "signed 128-bit integers";
+        write_f32_slice: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+        write_f64_slice: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+    }
+
+    delegate_to_writer! {
+        write_uleb128_u32(n: u32), "an unsigned 32-bit integer, encoded as unsigned LEB128,";
+        write_uleb128_u64(n: u64), "an unsigned 64-bit integer, encoded as unsigned LEB128,";
+        write_uleb128_u128(n: u128), "an unsigned 128-bit integer, encoded as unsigned LEB128,";
+        write_sleb128_i32(n: i32), "a signed 32-bit integer, encoded as signed LEB128,";
+        write_sleb128_i64(n: i64), "a signed 64-bit integer, encoded as signed LEB128,";
+        write_sleb128_i128(n: i128), "a signed 128-bit integer, encoded as signed LEB128,";
+        write_zigzag_i32(n: i32), "a signed 32-bit integer, encoded as zigzag unsigned LEB128,";
+        write_zigzag_i64(n: i64), "a signed 64-bit integer, encoded as zigzag unsigned LEB128,";
+        write_f32_as_f16(n: f32), "an `f32`, narrowed to a half-precision floating point number,";
+        write_f32_as_bf16(n: f32), "an `f32`, narrowed to a brain floating point number (bfloat16),";
+        write_uint(n: u64, nbytes: usize), "the low `nbytes` bytes of an unsigned integer";
+        write_int(n: i64, nbytes: usize), "the low `nbytes` bytes of a signed integer";
+        write_uint128(n: u128, nbytes: usize), "the low `nbytes` bytes of an unsigned 128-bit integer";
+        write_int128(n: i128, nbytes: usize), "the low `nbytes` bytes of a signed 128-bit integer";
+        write_u24(n: u32), "an unsigned 24-bit integer";
+        write_i24(n: i32), "a signed 24-bit integer";
+        write_u48(n: u64), "an unsigned 48-bit integer";
+        write_i48(n: i64), "a signed 48-bit integer";
+    }
+
+    /// Writes an unsigned integer to the buffer, encoded with a
+    /// variable-length `scheme`.
+    ///
+    /// See [`NumberWriter::write_varint`] for details.
+    ///
+    /// [`NumberWriter::write_varint`]: crate::NumberWriter::write_varint
+    #[inline]
+    pub fn write_varint<S: VarintScheme>(&mut self, scheme: S, n: u64) -> io::Result<()> {
+        self.staged(|writer| writer.write_varint(scheme, n))
+    }
+
+    /// Writes a sequence of `f32` values to the buffer, narrowing each of them
+    /// to a half-precision floating point number.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_f32_as_f16_slice(&mut self, src: &[f32]) -> io::Result<()> {
+        self.ensure_remaining_mut(2 * src.len())?;
+        self.unstaged(|writer| writer.write_f32_as_f16_slice(src))
+    }
+
+    /// Writes a sequence of `f32` values to the buffer, narrowing each of them
+    /// to a brain floating point number (bfloat16).
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_f32_as_bf16_slice(&mut self, src: &[f32]) -> io::Result<()> {
+        self.ensure_remaining_mut(2 * src.len())?;
+        self.unstaged(|writer| writer.write_f32_as_bf16_slice(src))
+    }
+
+    /// Returns a "buffer full" error unless at least `len` bytes can be
+    /// written.
+    #[inline]
+    fn ensure_remaining_mut(&self, len: usize) -> io::Result<()> {
+        if self.inner.remaining_mut() < len {
+            return Err(full());
+        }
+        Ok(())
+    }
+
+    /// Runs `f` with a [`NumberWriter`] over a small buffer, and then puts
+    /// what it wrote into the buffer, so that nothing is written if it does
+    /// not fit.
+    #[inline]
+    fn staged<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut NumberWriter<&mut &mut [u8]>) -> io::Result<()>,
+    {
+        let mut stage = [0; STAGE_LEN];
+        let mut rest = &mut stage[..];
+        let mut writer = NumberWriter::with_order(self.order.byte_order(), &mut rest);
+        writer.set_length_prefix(self.length_prefix);
+        f(&mut writer)?;
+        let len = STAGE_LEN - rest.len();
+        self.write_bytes(&stage[..len])
+    }
+
+    /// Runs `f` with a [`NumberWriter`] over this `NumberBufMut`, with the
+    /// same settings.
+    #[inline]
+    fn unstaged<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut NumberWriter<&mut Self>) -> io::Result<()>,
+    {
+        let order = self.order.byte_order();
+        let length_prefix = self.length_prefix;
+        let mut writer = NumberWriter::with_order(order, self);
+        writer.set_length_prefix(length_prefix);
+        f(&mut writer)
+    }
+
+    /// Puts as many bytes of `src` as fit, returning how many were put.
+    #[inline]
+    fn write_some(&mut self, src: &[u8]) -> usize {
+        let len = src.len().min(self.inner.remaining_mut());
+        self.inner.put_slice(&src[..len]);
+        len
+    }
+}
+
+/// Puts the bytes of each number in `src`, as converted by `to_bytes`, into
+/// `buf`.
+#[inline]
+fn put<B: BufMut, T: Number>(buf: &mut B, src: &[T], to_bytes: impl Fn(T) -> T::Bytes) {
+    for &n in src {
+        buf.put_slice(to_bytes(n).as_ref());
+    }
+}
+
+#[cold]
+fn eof() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "not enough bytes remain in the buffer",
+    )
+}
+
+#[cold]
+fn full() -> io::Error {
+    io::Error::new(io::ErrorKind::WriteZero, "buffer full")
+}
+
+#[cfg(feature = "std")]
+impl<B: Buf, O: Endianness> std::io::Read for NumberBuf<B, O> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(self.read_some(buf))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.read_all(buf)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<B: Buf, O: Endianness> Read for NumberBuf<B, O> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(self.read_some(buf))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.read_all(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: BufMut, O: Endianness> std::io::Write for NumberBufMut<B, O> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.write_some(buf))
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_bytes(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<B: BufMut, O: Endianness> Write for NumberBufMut<B, O> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.write_some(buf))
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_bytes(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+//! A [`Decoder`] and [`Encoder`] of `tokio-util` for length-prefixed frames.
+//!
+//! [`LengthPrefixedCodec`] splits a stream of bytes into frames which each
+//! begin with their length, encoded with any [`LengthPrefix`] in the byte
+//! order of the codec. Combined with `FramedRead`, `FramedWrite`, or `Framed`,
+//! it turns any tokio reader or writer into a stream or sink of frames.
+//!
+//! This module is only available with the `codec` feature enabled.
+//!
+//! [`LengthPrefix`]: crate::LengthPrefix
+
+use core::convert::TryFrom;
+
+use bytes::{Buf, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::buf::NumberBufMut;
+use crate::io;
+use crate::order::{ByteOrder, Endianness};
+use crate::prefix::LengthPrefix;
+use crate::slice_read::SliceReader;
+
+/// The default maximum length of the payload of a frame, which is 8 MiB.
+const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+/// The largest number of bytes that any length prefix can take.
+const MAX_PREFIX_LEN: usize = 10;
+
+/// A `LengthPrefixedCodec` decodes and encodes frames which begin with their
+/// length.
+///
+/// The length is encoded with the [`LengthPrefix`] set by
+/// [`set_length_prefix`], in the byte order that the codec takes upon
+/// initialization, and is followed by the payload of the frame. By default,
+/// the length is a [`LengthPrefix::U32`] that counts the bytes of the payload,
+/// and payloads of up to 8 MiB are accepted.
+///
+/// Protocols whose length prefix counts something other than the payload,
+/// such as the prefix itself, are supported by [`set_length_adjustment`].
+///
+/// Decoded frames are the payload alone, without the length prefix. Encoded
+/// frames are written from a [`Bytes`], or from any byte slice.
+///
+/// This type is only available with the `codec` feature enabled.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::codec::LengthPrefixedCodec;
+/// use byte_order::{ByteOrder, LengthPrefix};
+/// use bytes::{Bytes, BytesMut};
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// fn main() -> io::Result<()> {
+///     let mut codec = LengthPrefixedCodec::with_order(ByteOrder::BE);
+///     codec.set_length_prefix(LengthPrefix::U16);
+///
+///     let mut buf = BytesMut::new();
+///     codec.encode(Bytes::from_static(b"ping"), &mut buf)?;
+///     assert_eq!(&[0x00, 0x04, b'p', b'i', b'n', b'g'][..], buf);
+///
+///     let mut partial = buf.split_to(3);
+///     assert_eq!(None, codec.decode(&mut partial)?);
+///     partial.unsplit(buf);
+///     assert_eq!(&b"ping"[..], codec.decode(&mut partial)?.unwrap());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`LengthPrefix`]: crate::LengthPrefix
+/// [`set_length_prefix`]: LengthPrefixedCodec::set_length_prefix
+/// [`LengthPrefix::U32`]: crate::LengthPrefix::U32
+/// [`set_length_adjustment`]: LengthPrefixedCodec::set_length_adjustment
+/// [`Bytes`]: bytes::Bytes
+pub struct LengthPrefixedCodec<O: Endianness = ByteOrder> {
+    order: O,
+    length_prefix: LengthPrefix,
+    max_frame_len: usize,
+    length_adjustment: isize,
+    strict: bool,
+}
+
+impl LengthPrefixedCodec {
+    /// Creates a new `LengthPrefixedCodec` whose length prefixes have the
+    /// target platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: LengthPrefixedCodec::with_order
+    #[inline]
+    pub fn new() -> LengthPrefixedCodec {
+        LengthPrefixedCodec::with_order(ByteOrder::NE)
+    }
+}
+
+impl Default for LengthPrefixedCodec {
+    #[inline]
+    fn default() -> LengthPrefixedCodec {
+        LengthPrefixedCodec::new()
+    }
+}
+
+impl<O: Endianness> LengthPrefixedCodec<O> {
+    /// Creates a new `LengthPrefixedCodec` whose length prefixes have the
+    /// given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::codec::LengthPrefixedCodec;
+    /// use byte_order::{BigEndian, ByteOrder};
+    ///
+    /// let be_codec = LengthPrefixedCodec::with_order(ByteOrder::BE);
+    /// let static_codec = LengthPrefixedCodec::with_order(BigEndian);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O) -> LengthPrefixedCodec<O> {
+        LengthPrefixedCodec {
+            order,
+            length_prefix: LengthPrefix::default(),
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+            length_adjustment: 0,
+            strict: false,
+        }
+    }
+
+    /// Returns the encoding of the length that prefixes each frame.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes each frame.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Returns the largest payload, in bytes, that this codec decodes or
+    /// encodes.
+    #[inline]
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Sets the largest payload, in bytes, that this codec decodes or
+    /// encodes.
+    ///
+    /// A decoded length prefix above this limit is an error, which is
+    /// returned before any of the payload is buffered.
+    #[inline]
+    pub fn set_max_frame_len(&mut self, max_frame_len: usize) {
+        self.max_frame_len = max_frame_len;
+    }
+
+    /// Returns the number that is added to each decoded length prefix to
+    /// obtain the length of the payload.
+    #[inline]
+    pub fn length_adjustment(&self) -> isize {
+        self.length_adjustment
+    }
+
+    /// Sets the number that is added to each decoded length prefix to obtain
+    /// the length of the payload, and subtracted from the length of each
+    /// payload before it is encoded.
+    ///
+    /// For example, a protocol whose length prefix also counts the prefix
+    /// itself uses the negated width of the prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::codec::LengthPrefixedCodec;
+    /// use byte_order::{ByteOrder, LengthPrefix};
+    /// use bytes::BytesMut;
+    /// use tokio_util::codec::{Decoder, Encoder};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut codec = LengthPrefixedCodec::with_order(ByteOrder::LE);
+    ///     codec.set_length_prefix(LengthPrefix::U16);
+    ///     codec.set_length_adjustment(-2);
+    ///
+    ///     let mut buf = BytesMut::new();
+    ///     codec.encode(&b"ping"[..], &mut buf)?;
+    ///     assert_eq!(&[0x06, 0x00, b'p', b'i', b'n', b'g'][..], buf);
+    ///     assert_eq!(&b"ping"[..], codec.decode(&mut buf)?.unwrap());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_length_adjustment(&mut self, length_adjustment: isize) {
+        self.length_adjustment = length_adjustment;
+    }
+
+    /// Returns `true` if this codec rejects non-canonical encodings of
+    /// [`LengthPrefix::Uleb128`] prefixes.
+    ///
+    /// [`LengthPrefix::Uleb128`]: crate::LengthPrefix::Uleb128
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether this codec rejects non-canonical encodings of
+    /// [`LengthPrefix::Uleb128`] prefixes.
+    ///
+    /// See [`NumberReader::set_strict`] for details.
+    ///
+    /// [`LengthPrefix::Uleb128`]: crate::LengthPrefix::Uleb128
+    /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Reads the length prefix at the start of `src`, returning its width and
+    /// the length of the payload, or `None` if it is incomplete.
+    fn decode_header(&self, src: &[u8]) -> io::Result<Option<(usize, usize)>> {
+        let mut reader = SliceReader::with_order(self.order.byte_order(), src);
+        reader.set_strict(self.strict);
+        reader.set_length_prefix(self.length_prefix);
+        let len = match reader.read_length() {
+            Ok(len) => len,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let header_len = src.len() - reader.remaining();
+
+        let frame_len = (len as i128) + (self.length_adjustment as i128);
+        let frame_len = usize::try_from(frame_len).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "adjusted frame length is out of range",
+            )
+        })?;
+        if frame_len > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame exceeds the maximum frame length",
+            ));
+        }
+        Ok(Some((header_len, frame_len)))
+    }
+
+    /// Writes the length prefix of `frame`, and then `frame` itself.
+    fn encode_frame(&self, frame: &[u8], dst: &mut BytesMut) -> io::Result<()> {
+        if frame.len() > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame exceeds the maximum frame length",
+            ));
+        }
+        let len = (frame.len() as i128) - (self.length_adjustment as i128);
+        let len = usize::try_from(len).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "adjusted frame length is out of range",
+            )
+        })?;
+
+        dst.reserve(MAX_PREFIX_LEN + frame.len());
+        let mut buf = NumberBufMut::with_order(self.order.byte_order(), dst);
+        buf.set_length_prefix(self.length_prefix);
+        buf.write_length(len)?;
+        buf.write_bytes(frame)
+    }
+}
+
+impl<O: Endianness> Decoder for LengthPrefixedCodec<O> {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<BytesMut>> {
+        let (header_len, frame_len) = match self.decode_header(src)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        if src.len() - header_len < frame_len {
+            src.reserve(header_len + frame_len - src.len());
+            return Ok(None);
+        }
+        src.advance(header_len);
+        Ok(Some(src.split_to(frame_len)))
+    }
+}
+
+impl<O: Endianness> Encoder<Bytes> for LengthPrefixedCodec<O> {
+    type Error = io::Error;
+
+    fn encode(&mut self, frame: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        self.encode_frame(&frame, dst)
+    }
+}
+
+impl<O: Endianness> Encoder<&[u8]> for LengthPrefixedCodec<O> {
+    type Error = io::Error;
+
+    fn encode(&mut self, frame: &[u8], dst: &mut BytesMut) -> io::Result<()> {
+        self.encode_frame(frame, dst)
+    }
+}
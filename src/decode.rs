@@ -0,0 +1,218 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::io::{self, ErrorKind, Read};
+use crate::order::Endianness;
+use crate::read::NumberReader;
+
+/// A trait for types which can be read from a [`NumberReader`].
+///
+/// This trait is implemented for every primitive number type, `bool`, `char`,
+/// `Option<T>`, arrays, tuples of up to 12 elements, and for the
+/// length-prefixed types `Vec<T>` and `String`. It can be implemented for
+/// other types to describe how they are composed of these.
+///
+/// Values are usually read with [`NumberReader::decode`].
+///
+/// # Examples
+///
+/// Reading a record described as a tuple in one call:
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::{ByteOrder, NumberReader};
+///
+/// fn main() -> io::Result<()> {
+///     let src = Cursor::new(vec![0x12, 0x34, 0x01, 0x00, 0x00, 0x00, 0x02, b'h', b'i']);
+///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+///
+///     let record: (u16, bool, String) = reader.decode()?;
+///     assert_eq!((0x1234, true, String::from("hi")), record);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// Implementing `Decode` for a custom type:
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::io::Read;
+/// use byte_order::{ByteOrder, Decode, Endianness, NumberReader};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// impl Decode for Point {
+///     fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+///         Ok(Point {
+///             x: reader.decode()?,
+///             y: reader.decode()?,
+///         })
+///     }
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let src = Cursor::new(vec![0x00, 0x01, 0xFF, 0xFF]);
+///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+///     assert_eq!(Point { x: 1, y: -1 }, reader.decode()?);
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [`NumberReader::decode`]: crate::NumberReader::decode
+pub trait Decode: Sized {
+    /// Reads a value of this type from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Implementations should propagate any error recieved from `reader`, and
+    /// should return an error of the kind [`ErrorKind::InvalidData`] if the
+    /// bytes read do not form a valid value.
+    ///
+    /// [`ErrorKind::InvalidData`]: crate::io::ErrorKind::InvalidData
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self>;
+}
+
+/// The largest number of elements that is preallocated for a length-prefixed
+/// value, so that a corrupt length cannot cause an enormous allocation.
+#[cfg(feature = "alloc")]
+const MAX_PREALLOC: usize = 4096;
+
+macro_rules! impl_decode_number {
+    ($($ty:ty),*) => {
+        $(
+            impl Decode for $ty {
+                #[inline]
+                fn decode<R: Read, O: Endianness>(
+                    reader: &mut NumberReader<R, O>,
+                ) -> io::Result<Self> {
+                    reader.read_number()
+                }
+            }
+        )*
+    };
+}
+
+impl_decode_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+/// A `bool` is read as a single byte, which must be either `0` or `1`.
+impl Decode for bool {
+    #[inline]
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        match reader.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(io::Error::new(ErrorKind::InvalidData, "invalid bool")),
+        }
+    }
+}
+
+/// A `char` is read as an unsigned 32-bit integer, which must be a valid
+/// Unicode scalar value.
+impl Decode for char {
+    #[inline]
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        core::char::from_u32(reader.read_u32()?)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "invalid char"))
+    }
+}
+
+/// An `Option<T>` is read as a `bool` which is `true` if a `T` follows it.
+impl<T: Decode> Decode for Option<T> {
+    #[inline]
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        if reader.decode()? {
+            Ok(Some(reader.decode()?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// An array is read as each of its elements in order, without a length
+/// prefix.
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        let mut elems = [(); N].map(|_| None);
+        for elem in &mut elems {
+            *elem = Some(reader.decode()?);
+        }
+        Ok(elems.map(|elem| elem.unwrap()))
+    }
+}
+
+/// A `Vec<T>` is read as a length prefix, followed by that many elements.
+#[cfg(feature = "alloc")]
+impl<T: Decode> Decode for Vec<T> {
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        let len = reader.read_length()?;
+        let mut vec = Vec::with_capacity(len.min(MAX_PREALLOC));
+        for _ in 0..len {
+            vec.push(reader.decode()?);
+        }
+        Ok(vec)
+    }
+}
+
+/// A `String` is read as a length prefix, followed by that many bytes of
+/// UTF-8.
+#[cfg(feature = "alloc")]
+impl Decode for String {
+    fn decode<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>) -> io::Result<Self> {
+        let len = reader.read_length()?;
+        let buf = read_byte_vec(reader, len)?;
+        String::from_utf8(buf).map_err(|_| {
+            io::Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+        })
+    }
+}
+
+/// Reads `len` bytes into a new `Vec<u8>`.
+#[cfg(feature = "alloc")]
+pub(crate) fn read_byte_vec<R: Read, O: Endianness>(
+    reader: &mut NumberReader<R, O>,
+    len: usize,
+) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(len.min(MAX_PREALLOC));
+    // Grow the buffer as bytes arrive rather than trusting the length.
+    while buf.len() < len {
+        let start = buf.len();
+        buf.resize(start + (len - start).min(MAX_PREALLOC), 0);
+        reader.read_exact(&mut buf[start..])?;
+    }
+    Ok(buf)
+}
+
+macro_rules! impl_decode_tuple {
+    ($($name:ident)*) => {
+        /// A tuple is read as each of its elements in order.
+        impl<$($name: Decode),*> Decode for ($($name,)*) {
+            #[inline]
+            #[allow(unused_variables)]
+            fn decode<R: Read, O: Endianness>(
+                reader: &mut NumberReader<R, O>,
+            ) -> io::Result<Self> {
+                Ok(($(reader.decode::<$name>()?,)*))
+            }
+        }
+    };
+}
+
+impl_decode_tuple!();
+impl_decode_tuple!(A);
+impl_decode_tuple!(A B);
+impl_decode_tuple!(A B C);
+impl_decode_tuple!(A B C D);
+impl_decode_tuple!(A B C D E);
+impl_decode_tuple!(A B C D E F);
+impl_decode_tuple!(A B C D E F G);
+impl_decode_tuple!(A B C D E F G H);
+impl_decode_tuple!(A B C D E F G H I);
+impl_decode_tuple!(A B C D E F G H I J);
+impl_decode_tuple!(A B C D E F G H I J K);
+impl_decode_tuple!(A B C D E F G H I J K L);
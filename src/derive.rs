@@ -0,0 +1,197 @@
+//! Support code for the `Decode` and `Encode` derive macros. Nothing in this
+//! module is considered part of the public API.
+
+use core::fmt::Debug;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{self, ErrorKind};
+use crate::order::Endianness;
+use crate::prefix::LengthPrefix;
+use crate::read::NumberReader;
+use crate::write::NumberWriter;
+
+pub use crate::io::{Read, Result, Write};
+pub use core::result::Result::{Err, Ok};
+
+/// Adds the path of the field being read or written, such as `Header.len`, to
+/// an error.
+///
+/// An error which already holds a [`DecodeError`] describes its field itself,
+/// and is returned unchanged. Without the `std` feature, errors only carry a
+/// static message, so every error is returned unchanged.
+///
+/// [`DecodeError`]: crate::DecodeError
+#[cold]
+pub fn field_error(err: io::Error, path: &str) -> io::Error {
+    #[cfg(feature = "std")]
+    {
+        if crate::error::DecodeError::from_io_error(&err).is_some() {
+            return err;
+        }
+        io::Error::new(err.kind(), format!("{}: {}", path, err))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = path;
+        err
+    }
+}
+
+/// Reads a field with `f`.
+///
+/// If `reader` has error context enabled, the field is read within
+/// [`NumberReader::with_field`] under `name`, such as `len` or `Circle.radius`,
+/// and any error is described by a [`DecodeError`]. Otherwise, the path of the
+/// field, such as `Header.len`, is added to the message of any error.
+///
+/// [`DecodeError`]: crate::DecodeError
+pub fn decode_field<T, R, O, F>(
+    reader: &mut NumberReader<R, O>,
+    name: &str,
+    path: &str,
+    f: F,
+) -> io::Result<T>
+where
+    R: Read,
+    O: Endianness,
+    F: FnOnce(&mut NumberReader<R, O>) -> io::Result<T>,
+{
+    #[cfg(feature = "std")]
+    if reader.has_error_context() {
+        let _ = path;
+        return reader.with_field(name, |reader| {
+            let start = reader.position().unwrap_or(0);
+            f(reader).map_err(|err| reader.describe(err, start, core::any::type_name::<T>(), None))
+        });
+    }
+    let _ = name;
+    f(reader).map_err(|err| field_error(err, path))
+}
+
+/// Creates the error for a value which does not match its expected magic.
+#[cold]
+pub fn magic_error<T: Debug + ?Sized, U: Debug + ?Sized>(expected: &T, found: &U) -> io::Error {
+    invalid_magic(ErrorKind::InvalidData, expected, found)
+}
+
+/// Creates the error for a field which cannot be written because its value
+/// does not match its magic.
+#[cold]
+pub fn magic_input_error<T: Debug + ?Sized, U: Debug + ?Sized>(
+    expected: &T,
+    found: &U,
+) -> io::Error {
+    invalid_magic(ErrorKind::InvalidInput, expected, found)
+}
+
+fn invalid_magic<T: Debug + ?Sized, U: Debug + ?Sized>(
+    kind: ErrorKind,
+    expected: &T,
+    found: &U,
+) -> io::Error {
+    #[cfg(feature = "std")]
+    return io::Error::new(
+        kind,
+        format!(
+            "invalid magic, expected {:?} but found {:?}",
+            expected, found
+        ),
+    );
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = (expected, found);
+        io::Error::new(kind, "invalid magic")
+    }
+}
+
+/// Creates the error for an enum tag which does not match any variant.
+#[cold]
+pub fn tag_error(tag: u64) -> io::Error {
+    #[cfg(feature = "std")]
+    return io::Error::new(ErrorKind::InvalidData, format!("unknown tag {}", tag));
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = tag;
+        io::Error::new(ErrorKind::InvalidData, "unknown tag")
+    }
+}
+
+/// Reads a `T` with a length prefix other than the one `reader` uses.
+pub fn decode_with_prefix<T: Decode, R: Read, O: Endianness>(
+    reader: &mut NumberReader<R, O>,
+    length_prefix: LengthPrefix,
+) -> io::Result<T> {
+    let previous = reader.length_prefix();
+    reader.set_length_prefix(length_prefix);
+    let result = reader.decode();
+    reader.set_length_prefix(previous);
+    result
+}
+
+/// Writes a `T` with a length prefix other than the one `writer` uses.
+pub fn encode_with_prefix<T: Encode + ?Sized, W: Write, O: Endianness>(
+    writer: &mut NumberWriter<W, O>,
+    length_prefix: LengthPrefix,
+    value: &T,
+) -> io::Result<()> {
+    let previous = writer.length_prefix();
+    writer.set_length_prefix(length_prefix);
+    let result = writer.encode(value);
+    writer.set_length_prefix(previous);
+    result
+}
+
+/// Reads `N` bytes, failing if they differ from `expected`.
+pub fn decode_magic_bytes<R: Read, O: Endianness, const N: usize>(
+    reader: &mut NumberReader<R, O>,
+    expected: &[u8; N],
+) -> io::Result<()> {
+    let mut found = [0; N];
+    reader.read_exact(&mut found)?;
+    if &found != expected {
+        return Err(magic_error(expected, &found));
+    }
+    Ok(())
+}
+
+/// Reads a `T`, failing if it differs from `expected`.
+pub fn decode_magic<T, R, O>(reader: &mut NumberReader<R, O>, expected: T) -> io::Result<()>
+where
+    T: Decode + Debug + PartialEq,
+    R: Read,
+    O: Endianness,
+{
+    let found = T::decode(reader)?;
+    if found != expected {
+        return Err(magic_error(&expected, &found));
+    }
+    Ok(())
+}
+
+/// The size of the buffer padding is skipped or written through.
+const PAD_BUF_LEN: usize = 64;
+
+/// Reads and discards `len` bytes of padding.
+pub fn skip<R: Read, O: Endianness>(reader: &mut NumberReader<R, O>, len: u64) -> io::Result<()> {
+    let mut buf = [0; PAD_BUF_LEN];
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = remaining.min(PAD_BUF_LEN as u64) as usize;
+        reader.read_exact(&mut buf[..n])?;
+        remaining -= n as u64;
+    }
+    Ok(())
+}
+
+/// Writes `len` zero bytes of padding.
+pub fn pad<W: Write, O: Endianness>(writer: &mut NumberWriter<W, O>, len: u64) -> io::Result<()> {
+    let buf = [0; PAD_BUF_LEN];
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = remaining.min(PAD_BUF_LEN as u64) as usize;
+        writer.write_all(&buf[..n])?;
+        remaining -= n as u64;
+    }
+    Ok(())
+}
@@ -0,0 +1,261 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::io::{Result, Write};
+
+use crate::order::{ByteOrder, Endianness};
+use crate::prefix::LengthPrefix;
+use crate::write::NumberWriter;
+
+/// A trait for types which can be written to a [`NumberWriter`].
+///
+/// This trait is implemented for every primitive number type, `bool`, `char`,
+/// `Option<T>`, arrays, tuples of up to 12 elements, and for the
+/// length-prefixed types `[T]`, `Vec<T>`, `str`, and `String`. It can be
+/// implemented for other types to describe how they are composed of these.
+///
+/// Values are usually written with [`NumberWriter::encode`].
+///
+/// # Examples
+///
+/// Writing a record described as a tuple in one call:
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, NumberWriter};
+///
+/// fn main() -> io::Result<()> {
+///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+///     writer.encode(&(0x1234u16, true, "hi"))?;
+///     assert_eq!(
+///         writer.into_inner(),
+///         vec![0x12, 0x34, 0x01, 0x00, 0x00, 0x00, 0x02, b'h', b'i']
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// Implementing `Encode` for a custom type:
+///
+/// ```
+/// use std::io;
+/// use byte_order::io::Write;
+/// use byte_order::{ByteOrder, Encode, Endianness, NumberWriter};
+///
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// impl Encode for Point {
+///     fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> io::Result<()> {
+///         writer.encode(&self.x)?;
+///         writer.encode(&self.y)
+///     }
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+///     writer.encode(&Point { x: 1, y: -1 })?;
+///     assert_eq!(writer.into_inner(), vec![0x00, 0x01, 0xFF, 0xFF]);
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`NumberWriter::encode`]: crate::NumberWriter::encode
+pub trait Encode {
+    /// Writes this value to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Implementations should propagate any error recieved from `writer`, and
+    /// should return an error of the kind [`ErrorKind::InvalidInput`] if this
+    /// value cannot be represented.
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()>;
+}
+
+macro_rules! impl_encode_number {
+    ($($ty:ty),*) => {
+        $(
+            impl Encode for $ty {
+                #[inline]
+                fn encode<W: Write, O: Endianness>(
+                    &self,
+                    writer: &mut NumberWriter<W, O>,
+                ) -> Result<()> {
+                    writer.write_number(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+/// A `bool` is written as a single byte, either `0` or `1`.
+impl Encode for bool {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.write_u8(u8::from(*self))
+    }
+}
+
+/// A `char` is written as an unsigned 32-bit integer.
+impl Encode for char {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.write_u32(u32::from(*self))
+    }
+}
+
+/// An `Option<T>` is written as a `bool` which is `true` if a `T` follows it.
+impl<T: Encode> Encode for Option<T> {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        match self {
+            Some(value) => {
+                writer.encode(&true)?;
+                writer.encode(value)
+            }
+            None => writer.encode(&false),
+        }
+    }
+}
+
+/// An array is written as each of its elements in order, without a length
+/// prefix.
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        for elem in self {
+            writer.encode(elem)?;
+        }
+        Ok(())
+    }
+}
+
+/// A slice is written as a length prefix, followed by each of its elements.
+impl<T: Encode> Encode for [T] {
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.write_length(self.len())?;
+        for elem in self {
+            writer.encode(elem)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Vec<T>` is written as a length prefix, followed by each of its elements.
+#[cfg(feature = "alloc")]
+impl<T: Encode> Encode for Vec<T> {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.encode(self.as_slice())
+    }
+}
+
+/// A `str` is written as a length prefix, followed by its bytes.
+impl Encode for str {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.write_length(self.len())?;
+        writer.write_all(self.as_bytes())
+    }
+}
+
+/// A `String` is written as a length prefix, followed by its bytes.
+#[cfg(feature = "alloc")]
+impl Encode for String {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        writer.encode(self.as_str())
+    }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+    #[inline]
+    fn encode<W: Write, O: Endianness>(&self, writer: &mut NumberWriter<W, O>) -> Result<()> {
+        (**self).encode(writer)
+    }
+}
+
+macro_rules! impl_encode_tuple {
+    ($($name:ident $idx:tt)*) => {
+        /// A tuple is written as each of its elements in order.
+        impl<$($name: Encode),*> Encode for ($($name,)*) {
+            #[inline]
+            #[allow(unused_variables)]
+            fn encode<W: Write, O: Endianness>(
+                &self,
+                writer: &mut NumberWriter<W, O>,
+            ) -> Result<()> {
+                $(writer.encode(&self.$idx)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_encode_tuple!();
+impl_encode_tuple!(A 0);
+impl_encode_tuple!(A 0 B 1);
+impl_encode_tuple!(A 0 B 1 C 2);
+impl_encode_tuple!(A 0 B 1 C 2 D 3);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10);
+impl_encode_tuple!(A 0 B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11);
+
+/// Returns the number of bytes that `value` is encoded into by a
+/// [`NumberWriter`] with the given settings, without writing them anywhere.
+///
+/// This lets writers into a bounded buffer check that the whole value fits
+/// before any of it is written.
+pub(crate) fn encoded_len<T: Encode + ?Sized>(
+    value: &T,
+    order: ByteOrder,
+    length_prefix: LengthPrefix,
+) -> Result<usize> {
+    let mut writer = NumberWriter::with_order(order, Counter(0));
+    writer.set_length_prefix(length_prefix);
+    writer.encode(value)?;
+    Ok(writer.into_inner().0)
+}
+
+/// A sink which discards every byte written to it, counting them instead.
+struct Counter(usize);
+
+#[cfg(feature = "std")]
+impl std::io::Write for Counter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for Counter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// An error returned by a [`NumberReader`] with [error context] enabled,
+/// which describes where and what it failed to read.
+///
+/// A `DecodeError` is returned inside an [`io::Error`] of the same
+/// [`ErrorKind`] as the error that caused it, so the `read_*` methods keep
+/// their signatures. It can be recovered with [`DecodeError::from_io_error`],
+/// and the error that caused it is its [`source`].
+///
+/// When one `read_*` method is built on others, such as
+/// [`read_uleb128_u32`], which reads one byte at a time, the error describes
+/// the outermost of them.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Cursor, ErrorKind};
+/// use byte_order::{ByteOrder, DecodeError, NumberReader};
+///
+/// let src = Cursor::new(vec![0x00, 0x01, 0x02, 0x03, 0x04]);
+/// let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+/// reader.set_error_context(true);
+/// reader.read_u16().unwrap();
+///
+/// let err = reader.with_field("header.len", |reader| reader.read_u32()).unwrap_err();
+/// assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+///
+/// let decode_err = DecodeError::from_io_error(&err).unwrap();
+/// assert_eq!(2, decode_err.offset());
+/// assert_eq!("u32", decode_err.type_name());
+/// assert_eq!(Some(4), decode_err.width());
+/// assert_eq!(3, decode_err.available());
+/// assert_eq!(Some("header.len"), decode_err.path());
+/// assert_eq!(
+///     "failed to read u32 at offset 2 in `header.len`: \
+///      expected 4 bytes, 3 available: failed to fill whole buffer",
+///     decode_err.to_string()
+/// );
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [error context]: crate::NumberReader::set_error_context
+/// [`ErrorKind`]: io::ErrorKind
+/// [`source`]: Error::source
+/// [`read_uleb128_u32`]: crate::NumberReader::read_uleb128_u32
+#[derive(Debug)]
+pub struct DecodeError {
+    offset: u64,
+    type_name: &'static str,
+    width: Option<usize>,
+    available: usize,
+    path: String,
+    source: io::Error,
+}
+
+impl DecodeError {
+    /// Returns the `DecodeError` inside `err`, or `None` if `err` was not
+    /// returned by a [`NumberReader`] with error context enabled.
+    ///
+    /// [`NumberReader`]: crate::NumberReader
+    #[inline]
+    pub fn from_io_error(err: &io::Error) -> Option<&DecodeError> {
+        err.get_ref()?.downcast_ref()
+    }
+
+    /// Returns the kind of the error that caused this one.
+    #[inline]
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+
+    /// Returns the position of the reader when the failed read began.
+    ///
+    /// The offset is counted in the same way as [`NumberReader::position`],
+    /// from when position tracking was enabled or last reset.
+    ///
+    /// [`NumberReader::position`]: crate::NumberReader::position
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the name of the type that was requested, such as `u32`,
+    /// `[f64]`, or `uleb128 u64`.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the number of bytes that the read required, or `None` if the
+    /// type has a variable-length encoding.
+    #[inline]
+    pub fn width(&self) -> Option<usize> {
+        self.width
+    }
+
+    /// Returns the number of bytes that were consumed before the read failed,
+    /// which for an unexpected end of file is the number of bytes that were
+    /// available.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.available
+    }
+
+    /// Returns the path of the field that was being read, with the names
+    /// given to [`NumberReader::with_field`] joined by `.`, or `None` if no
+    /// field was named.
+    ///
+    /// [`NumberReader::with_field`]: crate::NumberReader::with_field
+    #[inline]
+    pub fn path(&self) -> Option<&str> {
+        if self.path.is_empty() {
+            None
+        } else {
+            Some(&self.path)
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to read {} at offset {}",
+            self.type_name, self.offset
+        )?;
+        if let Some(path) = self.path() {
+            write!(f, " in `{}`", path)?;
+        }
+        match self.width {
+            Some(width) => write!(
+                f,
+                ": expected {} bytes, {} available",
+                width, self.available
+            )?,
+            None => write!(f, ": {} bytes read", self.available)?,
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<DecodeError> for io::Error {
+    #[inline]
+    fn from(err: DecodeError) -> io::Error {
+        io::Error::new(err.kind(), err)
+    }
+}
+
+/// The state of a [`NumberReader`] with error context enabled.
+///
+/// [`NumberReader`]: crate::NumberReader
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ErrorContext {
+    /// The names of the fields being read, from the outermost inwards.
+    fields: Vec<String>,
+    /// The number of `read_*` methods currently running, of which only the
+    /// outermost describes its errors.
+    depth: usize,
+}
+
+impl ErrorContext {
+    /// Returns the context of a reader nested inside the one with this
+    /// context, which starts within the same fields.
+    #[inline]
+    pub(crate) fn nested(&self) -> ErrorContext {
+        ErrorContext {
+            fields: self.fields.clone(),
+            depth: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push_field(&mut self, name: &str) {
+        self.fields.push(name.to_owned());
+    }
+
+    #[inline]
+    pub(crate) fn pop_field(&mut self) {
+        self.fields.pop();
+    }
+
+    /// Marks the start of a `read_*` method, returning whether it is the
+    /// outermost one.
+    #[inline]
+    pub(crate) fn enter(&mut self) -> bool {
+        self.depth += 1;
+        self.depth == 1
+    }
+
+    #[inline]
+    pub(crate) fn exit(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Wraps `source` in a [`DecodeError`], unless it already is one.
+    pub(crate) fn describe(
+        &self,
+        source: io::Error,
+        offset: u64,
+        type_name: &'static str,
+        width: Option<usize>,
+        available: usize,
+    ) -> io::Error {
+        if DecodeError::from_io_error(&source).is_some() {
+            return source;
+        }
+        DecodeError {
+            offset,
+            type_name,
+            width,
+            available,
+            path: self.fields.join("."),
+            source,
+        }
+        .into()
+    }
+}
@@ -0,0 +1,127 @@
+//! Conversions between `f32` and the 16-bit floating point formats, which
+//! have no primitive type in Rust.
+//!
+//! Half-precision values are IEEE754 binary16 numbers, with 5 exponent bits
+//! and 10 mantissa bits, and brain floating point values are the upper 16 bits
+//! of an IEEE754 binary32 number. All conversions from `f32` round to the
+//! nearest representable value, with ties rounding to even.
+
+use crate::order::ByteOrder;
+use crate::pod;
+
+/// Converts the bits of a half-precision float to an `f32`. This conversion
+/// is exact.
+#[inline]
+pub(crate) fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exp = u32::from(bits & 0x7C00) >> 10;
+    let man = u32::from(bits & 0x03FF);
+
+    if exp == 0x1F {
+        // Infinity stays infinity and NaN stays NaN, keeping the payload.
+        return f32::from_bits(sign | 0x7F80_0000 | (man << 13));
+    }
+
+    if exp == 0 {
+        if man == 0 {
+            return f32::from_bits(sign);
+        }
+        // Every half-precision subnormal is a normal single-precision number,
+        // so shift the mantissa until its leading one becomes implicit.
+        let shift = man.leading_zeros() - 21;
+        let man = (man << shift) & 0x03FF;
+        let exp = 127 - 15 + 1 - shift;
+        return f32::from_bits(sign | (exp << 23) | (man << 13));
+    }
+
+    f32::from_bits(sign | ((exp + 127 - 15) << 23) | (man << 13))
+}
+
+/// Converts an `f32` to the bits of the nearest half-precision float.
+#[inline]
+pub(crate) fn f32_to_f16(n: f32) -> u16 {
+    let bits = n.to_bits();
+    let sign = ((bits & 0x8000_0000) >> 16) as u16;
+    let exp = ((bits & 0x7F80_0000) >> 23) as i32;
+    let man = bits & 0x007F_FFFF;
+
+    if exp == 0xFF {
+        if man == 0 {
+            return sign | 0x7C00;
+        }
+        // Keep as much of the payload as fits, but always produce a quiet
+        // NaN so that the result cannot become infinity.
+        return sign | 0x7E00 | (man >> 13) as u16;
+    }
+
+    let exp = exp - 127 + 15;
+    if exp >= 0x1F {
+        return sign | 0x7C00;
+    }
+
+    if exp <= 0 {
+        // The result is subnormal or zero. Values smaller than half of the
+        // smallest subnormal round to zero.
+        if exp < -10 {
+            return sign;
+        }
+        let man = man | 0x0080_0000;
+        let shift = (14 - exp) as u32;
+        return sign | round_shift(man, shift) as u16;
+    }
+
+    // Rounding may carry into the exponent, which correctly produces the next
+    // power of two or infinity.
+    sign | (((exp as u32) << 10) + round_shift(man, 13)) as u16
+}
+
+/// Converts the bits of a brain floating point number to an `f32`. This
+/// conversion is exact.
+#[inline]
+pub(crate) fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits(u32::from(bits) << 16)
+}
+
+/// Converts an `f32` to the bits of the nearest brain floating point number.
+#[inline]
+pub(crate) fn f32_to_bf16(n: f32) -> u16 {
+    let bits = n.to_bits();
+    if n.is_nan() {
+        return (bits >> 16) as u16 | 0x0040;
+    }
+    round_shift(bits, 16) as u16
+}
+
+/// Widens the 16-bit floating point numbers packed at the front of `dst`, in
+/// the given byte order, to fill all of `dst` with `to_f32`.
+#[inline]
+pub(crate) fn widen_in_place(dst: &mut [f32], order: ByteOrder, to_f32: fn(u16) -> f32) {
+    let len = dst.len();
+    let bytes = pod::as_bytes_mut(dst);
+    // The narrow numbers are packed at the front of the slice, so they are
+    // widened back to front. This way, each number is read before the slot it
+    // occupies is overwritten by a wider one.
+    for i in (0..len).rev() {
+        let buf = [bytes[2 * i], bytes[2 * i + 1]];
+        let n = if let ByteOrder::LE = order {
+            u16::from_le_bytes(buf)
+        } else {
+            u16::from_be_bytes(buf)
+        };
+        bytes[4 * i..4 * i + 4].copy_from_slice(&to_f32(n).to_ne_bytes());
+    }
+}
+
+/// Shifts `n` right by `shift` bits, rounding to the nearest integer with
+/// ties rounding to even.
+#[inline]
+fn round_shift(n: u32, shift: u32) -> u32 {
+    let half = 1 << (shift - 1);
+    let rest = n & ((1 << shift) - 1);
+    let n = n >> shift;
+    if rest > half || (rest == half && n & 1 == 1) {
+        n + 1
+    } else {
+        n
+    }
+}
@@ -0,0 +1,145 @@
+//! Asynchronous readers and writers built on the [`AsyncRead`] and
+//! [`AsyncWrite`] traits of `futures-io`.
+//!
+//! These traits are independent of any runtime, so the readers and writers of
+//! this module can be used with `async-std`, `smol`, or any other runtime whose
+//! I/O types implement them. [`AsyncNumberReader`] and [`AsyncNumberWriter`]
+//! provide every `read_*` and `write_*` method of [`NumberReader`] and
+//! [`NumberWriter`] as an `async fn`, with the same byte order and length
+//! prefix settings.
+//!
+//! This module is only available with the `futures` feature enabled.
+//!
+//! [`NumberReader`]: crate::NumberReader
+//! [`NumberWriter`]: crate::NumberWriter
+
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use futures_io::{AsyncRead, AsyncWrite};
+
+use crate::async_io::{async_reader, async_writer};
+use crate::io;
+use crate::order::Endianness;
+
+async_reader! {
+    /// An `AsyncNumberReader` wraps a `futures-io` [`AsyncRead`] and provides
+    /// methods for reading numbers.
+    ///
+    /// Like a [`NumberReader`], an `AsyncNumberReader` takes byte order as a
+    /// parameter upon initialization. Since its methods take `&mut self`, the
+    /// underlying reader must be [`Unpin`]; other readers can be wrapped in a
+    /// `Box::pin` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::futures::AsyncNumberReader;
+    /// use byte_order::ByteOrder;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     futures_executor::block_on(async {
+    ///         let src: &[u8] = &[0x12, 0x34, 0xE5, 0x8E, 0x26];
+    ///         let mut reader = AsyncNumberReader::with_order(ByteOrder::BE, src);
+    ///
+    ///         assert_eq!(0x1234u16, reader.read_u16().await?);
+    ///         assert_eq!(624485u32, reader.read_uleb128_u32().await?);
+    ///
+    ///         Ok(())
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// [`NumberReader`]: crate::NumberReader
+    AsyncRead
+}
+
+async_writer! {
+    /// An `AsyncNumberWriter` wraps a `futures-io` [`AsyncWrite`] and provides
+    /// methods for writing numbers.
+    ///
+    /// Like a [`NumberWriter`], an `AsyncNumberWriter` takes byte order as a
+    /// parameter upon initialization. Since its methods take `&mut self`, the
+    /// underlying writer must be [`Unpin`]; other writers can be wrapped in a
+    /// `Box::pin` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::futures::AsyncNumberWriter;
+    /// use byte_order::ByteOrder;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     futures_executor::block_on(async {
+    ///         let mut writer = AsyncNumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///
+    ///         writer.write_u16(0x1234).await?;
+    ///         writer.write_uleb128_u32(624485).await?;
+    ///         assert_eq!(writer.into_inner(), vec![0x34, 0x12, 0xE5, 0x8E, 0x26]);
+    ///
+    ///         Ok(())
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// [`NumberWriter`]: crate::NumberWriter
+    AsyncWrite
+}
+
+#[inline]
+fn poll_read_some<R: AsyncRead + Unpin>(
+    src: &mut R,
+    cx: &mut Context<'_>,
+    buf: &mut [u8],
+) -> Poll<io::Result<usize>> {
+    Pin::new(src).poll_read(cx, buf)
+}
+
+#[inline]
+fn poll_write_some<W: AsyncWrite + Unpin>(
+    dst: &mut W,
+    cx: &mut Context<'_>,
+    buf: &[u8],
+) -> Poll<io::Result<usize>> {
+    Pin::new(dst).poll_write(cx, buf)
+}
+
+impl<R: AsyncRead + Unpin, O: Endianness + Unpin> AsyncRead for AsyncNumberReader<R, O> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if !this.pending.is_empty() {
+            return Poll::Ready(Ok(this.pending.read_buffered(buf)));
+        }
+        Pin::new(&mut this.inner).poll_read(cx, buf)
+    }
+}
+
+impl<W: AsyncWrite + Unpin, O: Endianness + Unpin> AsyncWrite for AsyncNumberWriter<W, O> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
@@ -0,0 +1,329 @@
+//! The I/O traits and error type that [`NumberReader`] and [`NumberWriter`]
+//! are built on.
+//!
+//! With the `std` feature enabled, which it is by default, [`Error`],
+//! [`ErrorKind`], and [`Result`] are those of [`std::io`], and [`Read`] and
+//! [`Write`] are implemented for every type that implements their
+//! counterpart in [`std::io`]. Any reader or writer from the standard library
+//! can then be used directly.
+//!
+//! Without the `std` feature, this module provides a minimal error type of its
+//! own, and [`Read`] and [`Write`] are implemented for byte slices, mutable
+//! references, and, with the `alloc` feature enabled, `Vec<u8>`.
+//!
+//! [`NumberReader`]: crate::NumberReader
+//! [`NumberWriter`]: crate::NumberWriter
+//! [`std::io`]: https://doc.rust-lang.org/std/io/index.html
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Result};
+
+/// A source of bytes.
+///
+/// This is a minimal counterpart to [`std::io::Read`], and with the `std`
+/// feature enabled, it's implemented for every type that implements that
+/// trait.
+///
+/// [`std::io::Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+pub trait Read {
+    /// Pulls some bytes from this source into the specified buffer, returning
+    /// how many bytes were read.
+    ///
+    /// A return value of `0` indicates that the end of the source has been
+    /// reached, or that `buf` is empty.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`], the read may be retried.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+    /// Reads the exact number of bytes required to fill `buf`.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the source is reached before `buf` is filled, an error
+    /// of the kind [`ErrorKind::UnexpectedEof`] is returned, and the contents
+    /// of `buf` are unspecified. Any error other than one of the kind
+    /// [`ErrorKind::Interrupted`] returned by [`read`] is propagated.
+    ///
+    /// [`read`]: Read::read
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match self.read(buf) {
+                Ok(0) => break,
+                Ok(n) => buf = &mut buf[n..],
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if buf.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ))
+        }
+    }
+}
+
+/// A sink of bytes.
+///
+/// This is a minimal counterpart to [`std::io::Write`], and with the `std`
+/// feature enabled, it's implemented for every type that implements that
+/// trait.
+///
+/// [`std::io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+pub trait Write {
+    /// Writes some bytes from `buf` into this sink, returning how many bytes
+    /// were written.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`], the write may be retried.
+    fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+    /// Writes all of `buf` into this sink.
+    ///
+    /// # Errors
+    ///
+    /// If [`write`] returns `Ok(0)` before all of `buf` is written, an error
+    /// of the kind [`ErrorKind::WriteZero`] is returned. Any error other than
+    /// one of the kind [`ErrorKind::Interrupted`] returned by [`write`] is
+    /// propagated.
+    ///
+    /// [`write`]: Write::write
+    fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match self.write(buf) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => buf = &buf[n..],
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Flushes any bytes buffered by this sink to their destination.
+    ///
+    /// # Errors
+    ///
+    /// It's considered an error if not all bytes could be written.
+    fn flush(&mut self) -> Result<()>;
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read + ?Sized> Read for R {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        std::io::Read::read(self, buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        std::io::Read::read_exact(self, buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> Write for W {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        std::io::Write::write(self, buf)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        std::io::Write::write_all(self, buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        std::io::Write::flush(self)
+    }
+}
+
+/// A specialized [`Result`](core::result::Result) type for I/O operations.
+#[cfg(not(feature = "std"))]
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A list specifying general categories of I/O error.
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Data not valid for the operation were encountered.
+    InvalidData,
+    /// A parameter was incorrect.
+    InvalidInput,
+    /// The operation was interrupted, and can typically be retried.
+    Interrupted,
+    /// An error returned when an operation could not be completed because an
+    /// "end of file" was reached prematurely.
+    UnexpectedEof,
+    /// An error returned when an operation could not be completed because a
+    /// call to [`Write::write`] returned `Ok(0)`.
+    WriteZero,
+    /// A custom error that does not fall under any other I/O error kind.
+    Other,
+}
+
+#[cfg(not(feature = "std"))]
+impl ErrorKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::InvalidData => "invalid data",
+            ErrorKind::InvalidInput => "invalid input parameter",
+            ErrorKind::Interrupted => "operation interrupted",
+            ErrorKind::UnexpectedEof => "unexpected end of file",
+            ErrorKind::WriteZero => "write zero",
+            ErrorKind::Other => "other error",
+        }
+    }
+}
+
+/// The error type for I/O operations of the [`Read`] and [`Write`] traits.
+///
+/// Unlike its counterpart in the standard library, this error only carries a
+/// static message, so it never allocates.
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    message: &'static str,
+}
+
+#[cfg(not(feature = "std"))]
+impl Error {
+    /// Creates a new I/O error from a known kind of error and a message.
+    pub const fn new(kind: ErrorKind, message: &'static str) -> Error {
+        Error { kind, message }
+    }
+
+    /// Returns the corresponding [`ErrorKind`] for this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error::new(kind, kind.as_str())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<R: Read + ?Sized> Read for &mut R {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        (**self).read(buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        (**self).read_exact(buf)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Read for &[u8] {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let len = buf.len().min(self.len());
+        let (head, tail) = self.split_at(len);
+        buf[..len].copy_from_slice(head);
+        *self = tail;
+        Ok(len)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.len() > self.len() {
+            *self = &self[self.len()..];
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+        let (head, tail) = self.split_at(buf.len());
+        buf.copy_from_slice(head);
+        *self = tail;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<W: Write + ?Sized> Write for &mut W {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        (**self).write(buf)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        (**self).write_all(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for &mut [u8] {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = buf.len().min(self.len());
+        let (head, tail) = core::mem::take(self).split_at_mut(len);
+        head.copy_from_slice(&buf[..len]);
+        *self = tail;
+        Ok(len)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+impl Write for Vec<u8> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
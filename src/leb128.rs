@@ -0,0 +1,150 @@
+use crate::io::{self, ErrorKind};
+
+/// The maximum number of bytes a LEB128 encoding of a 128-bit integer may
+/// occupy.
+pub(crate) const MAX_LEN: usize = 19;
+
+/// An incremental decoder for a single LEB128-encoded integer.
+///
+/// Bytes are fed to the decoder one at a time with [`push`] until it yields
+/// the decoded value. Signed values are sign-extended to 128 bits, so the
+/// result may be truncated to the target width with an `as` cast.
+///
+/// [`push`]: Decoder::push
+pub(crate) struct Decoder {
+    bits: u32,
+    signed: bool,
+    strict: bool,
+    shift: u32,
+    value: u128,
+    last: u8,
+}
+
+impl Decoder {
+    /// Creates a decoder for an unsigned integer that is `bits` wide.
+    #[inline]
+    pub(crate) fn unsigned(bits: u32, strict: bool) -> Decoder {
+        Decoder {
+            bits,
+            signed: false,
+            strict,
+            shift: 0,
+            value: 0,
+            last: 0,
+        }
+    }
+
+    /// Creates a decoder for a signed integer that is `bits` wide.
+    #[inline]
+    pub(crate) fn signed(bits: u32, strict: bool) -> Decoder {
+        Decoder {
+            signed: true,
+            ..Decoder::unsigned(bits, strict)
+        }
+    }
+
+    /// Feeds the next byte of the encoding to the decoder, returning the
+    /// decoded value once the final byte has been fed.
+    ///
+    /// # Errors
+    ///
+    /// An error of the kind [`ErrorKind::InvalidData`] is returned if the
+    /// encoded value does not fit in the target width, or if the decoder is
+    /// strict and the encoding is not the shortest possible one.
+    pub(crate) fn push(&mut self, byte: u8) -> io::Result<Option<u128>> {
+        if self.shift >= self.bits {
+            return Err(overflow());
+        }
+
+        let payload = byte & 0x7F;
+        let remaining = self.bits - self.shift;
+        if remaining < 7 {
+            // This is the last byte that may be present, so every bit that
+            // does not fit in the target width must be an extension of the
+            // value's sign (or zero, for unsigned integers).
+            let fits = if self.signed {
+                let upper = payload >> (remaining - 1);
+                upper == 0 || upper == 0x7F >> (remaining - 1)
+            } else {
+                payload >> remaining == 0
+            };
+            if !fits {
+                return Err(overflow());
+            }
+        }
+
+        self.value |= u128::from(payload) << self.shift;
+        if byte & 0x80 != 0 {
+            self.last = byte;
+            self.shift += 7;
+            return Ok(None);
+        }
+
+        if self.strict && self.shift > 0 && self.is_redundant(byte) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "non-canonical LEB128 encoding",
+            ));
+        }
+
+        let end = self.shift + 7;
+        if self.signed && payload & 0x40 != 0 && end < 128 {
+            self.value |= !0 << end;
+        }
+        Ok(Some(self.value))
+    }
+
+    /// Returns whether the final byte of the encoding, `byte`, could have been
+    /// omitted.
+    #[inline]
+    fn is_redundant(&self, byte: u8) -> bool {
+        let sign = self.last & 0x40 != 0;
+        if self.signed {
+            (byte == 0x00 && !sign) || (byte == 0x7F && sign)
+        } else {
+            byte == 0x00
+        }
+    }
+}
+
+/// Encodes `n` as an unsigned LEB128 integer into `buf`, returning the number
+/// of bytes used.
+#[inline]
+pub(crate) fn encode_unsigned(mut n: u128, buf: &mut [u8; MAX_LEN]) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = (n & 0x7F) as u8;
+        n >>= 7;
+        if n == 0 {
+            buf[len] = byte;
+            return len + 1;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Encodes `n` as a signed LEB128 integer into `buf`, returning the number of
+/// bytes used.
+#[inline]
+pub(crate) fn encode_signed(mut n: i128, buf: &mut [u8; MAX_LEN]) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = (n & 0x7F) as u8;
+        n >>= 7;
+        if (n == 0 && byte & 0x40 == 0) || (n == -1 && byte & 0x40 != 0) {
+            buf[len] = byte;
+            return len + 1;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+#[inline]
+fn overflow() -> io::Error {
+    io::Error::new(
+        ErrorKind::InvalidData,
+        "LEB128 value overflows the target integer type",
+    )
+}
@@ -9,6 +9,56 @@
 //! the endianness that [`NumberReader`] and [`NumberWriter`] structures perform
 //! their operations.
 //!
+//! For data which is already in memory, [`SliceReader`] reads numbers directly
+//! from a byte slice, and can borrow sub-slices of it without copying. Its
+//! counterpart, [`SliceWriter`], writes numbers into a fixed-size buffer, and
+//! [`VecWriter`] appends them to a growable `Vec<u8>` without ever failing.
+//!
+//! When the endianness is known at compile time, the marker types
+//! [`BigEndian`] and [`LittleEndian`] can be used in place of a [`ByteOrder`]
+//! value. Both are accepted anywhere a byte order is expected through the
+//! [`Endianness`] trait, and with a marker type no operation branches on the
+//! byte order at runtime. When the byte order is only revealed by the data
+//! itself, a [`Magic`] number at its start can be used to detect it.
+//!
+//! Besides LEB128, variable-length integers can be read and written in the
+//! encodings of QUIC, SQLite, Bitcoin, and MIDI, or in any other encoding
+//! described by the `VarintScheme` trait of the [`varint`] module.
+//!
+//! Beyond single numbers, the [`Decode`] and [`Encode`] traits describe how
+//! composite values, such as tuples, arrays, and length-prefixed strings, are
+//! read from a [`NumberReader`] and written to a [`NumberWriter`]. With the
+//! `derive` feature enabled, both traits can be derived for structs and enums,
+//! with attributes to control the byte order, padding, and length prefix of
+//! each field.
+//!
+//! # Features
+//!
+//! The `std` feature, which is enabled by default, makes every reader and
+//! writer of the standard library usable with this crate. Without it, the
+//! crate is `no_std`, and readers and writers implement the minimal [`Read`]
+//! and [`Write`] traits of the [`io`] module instead. The `alloc` feature,
+//! which `std` enables, provides [`VecWriter`] and the [`Decode`] and
+//! [`Encode`] implementations of `Vec<T>` and `String`. The `std` feature
+//! also provides `DecodeError`, with which a [`NumberReader`] can describe
+//! the offset, type, and field of a failed read.
+//!
+//! The `tokio` feature provides asynchronous counterparts to [`NumberReader`]
+//! and [`NumberWriter`] in the `byte_order::tokio` module, built on the
+//! `AsyncRead` and `AsyncWrite` traits of `tokio`. The `futures` feature does
+//! the same in the `byte_order::futures` module for the runtime-agnostic
+//! traits of `futures-io`, which `async-std` and `smol` use.
+//!
+//! The `bytes` feature provides `NumberBuf` and `NumberBufMut`, which read
+//! numbers from any `bytes::Buf` and write them to any `bytes::BufMut`, and
+//! split payloads off a `Bytes` frame without copying them. The `codec`
+//! feature builds on it with a `tokio-util` codec for length-prefixed frames in
+//! the `byte_order::codec` module.
+//!
+//! The `serde` feature provides a `serde` serializer and deserializer in the
+//! `byte_order::serde` module, which lay out any serializable value in a
+//! compact binary form with a chosen byte order.
+//!
 //! # Examples
 //!
 //! Read unsigned 16-bit big-endian integers from a reader:
@@ -64,11 +114,75 @@
 //! [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
 //! [writers]: https://doc.rust-lang.org/std/io/trait.Write.html
 //! [`ByteOrder`]: crate::ByteOrder
+//! [`BigEndian`]: crate::BigEndian
+//! [`LittleEndian`]: crate::LittleEndian
+//! [`Endianness`]: crate::Endianness
+//! [`Decode`]: crate::Decode
+//! [`Encode`]: crate::Encode
+//! [`SliceReader`]: crate::SliceReader
+//! [`SliceWriter`]: crate::SliceWriter
+//! [`VecWriter`]: crate::VecWriter
+//! [`Read`]: crate::io::Read
+//! [`Write`]: crate::io::Write
+//! [`io`]: crate::io
+//! [`varint`]: crate::varint
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(any(feature = "futures", feature = "tokio"))]
+mod async_io;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "codec")]
+pub mod codec;
+mod decode;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod derive;
+mod encode;
+#[cfg(feature = "std")]
+mod error;
+mod float;
+#[cfg(feature = "futures")]
+pub mod futures;
+pub mod io;
+mod leb128;
+mod magic;
+mod number;
 mod order;
+mod pod;
+mod prefix;
 mod read;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod slice_read;
+mod slice_write;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+pub mod varint;
+#[cfg(feature = "alloc")]
+mod vec_write;
 mod write;
+pub mod zigzag;
 
-pub use order::ByteOrder;
+#[cfg(feature = "bytes")]
+pub use buf::{NumberBuf, NumberBufMut};
+#[cfg(feature = "derive")]
+pub use byte_order_derive::{Decode, Encode};
+pub use decode::Decode;
+pub use encode::Encode;
+#[cfg(feature = "std")]
+pub use error::DecodeError;
+pub use magic::Magic;
+pub use number::Number;
+pub use order::{BigEndian, ByteOrder, Endianness, LittleEndian, NativeEndian};
+pub use prefix::LengthPrefix;
 pub use read::NumberReader;
+pub use slice_read::SliceReader;
+pub use slice_write::SliceWriter;
+#[cfg(feature = "alloc")]
+pub use vec_write::VecWriter;
 pub use write::NumberWriter;
@@ -0,0 +1,96 @@
+use core::mem;
+
+use crate::io;
+use crate::number::Number;
+use crate::order::ByteOrder;
+
+/// A magic number at the start of a file or stream, which reveals the byte
+/// order of the data that follows it.
+///
+/// Most formats, such as pcap with `0xA1B2C3D4` or UTF-16 with its byte
+/// order mark `0xFEFF`, write a single value in whichever byte order they use,
+/// which is what [`Magic::new`] describes. Formats which instead begin with a
+/// different mark for each byte order, such as TIFF with `II` or `MM`, are
+/// described by [`Magic::with_marks`].
+///
+/// A `Magic` is used by [`NumberReader::detect_order`] and
+/// [`NumberReader::peek_order`] to create a [`NumberReader`] with the detected
+/// byte order.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{ByteOrder, Magic};
+///
+/// let bom = Magic::new(0xFEFFu16);
+/// assert_eq!(Some(ByteOrder::BE), bom.detect(&[0xFE, 0xFF, b'h']));
+/// assert_eq!(Some(ByteOrder::LE), bom.detect(&[0xFF, 0xFE, b'h']));
+/// assert_eq!(None, bom.detect(&[b'h', 0x00]));
+///
+/// let tiff = Magic::with_marks(u16::from_be_bytes(*b"MM"), u16::from_le_bytes(*b"II"));
+/// assert_eq!(Some(ByteOrder::BE), tiff.detect(b"MM\0*"));
+/// assert_eq!(Some(ByteOrder::LE), tiff.detect(b"II*\0"));
+/// ```
+///
+/// [`NumberReader::detect_order`]: crate::NumberReader::detect_order
+/// [`NumberReader::peek_order`]: crate::NumberReader::peek_order
+/// [`NumberReader`]: crate::NumberReader
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Magic<T: Number> {
+    be: T,
+    le: T,
+}
+
+impl<T: Number> Magic<T> {
+    /// Creates a `Magic` for a value which a format writes in whichever byte
+    /// order it uses.
+    ///
+    /// The value must not read the same in both byte orders, as is the case
+    /// for `0x1221u16`, or else it always detects big-endian byte order.
+    #[inline]
+    pub fn new(magic: T) -> Magic<T> {
+        Magic {
+            be: magic,
+            le: magic,
+        }
+    }
+
+    /// Creates a `Magic` for a format which begins with `be_mark` when it uses
+    /// big-endian byte order and with `le_mark` when it uses little-endian
+    /// byte order, where each mark is read in its own byte order.
+    #[inline]
+    pub fn with_marks(be_mark: T, le_mark: T) -> Magic<T> {
+        Magic {
+            be: be_mark,
+            le: le_mark,
+        }
+    }
+
+    /// Returns the byte order revealed by the start of `bytes`, or `None` if
+    /// it does not match this magic number in either byte order.
+    ///
+    /// If `bytes` is shorter than the magic number, `None` is returned.
+    #[inline]
+    pub fn detect(&self, bytes: &[u8]) -> Option<ByteOrder> {
+        let bytes = bytes.get(..mem::size_of::<T>())?;
+        if self.be.to_bytes(ByteOrder::BE).as_ref() == bytes {
+            Some(ByteOrder::BE)
+        } else if self.le.to_bytes(ByteOrder::LE).as_ref() == bytes {
+            Some(ByteOrder::LE)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the byte order revealed by `bytes`, or an error if it does not
+    /// match this magic number in either byte order.
+    #[inline]
+    pub(crate) fn detect_or_err(&self, bytes: &[u8]) -> io::Result<ByteOrder> {
+        self.detect(bytes).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "magic number does not match either byte order",
+            )
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use core::mem;
+
+use crate::order::ByteOrder;
+
+/// A trait for the primitive number types that [`NumberReader`] and
+/// [`NumberWriter`] structures can read and write.
+///
+/// This trait is implemented for every integer and floating point type in
+/// Rust except for those with platform-dependent sizes (`usize` and `isize`).
+/// It allows code to be generic over the type of number being read or
+/// written, through methods like [`NumberReader::read_number`] and
+/// [`NumberWriter::write_number`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// Writing a decoder which is generic over the type of number it reads:
+///
+/// ```
+/// use std::io::{self, Cursor, Read};
+/// use byte_order::{ByteOrder, Number, NumberReader};
+///
+/// fn read_pair<T: Number, R: Read>(reader: &mut NumberReader<R>) -> io::Result<(T, T)> {
+///     Ok((reader.read_number()?, reader.read_number()?))
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+///
+///     let mut reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+///     assert_eq!((0x1234u16, 0x5678u16), read_pair(&mut reader)?);
+///
+///     let mut reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+///     assert_eq!((0x12u8, 0x34u8), read_pair(&mut reader)?);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// Converting numbers to and from bytes directly:
+///
+/// ```
+/// use byte_order::{ByteOrder, Number};
+///
+/// assert_eq!([0x12, 0x34], 0x1234u16.to_bytes(ByteOrder::BE));
+/// assert_eq!(0x3412u16, u16::from_bytes([0x12, 0x34], ByteOrder::LE));
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`NumberReader::read_number`]: crate::NumberReader::read_number
+/// [`NumberWriter::write_number`]: crate::NumberWriter::write_number
+pub trait Number: private::Sealed {
+    /// The byte array that holds the memory representation of this type, such
+    /// as `[u8; 4]` for `u32`.
+    type Bytes: AsRef<[u8]> + AsMut<[u8]> + Copy + Default;
+
+    /// Creates a number from its memory representation in the given byte
+    /// order.
+    fn from_bytes(bytes: Self::Bytes, order: ByteOrder) -> Self;
+
+    /// Returns the memory representation of this number in the given byte
+    /// order.
+    fn to_bytes(self, order: ByteOrder) -> Self::Bytes;
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl Number for $ty {
+                type Bytes = [u8; mem::size_of::<$ty>()];
+
+                #[inline]
+                fn from_bytes(bytes: Self::Bytes, order: ByteOrder) -> Self {
+                    match order {
+                        ByteOrder::BE => <$ty>::from_be_bytes(bytes),
+                        ByteOrder::LE => <$ty>::from_le_bytes(bytes),
+                    }
+                }
+
+                #[inline]
+                fn to_bytes(self, order: ByteOrder) -> Self::Bytes {
+                    match order {
+                        ByteOrder::BE => self.to_be_bytes(),
+                        ByteOrder::LE => self.to_le_bytes(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+pub(crate) mod private {
+    use crate::pod::Pod;
+
+    pub trait Sealed: Pod {
+        /// Reverses the byte order of this number.
+        fn swap_bytes(self) -> Self;
+    }
+
+    macro_rules! impl_sealed_int {
+        ($($ty:ty),*) => {
+            $(
+                impl Sealed for $ty {
+                    #[inline]
+                    fn swap_bytes(self) -> Self {
+                        <$ty>::swap_bytes(self)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_sealed_int!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+    impl Sealed for f32 {
+        #[inline]
+        fn swap_bytes(self) -> Self {
+            f32::from_bits(self.to_bits().swap_bytes())
+        }
+    }
+
+    impl Sealed for f64 {
+        #[inline]
+        fn swap_bytes(self) -> Self {
+            f64::from_bits(self.to_bits().swap_bytes())
+        }
+    }
+}
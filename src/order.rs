@@ -23,7 +23,7 @@
 ///
 /// [`NumberReader`]: crate::NumberReader
 /// [`NumberWriter`]: crate::NumberWriter
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum ByteOrder {
     BE,
     LE,
@@ -52,3 +52,132 @@ impl ByteOrder {
         ByteOrder::LE
     };
 }
+
+/// A trait for types which specify the byte order that a [`NumberReader`] or
+/// [`NumberWriter`] performs its operations with.
+///
+/// This trait is implemented by [`ByteOrder`], which chooses the byte order at
+/// runtime, and by the marker types [`BigEndian`] and [`LittleEndian`], which
+/// choose it at compile time. When a marker type is used, the byte order is
+/// known to the compiler, so no runtime branching takes place when reading or
+/// writing numbers.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::{BigEndian, ByteOrder, NumberReader};
+///
+/// fn main() -> io::Result<()> {
+///     let src = Cursor::new(vec![0x12, 0x34]);
+///
+///     let mut static_reader = NumberReader::with_order(BigEndian, src.clone());
+///     assert_eq!(0x1234u16, static_reader.read_u16()?);
+///
+///     let mut dynamic_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+///     assert_eq!(0x1234u16, dynamic_reader.read_u16()?);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [`NumberWriter`]: crate::NumberWriter
+pub trait Endianness: private::Sealed {
+    /// Returns the byte order that is specified by this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, Endianness, LittleEndian};
+    ///
+    /// assert_eq!(ByteOrder::BE, BigEndian.byte_order());
+    /// assert_eq!(ByteOrder::LE, LittleEndian.byte_order());
+    /// assert_eq!(ByteOrder::LE, ByteOrder::LE.byte_order());
+    /// ```
+    fn byte_order(&self) -> ByteOrder;
+}
+
+/// A marker type which specifies big-endian byte order at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{BigEndian, NumberWriter};
+///
+/// let be_writer = NumberWriter::with_order(BigEndian, vec![]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigEndian;
+
+/// A marker type which specifies little-endian byte order at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{LittleEndian, NumberWriter};
+///
+/// let le_writer = NumberWriter::with_order(LittleEndian, vec![]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LittleEndian;
+
+/// The marker type for the native-endian serialization of the target platform.
+/// This type will be equal to [`BigEndian`] or [`LittleEndian`].
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{ByteOrder, Endianness, NativeEndian};
+///
+/// assert_eq!(ByteOrder::NE, NativeEndian::default().byte_order());
+/// ```
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// The marker type for the native-endian serialization of the target platform.
+/// This type will be equal to [`BigEndian`] or [`LittleEndian`].
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{ByteOrder, Endianness, NativeEndian};
+///
+/// assert_eq!(ByteOrder::NE, NativeEndian::default().byte_order());
+/// ```
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+impl Endianness for ByteOrder {
+    #[inline]
+    fn byte_order(&self) -> ByteOrder {
+        match self {
+            ByteOrder::BE => ByteOrder::BE,
+            ByteOrder::LE => ByteOrder::LE,
+        }
+    }
+}
+
+impl Endianness for BigEndian {
+    #[inline]
+    fn byte_order(&self) -> ByteOrder {
+        ByteOrder::BE
+    }
+}
+
+impl Endianness for LittleEndian {
+    #[inline]
+    fn byte_order(&self) -> ByteOrder {
+        ByteOrder::LE
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::ByteOrder {}
+    impl Sealed for super::BigEndian {}
+    impl Sealed for super::LittleEndian {}
+}
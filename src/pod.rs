@@ -0,0 +1,41 @@
+use core::mem;
+use core::slice;
+
+/// A marker for primitive number types that have no padding bytes and for
+/// which every bit pattern is a valid value.
+///
+/// # Safety
+///
+/// Implementing this trait for a type which does not uphold the above
+/// invariants results in undefined behavior when viewing slices of that type
+/// as bytes.
+pub unsafe trait Pod: Copy {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for u128 {}
+unsafe impl Pod for i128 {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+
+/// Views a slice of numbers as its underlying bytes.
+#[inline]
+pub(crate) fn as_bytes<T: Pod>(src: &[T]) -> &[u8] {
+    // SAFETY: `T: Pod` guarantees there are no padding bytes, and `u8` has an
+    // alignment of one, so every byte within the original slice is readable.
+    unsafe { slice::from_raw_parts(src.as_ptr() as *const u8, mem::size_of_val(src)) }
+}
+
+/// Views a mutable slice of numbers as its underlying bytes.
+#[inline]
+pub(crate) fn as_bytes_mut<T: Pod>(dst: &mut [T]) -> &mut [u8] {
+    // SAFETY: `T: Pod` guarantees that any bytes written through the returned
+    // slice form a valid `T`, and `u8` has an alignment of one.
+    unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut u8, mem::size_of_val(dst)) }
+}
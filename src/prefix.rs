@@ -0,0 +1,67 @@
+/// An enumeration of the encodings that a [`NumberReader`] or [`NumberWriter`]
+/// can use for the length that prefixes variable-sized values, such as
+/// `Vec<T>` and `String`.
+///
+/// Fixed-width prefixes are read and written with the byte order of the
+/// reader or writer. The default prefix is [`LengthPrefix::U32`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, LengthPrefix, NumberWriter};
+///
+/// fn main() -> io::Result<()> {
+///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+///     writer.set_length_prefix(LengthPrefix::U16);
+///     writer.encode("hi")?;
+///     assert_eq!(writer.into_inner(), vec![0x00, 0x02, b'h', b'i']);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`LengthPrefix::U32`]: LengthPrefix::U32
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LengthPrefix {
+    /// An unsigned 8-bit integer.
+    U8,
+    /// An unsigned 16-bit integer.
+    U16,
+    /// An unsigned 32-bit integer.
+    U32,
+    /// An unsigned 64-bit integer.
+    U64,
+    /// An unsigned LEB128 integer of at most 64 bits.
+    Uleb128,
+}
+
+impl LengthPrefix {
+    /// Returns the largest length that can be represented by this prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::LengthPrefix;
+    ///
+    /// assert_eq!(255, LengthPrefix::U8.max_len());
+    /// assert_eq!(u64::MAX, LengthPrefix::Uleb128.max_len());
+    /// ```
+    pub fn max_len(self) -> u64 {
+        match self {
+            LengthPrefix::U8 => u8::MAX.into(),
+            LengthPrefix::U16 => u16::MAX.into(),
+            LengthPrefix::U32 => u32::MAX.into(),
+            LengthPrefix::U64 | LengthPrefix::Uleb128 => u64::MAX,
+        }
+    }
+}
+
+impl Default for LengthPrefix {
+    #[inline]
+    fn default() -> LengthPrefix {
+        LengthPrefix::U32
+    }
+}
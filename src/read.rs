@@ -1,7 +1,21 @@
-use std::io::{self, Read};
-use std::mem;
+use core::any;
+use core::convert::TryFrom;
+use core::mem;
 
-use crate::order::ByteOrder;
+use crate::io::{self, Read};
+
+use crate::decode::Decode;
+#[cfg(feature = "std")]
+use crate::error::ErrorContext;
+use crate::float;
+use crate::leb128;
+use crate::magic::Magic;
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::varint::VarintScheme;
+use crate::zigzag;
 
 /// A `NumberReader` wraps a [reader] and provides methods for reading numbers.
 ///
@@ -66,9 +80,14 @@ use crate::order::ByteOrder;
 /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
 /// [`NumberReader::new`]: NumberReader::new
 /// [`NumberReader::with_order`]: NumberReader::with_order
-pub struct NumberReader<R: Read> {
+pub struct NumberReader<R: Read, O: Endianness = ByteOrder> {
     inner: R,
-    order: ByteOrder,
+    order: O,
+    strict: bool,
+    length_prefix: LengthPrefix,
+    position: Option<u64>,
+    #[cfg(feature = "std")]
+    context: Option<ErrorContext>,
 }
 
 impl<R: Read> NumberReader<R> {
@@ -112,6 +131,147 @@ impl<R: Read> NumberReader<R> {
         NumberReader::with_order(ByteOrder::NE, src)
     }
 
+    /// Reads a magic number from the given [reader], and creates a new
+    /// `NumberReader` which wraps it with the byte order that the magic number
+    /// was written in.
+    ///
+    /// The bytes of the magic number are consumed. To leave them in a
+    /// buffered reader, use [`peek_order`] instead.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If the bytes do not match `magic` in either byte
+    /// order, an error of the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, Magic, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let pcap = Cursor::new(vec![0xD4, 0xC3, 0xB2, 0xA1, 0x02, 0x00]);
+    ///     let mut reader = NumberReader::detect_order(pcap, Magic::new(0xA1B2C3D4u32))?;
+    ///
+    ///     assert_eq!(ByteOrder::LE, reader.order());
+    ///     assert_eq!(2, reader.read_u16()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    /// [`peek_order`]: NumberReader::peek_order
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    pub fn detect_order<T: Number>(mut src: R, magic: Magic<T>) -> io::Result<NumberReader<R>> {
+        let mut buf = T::Bytes::default();
+        src.read_exact(buf.as_mut())?;
+        let order = magic.detect_or_err(buf.as_ref())?;
+        Ok(NumberReader::with_order(order, src))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead> NumberReader<R> {
+    /// Peeks at a magic number in the given buffered reader, and creates a new
+    /// `NumberReader` which wraps it with the byte order that the magic number
+    /// was written in.
+    ///
+    /// Unlike [`detect_order`], no bytes are consumed, so the magic number is
+    /// still the first thing that the `NumberReader` reads. The magic number
+    /// must be within the bytes returned by a single call to
+    /// [`BufRead::fill_buf`].
+    ///
+    /// This method is only available with the `std` feature enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`BufRead::fill_buf`]. If fewer bytes than the magic number takes are
+    /// buffered, an error of the kind [`ErrorKind::UnexpectedEof`] is
+    /// returned. If the bytes do not match `magic` in either byte order, an
+    /// error of the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, BufReader};
+    /// use byte_order::{ByteOrder, Magic, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let utf16: &[u8] = &[0xFF, 0xFE, b'h', 0x00];
+    ///     let mut reader = NumberReader::peek_order(BufReader::new(utf16), Magic::new(0xFEFFu16))?;
+    ///
+    ///     assert_eq!(ByteOrder::LE, reader.order());
+    ///     assert_eq!(0xFEFF, reader.read_u16()?);
+    ///     assert_eq!(u16::from(b'h'), reader.read_u16()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`detect_order`]: NumberReader::detect_order
+    /// [`BufRead::fill_buf`]: std::io::BufRead::fill_buf
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    pub fn peek_order<T: Number>(mut src: R, magic: Magic<T>) -> io::Result<NumberReader<R>> {
+        let buf = src.fill_buf()?;
+        if buf.len() < mem::size_of::<T>() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "not enough bytes are buffered to detect the byte order",
+            ));
+        }
+        let order = magic.detect_or_err(buf)?;
+        Ok(NumberReader::with_order(order, src))
+    }
+}
+
+macro_rules! try_read_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the underlying reader, or returns `None` if it is at end of file.")]
+            ///
+            /// See [`try_read`] for details.
+            ///
+            /// [`try_read`]: NumberReader::try_read
+            #[inline]
+            pub fn $name(&mut self) -> io::Result<Option<$ty>> {
+                self.try_read()
+            }
+        )*
+    };
+}
+
+macro_rules! try_read_leb128 {
+    ($($name:ident => $read:ident: $ty:ty, $decoder:ident($bits:expr), $type_name:expr;)*) => {
+        $(
+            #[doc = concat!("Reads a value like [`", stringify!($read), "`], or returns `None` if the underlying reader is at end of file.")]
+            ///
+            /// `None` is only returned if no byte of the encoding could be
+            /// read.
+            ///
+            /// # Errors
+            ///
+            /// If the end of file is reached after the first byte of the
+            /// encoding, an error of the kind [`ErrorKind::UnexpectedEof`] is
+            #[doc = concat!("returned. Otherwise, the errors are those of [`", stringify!($read), "`].")]
+            ///
+            /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+            #[doc = concat!("[`", stringify!($read), "`]: NumberReader::", stringify!($read))]
+            #[inline]
+            pub fn $name(&mut self) -> io::Result<Option<$ty>> {
+                let decoder = leb128::Decoder::$decoder($bits, self.strict);
+                let n = self.with_context($type_name, None, |reader| reader.try_read_leb128(decoder))?;
+                Ok(n.map(|n| n as $ty))
+            }
+        )*
+    };
+}
+
+impl<R: Read, O: Endianness> NumberReader<R, O> {
     /// Creates a new `NumberReader` by wrapping the given [reader] with the
     /// specified byte order.
     ///
@@ -119,11 +279,16 @@ impl<R: Read> NumberReader<R> {
     /// [`ByteOrder::LE`] for little-endian byte ordering, or [`ByteOrder::NE`]
     /// to explicitly use the target platform's endianness.
     ///
+    /// If the byte order is known at compile time, the marker types
+    /// [`BigEndian`], [`LittleEndian`], and [`NativeEndian`] may be used
+    /// instead. With a marker type, the byte order is resolved at compile time
+    /// and no `read_*` method branches on it at runtime.
+    ///
     /// # Examples
     ///
     /// ```
     /// use std::io::{self, Cursor};
-    /// use byte_order::{ByteOrder, NumberReader};
+    /// use byte_order::{BigEndian, ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
     ///     let src = Cursor::new(vec![0xA1, 0xB2]);
@@ -144,6 +309,9 @@ impl<R: Read> NumberReader<R> {
     ///         ne_reader.read_u16()?
     ///     );
     ///
+    ///     let mut static_be_reader = NumberReader::with_order(BigEndian, src.clone());
+    ///     assert_eq!(0xA1B2, static_be_reader.read_u16()?);
+    ///
     ///     Ok(())
     /// }
     /// ```
@@ -152,9 +320,20 @@ impl<R: Read> NumberReader<R> {
     /// [`ByteOrder::BE`]: ByteOrder::BE
     /// [`ByteOrder::LE`]: ByteOrder::LE
     /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`BigEndian`]: crate::BigEndian
+    /// [`LittleEndian`]: crate::LittleEndian
+    /// [`NativeEndian`]: crate::NativeEndian
     #[inline]
-    pub fn with_order(order: ByteOrder, src: R) -> NumberReader<R> {
-        NumberReader { inner: src, order }
+    pub fn with_order(order: O, src: R) -> NumberReader<R, O> {
+        NumberReader {
+            inner: src,
+            order,
+            strict: false,
+            length_prefix: LengthPrefix::default(),
+            position: None,
+            #[cfg(feature = "std")]
+            context: None,
+        }
     }
 
     /// Consumes this `NumberReader`, returning the underlying value.
@@ -205,142 +384,199 @@ impl<R: Read> NumberReader<R> {
         &mut self.inner
     }
 
-    /// Reads an unsigned 8-bit integer from the underlying reader.
+    /// Returns whether this `NumberReader` rejects non-canonical encodings of
+    /// variable-length integers.
     ///
-    /// **Note:** Since this method reads a single byte, no byte order
-    /// conversions are used. It is included for completeness.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```
+    /// use std::io::Cursor;
+    /// use byte_order::NumberReader;
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// let reader = NumberReader::new(Cursor::new(vec![]));
+    /// assert!(!reader.is_strict());
+    /// ```
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether this `NumberReader` rejects non-canonical encodings of
+    /// variable-length integers.
+    ///
+    /// A `NumberReader` is not strict by default, so encodings which are
+    /// longer than necessary, such as the LEB128 encoding `[0x81, 0x00]` for
+    /// the number `1`, are accepted as long as the value fits in the requested
+    /// type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::io::{self, Cursor};
+    /// use std::io::{Cursor, ErrorKind};
     /// use byte_order::NumberReader;
     ///
-    /// fn main() -> io::Result<()> {
-    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12]));
-    ///     assert_eq!(0x12u8, reader.read_u8()?);
-    ///     Ok(())
-    /// }
-    /// ```
+    /// let src = Cursor::new(vec![0x81, 0x00]);
     ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_u8(&mut self) -> io::Result<u8> {
-        let mut buf = [0; mem::size_of::<u8>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(buf[0])
+    /// let mut reader = NumberReader::new(src.clone());
+    /// assert_eq!(1, reader.read_uleb128_u32().unwrap());
+    ///
+    /// let mut strict_reader = NumberReader::new(src.clone());
+    /// strict_reader.set_strict(true);
+    /// assert_eq!(
+    ///     ErrorKind::InvalidData,
+    ///     strict_reader.read_uleb128_u32().unwrap_err().kind()
+    /// );
+    /// ```
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
     }
 
-    /// Reads a signed 8-bit integer from the underlying reader.
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// read by this `NumberReader`.
     ///
-    /// **Note:** Since this method reads a single byte, no byte order
-    /// conversions are used. It is included for completeness.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```
+    /// use std::io::Cursor;
+    /// use byte_order::{LengthPrefix, NumberReader};
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// let reader = NumberReader::new(Cursor::new(vec![]));
+    /// assert_eq!(LengthPrefix::U32, reader.length_prefix());
+    /// ```
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values,
+    /// such as `Vec<T>` and `String`, read by this `NumberReader`.
     ///
     /// # Examples
     ///
     /// ```
     /// use std::io::{self, Cursor};
-    /// use byte_order::NumberReader;
+    /// use byte_order::{LengthPrefix, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12]));
-    ///     assert_eq!(0x12i8, reader.read_i8()?);
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x02, b'h', b'i']));
+    ///     reader.set_length_prefix(LengthPrefix::U8);
+    ///     assert_eq!("hi", reader.decode::<String>()?);
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_i8(&mut self) -> io::Result<i8> {
-        Ok(self.read_u8()? as i8)
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
     }
 
-    /// Reads an unsigned 16-bit integer from the underlying reader.
-    ///
-    /// # Errors
-    ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// Returns the number of bytes read through this `NumberReader` since
+    /// position tracking was enabled or last reset, or `None` if it is not
+    /// tracked.
+    ///
+    /// The position is counted by this `NumberReader` itself, so the
+    /// underlying reader does not need to implement `Seek`. It includes the
+    /// bytes consumed by every `read_*` method and by the [`Read`]
+    /// implementation of this `NumberReader`. When a read fails, the bytes
+    /// that were consumed from the underlying reader before it failed are
+    /// still counted, so the position always matches what the underlying
+    /// reader has given up.
     ///
     /// # Examples
     ///
     /// ```
     /// use std::io::{self, Cursor};
-    /// use byte_order::{ByteOrder, NumberReader};
+    /// use byte_order::NumberReader;
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34]);
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12, 0x34, 0x56, 0x80]));
+    ///     assert_eq!(None, reader.position());
     ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x1234u16, be_reader.read_u16()?);
-    ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x3412u16, le_reader.read_u16()?);
+    ///     reader.set_track_position(true);
+    ///     reader.read_u16()?;
+    ///     assert_eq!(Some(2), reader.position());
     ///
+    ///     reader.read_u8()?;
+    ///     assert!(reader.read_u16().is_err());
+    ///     assert_eq!(Some(4), reader.position());
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_u16(&mut self) -> io::Result<u16> {
-        let mut buf = [0; mem::size_of::<u16>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            u16::from_le_bytes(buf)
-        } else {
-            u16::from_be_bytes(buf)
-        })
+    /// [`Read`]: Read
+    pub fn position(&self) -> Option<u64> {
+        self.position
     }
 
-    /// Reads a signed 16-bit integer from the underlying reader.
-    ///
-    /// # Errors
+    /// Sets whether this `NumberReader` counts the bytes read through it.
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// Position tracking is disabled by default. Enabling it starts the count
+    /// at zero, unless it is already enabled, in which case the count is
+    /// kept.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::io::{self, Cursor};
-    /// use byte_order::{ByteOrder, NumberReader};
+    /// use std::io::Cursor;
+    /// use byte_order::NumberReader;
     ///
-    /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34]);
+    /// let mut reader = NumberReader::new(Cursor::new(vec![]));
+    /// reader.set_track_position(true);
+    /// assert_eq!(Some(0), reader.position());
     ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x1234i16, be_reader.read_i16()?);
+    /// reader.set_track_position(false);
+    /// assert_eq!(None, reader.position());
+    /// ```
+    pub fn set_track_position(&mut self, track: bool) {
+        self.position = match (track, self.position) {
+            (true, position) => position.or(Some(0)),
+            (false, _) => None,
+        };
+    }
+
+    /// Resets the count of bytes read through this `NumberReader` to zero, if
+    /// position tracking is enabled.
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x3412i16, le_reader.read_i16()?);
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
     ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12, 0x34]));
+    ///     reader.set_track_position(true);
+    ///     reader.read_u8()?;
+    ///     reader.reset_position();
+    ///     reader.read_u8()?;
+    ///     assert_eq!(Some(1), reader.position());
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_i16(&mut self) -> io::Result<i16> {
-        Ok(self.read_u16()? as i16)
+    pub fn reset_position(&mut self) {
+        if self.position.is_some() {
+            self.position = Some(0);
+        }
     }
 
-    /// Reads an unsigned 32-bit integer from the underlying reader.
+    /// Returns the byte order that this `NumberReader` reads numbers with.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// ```
+    /// use std::io::Cursor;
+    /// use byte_order::{BigEndian, ByteOrder, NumberReader};
+    ///
+    /// let reader = NumberReader::with_order(ByteOrder::LE, Cursor::new(vec![]));
+    /// assert_eq!(ByteOrder::LE, reader.order());
+    ///
+    /// let static_reader = NumberReader::with_order(BigEndian, Cursor::new(vec![]));
+    /// assert_eq!(ByteOrder::BE, static_reader.order());
+    /// ```
+    pub fn order(&self) -> ByteOrder {
+        self.order.byte_order()
+    }
+
+    /// Sets the byte order that this `NumberReader` reads numbers with.
+    ///
+    /// This is useful for formats, such as TIFF and ELF, which declare their
+    /// byte order in a header.
     ///
     /// # Examples
     ///
@@ -349,36 +585,27 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
-    ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x12345678u32, be_reader.read_u32()?);
+    ///     let src = Cursor::new(vec![b'M', b'M', 0x00, 0x2A]);
+    ///     let mut reader = NumberReader::new(src);
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x78563412u32, le_reader.read_u32()?);
+    ///     // "II" and "MM" read the same in either byte order.
+    ///     let mark = reader.read_u16()?;
+    ///     reader.set_order(if mark == 0x4D4D { ByteOrder::BE } else { ByteOrder::LE });
+    ///     assert_eq!(42, reader.read_u16()?);
     ///
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_u32(&mut self) -> io::Result<u32> {
-        let mut buf = [0; mem::size_of::<u32>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            u32::from_le_bytes(buf)
-        } else {
-            u32::from_be_bytes(buf)
-        })
+    pub fn set_order(&mut self, order: O) {
+        self.order = order;
     }
 
-    /// Reads a signed 32-bit integer from the underlying reader.
-    ///
-    /// # Errors
+    /// Calls `f` with this `NumberReader` set to the given byte order, and
+    /// then restores the byte order it had before, returning the result of
+    /// `f`.
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// Unlike [`by_order`], the byte order can only be changed to another
+    /// value of the same type, but `f` receives this very `NumberReader`.
     ///
     /// # Examples
     ///
@@ -387,63 +614,74 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
-    ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x12345678i32, be_reader.read_i32()?);
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x12, 0x34]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::LE, src);
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x78563412i32, le_reader.read_i32()?);
+    ///     let n = reader.with_scoped_order(ByteOrder::BE, |r| r.read_u16())?;
+    ///     assert_eq!(0x1234, n);
+    ///     assert_eq!(ByteOrder::LE, reader.order());
+    ///     assert_eq!(0x3412, reader.read_u16()?);
     ///
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_i32(&mut self) -> io::Result<i32> {
-        Ok(self.read_u32()? as i32)
+    /// [`by_order`]: NumberReader::by_order
+    pub fn with_scoped_order<T, F>(&mut self, order: O, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let previous = mem::replace(&mut self.order, order);
+        let result = f(self);
+        self.order = previous;
+        result
     }
 
-    /// Reads an unsigned 64-bit integer from the underlying reader.
+    /// Creates a `NumberReader` which reads from this one with a different
+    /// byte order, keeping every other setting of this `NumberReader`.
     ///
-    /// # Errors
-    ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// This is useful for formats which store a few values in a byte order
+    /// other than the one used by the rest of the data.
     ///
     /// # Examples
     ///
     /// ```
     /// use std::io::{self, Cursor};
-    /// use byte_order::{ByteOrder, NumberReader};
+    /// use byte_order::{BigEndian, ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]);
-    ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x1234567890123456u64, be_reader.read_u64()?);
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x12, 0x34]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::LE, src);
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x5634129078563412u64, le_reader.read_u64()?);
+    ///     assert_eq!(0x1234u16, reader.by_order(BigEndian).read_u16()?);
+    ///     assert_eq!(0x3412u16, reader.read_u16()?);
     ///
     ///     Ok(())
     /// }
     /// ```
-    ///
-    /// [`Read::read_exact`]: Read::read_exact
-    #[inline]
-    pub fn read_u64(&mut self) -> io::Result<u64> {
-        let mut buf = [0; mem::size_of::<u64>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            u64::from_le_bytes(buf)
-        } else {
-            u64::from_be_bytes(buf)
-        })
+    pub fn by_order<P: Endianness>(&mut self, order: P) -> NumberReader<&mut Self, P> {
+        let strict = self.strict;
+        let length_prefix = self.length_prefix;
+        let position = self.position;
+        #[cfg(feature = "std")]
+        let context = self.context.as_ref().map(ErrorContext::nested);
+        NumberReader {
+            inner: self,
+            order,
+            strict,
+            length_prefix,
+            position,
+            #[cfg(feature = "std")]
+            context,
+        }
     }
 
-    /// Reads a signed 64-bit integer from the underlying reader.
+    /// Reads a number of any primitive type from the underlying reader.
+    ///
+    /// The type of number to read is chosen by the type parameter `T`, which
+    /// is usually inferred. Each of the named `read_*` methods, such as
+    /// [`read_u32`], is equivalent to calling this method with the
+    /// corresponding type.
     ///
     /// # Errors
     ///
@@ -457,71 +695,93 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]);
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x9A]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
     ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x1234567890123456i64, be_reader.read_i64()?);
+    ///     assert_eq!(0x12345678, reader.read_number::<u32>()?);
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x5634129078563412i64, le_reader.read_i64()?);
+    ///     let n: i8 = reader.read_number()?;
+    ///     assert_eq!(-0x66, n);
     ///
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// [`read_u32`]: NumberReader::read_u32
     /// [`Read::read_exact`]: Read::read_exact
     #[inline]
-    pub fn read_i64(&mut self) -> io::Result<i64> {
-        Ok(self.read_u64()? as i64)
+    pub fn read_number<T: Number>(&mut self) -> io::Result<T> {
+        self.with_context(any::type_name::<T>(), Some(mem::size_of::<T>()), |reader| {
+            let mut buf = T::Bytes::default();
+            reader.read_raw(buf.as_mut())?;
+            Ok(T::from_bytes(buf, reader.order.byte_order()))
+        })
     }
 
-    /// Reads an unsigned 128-bit integer from the underlying reader.
+    /// Reads a number of any primitive type from the underlying reader, or
+    /// returns `None` if the reader is at end of file.
+    ///
+    /// This distinguishes a stream which ends cleanly between numbers from
+    /// one which ends partway through a number, which makes it suitable for
+    /// reading records until the end of a file. Each of the named
+    /// `try_read_*` methods, such as [`try_read_u32`], is equivalent to
+    /// calling this method with the corresponding type.
     ///
     /// # Errors
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// If at least one byte of the number is read before the end of file is
+    /// reached, an error of the kind [`ErrorKind::UnexpectedEof`] is returned.
+    /// Any other error recieved from the underlying reader is propagated.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::io::{self, Cursor};
+    /// use std::io::{self, Cursor, ErrorKind};
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![
-    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
-    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
-    ///     ]);
-    ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x12345678901234567890123456789012u128, be_reader.read_u128()?);
-    ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x12907856341290785634129078563412u128, le_reader.read_u128()?);
-    ///
+    ///     let src = Cursor::new(vec![0x00, 0x01, 0x00, 0x02]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+    ///
+    ///     let mut records = vec![];
+    ///     while let Some(record) = reader.try_read::<u16>()? {
+    ///         records.push(record);
+    ///     }
+    ///     assert_eq!(vec![1, 2], records);
+    ///
+    ///     let mut truncated = NumberReader::with_order(ByteOrder::BE, Cursor::new(vec![0x00]));
+    ///     let err = truncated.try_read_u16().unwrap_err();
+    ///     assert_eq!(ErrorKind::UnexpectedEof, err.kind());
     ///     Ok(())
     /// }
     /// ```
     ///
-    /// [`Read::read_exact`]: Read::read_exact
+    /// [`try_read_u32`]: NumberReader::try_read_u32
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
     #[inline]
-    pub fn read_u128(&mut self) -> io::Result<u128> {
-        let mut buf = [0; mem::size_of::<u128>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            u128::from_le_bytes(buf)
-        } else {
-            u128::from_be_bytes(buf)
+    pub fn try_read<T: Number>(&mut self) -> io::Result<Option<T>> {
+        self.with_context(any::type_name::<T>(), Some(mem::size_of::<T>()), |reader| {
+            let mut buf = T::Bytes::default();
+            if !reader.read_unless_eof(buf.as_mut())? {
+                return Ok(None);
+            }
+            Ok(Some(T::from_bytes(buf, reader.order.byte_order())))
         })
     }
 
-    /// Reads a signed 128-bit integer from the underlying reader.
+    /// Reads a sequence of numbers of any primitive type from the underlying
+    /// reader, filling `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place. Each of the named
+    /// `read_*_into` methods, such as [`read_u32_into`], is equivalent to
+    /// calling this method with the corresponding type.
     ///
     /// # Errors
     ///
     /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
     ///
     /// # Examples
     ///
@@ -530,34 +790,39 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let src = Cursor::new(vec![
-    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
-    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
-    ///     ]);
-    ///
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
-    ///     assert_eq!(0x12345678901234567890123456789012u128, be_reader.read_u128()?);
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::LE, src);
     ///
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
-    ///     assert_eq!(0x12907856341290785634129078563412u128, le_reader.read_u128()?);
+    ///     let mut dst = [0i16; 2];
+    ///     reader.read_into(&mut dst)?;
+    ///     assert_eq!([0x3412, 0x7856], dst);
     ///
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// [`read_u32_into`]: NumberReader::read_u32_into
     /// [`Read::read_exact`]: Read::read_exact
     #[inline]
-    pub fn read_i128(&mut self) -> io::Result<i128> {
-        Ok(self.read_u128()? as i128)
+    pub fn read_into<T: Number>(&mut self, dst: &mut [T]) -> io::Result<()> {
+        let width = mem::size_of_val(dst);
+        self.with_context(any::type_name::<[T]>(), Some(width), |reader| {
+            reader.read_raw(pod::as_bytes_mut(dst))
+        })?;
+        if self.order.byte_order() != ByteOrder::NE {
+            for n in dst {
+                *n = n.swap_bytes();
+            }
+        }
+        Ok(())
     }
 
-    /// Reads a IEEE754 single-precision floating point number from the
+    /// Reads a value of any type that implements [`Decode`] from the
     /// underlying reader.
     ///
     /// # Errors
     ///
-    /// This method propagates any error recieved from the internal call to
-    /// [`Read::read_exact`].
+    /// This method propagates any error returned by [`Decode::decode`].
     ///
     /// # Examples
     ///
@@ -566,12 +831,426 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let be_src = Cursor::new(vec![0x41, 0x48, 0x00, 0x00]);
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
-    ///     assert_eq!(12.5f32, be_reader.read_f32()?);
+    ///     let src = Cursor::new(vec![0x01, 0x12, 0x34, 0x56, 0x78]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
     ///
-    ///     let le_src = Cursor::new(vec![0x00, 0x00, 0x48, 0x41]);
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     let (flag, pair): (bool, [u16; 2]) = reader.decode()?;
+    ///     assert!(flag);
+    ///     assert_eq!([0x1234, 0x5678], pair);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Decode`]: crate::Decode
+    /// [`Decode::decode`]: crate::Decode::decode
+    #[inline]
+    pub fn decode<T: Decode>(&mut self) -> io::Result<T> {
+        #[cfg(feature = "std")]
+        if self.context.is_some() {
+            let start = self.position.unwrap_or(0);
+            return T::decode(self)
+                .map_err(|err| self.describe(err, start, any::type_name::<T>(), None));
+        }
+        T::decode(self)
+    }
+
+    /// Reads the length that prefixes a variable-sized value from the
+    /// underlying reader, using the encoding set by [`set_length_prefix`].
+    ///
+    /// This is useful when implementing [`Decode`] for collections.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the length does not fit in a `usize`, an
+    /// error of the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, LengthPrefix, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x00, 0x03]);
+    ///     let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+    ///     reader.set_length_prefix(LengthPrefix::U16);
+    ///     assert_eq!(3, reader.read_length()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`set_length_prefix`]: NumberReader::set_length_prefix
+    /// [`Decode`]: crate::Decode
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    pub fn read_length(&mut self) -> io::Result<usize> {
+        let width = match self.length_prefix {
+            LengthPrefix::U8 => Some(1),
+            LengthPrefix::U16 => Some(2),
+            LengthPrefix::U32 => Some(4),
+            LengthPrefix::U64 => Some(8),
+            LengthPrefix::Uleb128 => None,
+        };
+        self.with_context("length prefix", width, |reader| {
+            let len = match reader.length_prefix {
+                LengthPrefix::U8 => reader.read_u8()?.into(),
+                LengthPrefix::U16 => reader.read_u16()?.into(),
+                LengthPrefix::U32 => reader.read_u32()?.into(),
+                LengthPrefix::U64 => reader.read_u64()?,
+                LengthPrefix::Uleb128 => reader.read_uleb128_u64()?,
+            };
+            usize::try_from(len).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "length prefix does not fit in usize",
+                )
+            })
+        })
+    }
+
+    /// Reads an unsigned 8-bit integer from the underlying reader.
+    ///
+    /// **Note:** Since this method reads a single byte, no byte order
+    /// conversions are used. It is included for completeness.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12]));
+    ///     assert_eq!(0x12u8, reader.read_u8()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u8(&mut self) -> io::Result<u8> {
+        self.read_number()
+    }
+
+    /// Reads a signed 8-bit integer from the underlying reader.
+    ///
+    /// **Note:** Since this method reads a single byte, no byte order
+    /// conversions are used. It is included for completeness.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x12]));
+    ///     assert_eq!(0x12i8, reader.read_i8()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i8(&mut self) -> io::Result<i8> {
+        self.read_number()
+    }
+
+    /// Reads an unsigned 16-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x1234u16, be_reader.read_u16()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x3412u16, le_reader.read_u16()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u16(&mut self) -> io::Result<u16> {
+        self.read_number()
+    }
+
+    /// Reads a signed 16-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x1234i16, be_reader.read_i16()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x3412i16, le_reader.read_i16()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i16(&mut self) -> io::Result<i16> {
+        self.read_number()
+    }
+
+    /// Reads an unsigned 32-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x12345678u32, be_reader.read_u32()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x78563412u32, le_reader.read_u32()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u32(&mut self) -> io::Result<u32> {
+        self.read_number()
+    }
+
+    /// Reads a signed 32-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x12345678i32, be_reader.read_i32()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x78563412i32, le_reader.read_i32()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i32(&mut self) -> io::Result<i32> {
+        self.read_number()
+    }
+
+    /// Reads an unsigned 64-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x1234567890123456u64, be_reader.read_u64()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x5634129078563412u64, le_reader.read_u64()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u64(&mut self) -> io::Result<u64> {
+        self.read_number()
+    }
+
+    /// Reads a signed 64-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x1234567890123456i64, be_reader.read_i64()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x5634129078563412i64, le_reader.read_i64()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i64(&mut self) -> io::Result<i64> {
+        self.read_number()
+    }
+
+    /// Reads an unsigned 128-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///     ]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x12345678901234567890123456789012u128, be_reader.read_u128()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x12907856341290785634129078563412u128, le_reader.read_u128()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u128(&mut self) -> io::Result<u128> {
+        self.read_number()
+    }
+
+    /// Reads a signed 128-bit integer from the underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///     ]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x12345678901234567890123456789012u128, be_reader.read_u128()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x12907856341290785634129078563412u128, le_reader.read_u128()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i128(&mut self) -> io::Result<i128> {
+        self.read_number()
+    }
+
+    /// Reads a IEEE754 single-precision floating point number from the
+    /// underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x41, 0x48, 0x00, 0x00]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     assert_eq!(12.5f32, be_reader.read_f32()?);
+    ///
+    ///     let le_src = Cursor::new(vec![0x00, 0x00, 0x48, 0x41]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
     ///     assert_eq!(12.5f32, le_reader.read_f32()?);
     ///
     ///     Ok(())
@@ -580,18 +1259,1123 @@ impl<R: Read> NumberReader<R> {
     ///
     /// [`Read::read_exact`]: Read::read_exact
     #[inline]
-    pub fn read_f32(&mut self) -> io::Result<f32> {
-        let mut buf = [0; mem::size_of::<f32>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            f32::from_le_bytes(buf)
-        } else {
-            f32::from_be_bytes(buf)
+    pub fn read_f32(&mut self) -> io::Result<f32> {
+        self.read_number()
+    }
+
+    /// Reads a IEEE754 double-precision floating point number from the
+    /// underlying reader.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x40, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     assert_eq!(12.5f64, be_reader.read_f64()?);
+    ///
+    ///     let le_src = Cursor::new(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x29, 0x40]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     assert_eq!(12.5f64, le_reader.read_f64()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_f64(&mut self) -> io::Result<f64> {
+        self.read_number()
+    }
+
+    /// Reads a sequence of unsigned 16-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    ///     let mut dst = [0u16; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_u16_into(&mut dst)?;
+    ///     assert_eq!([0x1234, 0x5678], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_u16_into(&mut dst)?;
+    ///     assert_eq!([0x3412, 0x7856], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u16_into(&mut self, dst: &mut [u16]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of signed 16-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    ///     let mut dst = [0i16; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_i16_into(&mut dst)?;
+    ///     assert_eq!([0x1234, 0x5678], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_i16_into(&mut dst)?;
+    ///     assert_eq!([0x3412, 0x7856], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i16_into(&mut self, dst: &mut [i16]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of unsigned 32-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]);
+    ///     let mut dst = [0u32; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_u32_into(&mut dst)?;
+    ///     assert_eq!([0x12345678, 0x90123456], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_u32_into(&mut dst)?;
+    ///     assert_eq!([0x78563412, 0x56341290], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u32_into(&mut self, dst: &mut [u32]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of signed 32-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78]);
+    ///     let mut dst = [0i32; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_i32_into(&mut dst)?;
+    ///     assert_eq!([0x12345678, 0x12345678], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_i32_into(&mut dst)?;
+    ///     assert_eq!([0x78563412, 0x78563412], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i32_into(&mut self, dst: &mut [i32]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of unsigned 64-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///     ]);
+    ///     let mut dst = [0u64; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_u64_into(&mut dst)?;
+    ///     assert_eq!([0x1234567890123456, 0x7890123456789012], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_u64_into(&mut dst)?;
+    ///     assert_eq!([0x5634129078563412, 0x1290785634129078], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u64_into(&mut self, dst: &mut [u64]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of signed 64-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///     ]);
+    ///     let mut dst = [0i64; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_i64_into(&mut dst)?;
+    ///     assert_eq!([0x1234567812345678, 0x1234567812345678], dst);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_i64_into(&mut dst)?;
+    ///     assert_eq!([0x7856341278563412, 0x7856341278563412], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i64_into(&mut self, dst: &mut [i64]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of unsigned 128-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///         0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///         0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78,
+    ///         0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34,
+    ///     ]);
+    ///     let mut dst = [0u128; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_u128_into(&mut dst)?;
+    ///     assert_eq!(
+    ///         [
+    ///             0x12345678901234567890123456789012,
+    ///             0x34567890123456789012345678901234,
+    ///         ],
+    ///         dst
+    ///     );
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_u128_into(&mut dst)?;
+    ///     assert_eq!(
+    ///         [
+    ///             0x12907856341290785634129078563412,
+    ///             0x34129078563412907856341290785634,
+    ///         ],
+    ///         dst
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u128_into(&mut self, dst: &mut [u128]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of signed 128-bit integers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///     ]);
+    ///     let mut dst = [0i128; 2];
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     be_reader.read_i128_into(&mut dst)?;
+    ///     assert_eq!(
+    ///         [
+    ///             0x12345678123456781234567812345678,
+    ///             0x12345678123456781234567812345678,
+    ///         ],
+    ///         dst
+    ///     );
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     le_reader.read_i128_into(&mut dst)?;
+    ///     assert_eq!(
+    ///         [
+    ///             0x78563412785634127856341278563412,
+    ///             0x78563412785634127856341278563412,
+    ///         ],
+    ///         dst
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i128_into(&mut self, dst: &mut [i128]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of IEEE754 single-precision floating point numbers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x41, 0x48, 0x00, 0x00, 0xBF, 0x40, 0x00, 0x00]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     let mut dst = [0f32; 2];
+    ///     be_reader.read_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     let le_src = Cursor::new(vec![0x00, 0x00, 0x48, 0x41, 0x00, 0x00, 0x40, 0xBF]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     let mut dst = [0f32; 2];
+    ///     le_reader.read_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_f32_into(&mut self, dst: &mut [f32]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads a sequence of IEEE754 double-precision floating point numbers from the underlying reader, filling
+    /// `dst` entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst`
+    /// are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![
+    ///         0x40, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///         0xBF, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     ]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     let mut dst = [0f64; 2];
+    ///     be_reader.read_f64_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     let le_src = Cursor::new(vec![
+    ///         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x29, 0x40,
+    ///         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE8, 0xBF,
+    ///     ]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     let mut dst = [0f64; 2];
+    ///     le_reader.read_f64_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_f64_into(&mut self, dst: &mut [f64]) -> io::Result<()> {
+        self.read_into(dst)
+    }
+
+    /// Reads an unsigned 32-bit integer encoded as unsigned LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in a `u32`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xE5, 0x8E, 0x26]));
+    ///     assert_eq!(624485u32, reader.read_uleb128_u32()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_uleb128_u32(&mut self) -> io::Result<u32> {
+        self.with_context("uleb128 u32", None, |reader| {
+            let decoder = leb128::Decoder::unsigned(32, reader.strict);
+            Ok(reader.read_leb128(decoder)? as u32)
+        })
+    }
+
+    /// Reads an unsigned 64-bit integer encoded as unsigned LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in a `u64`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xE5, 0x8E, 0x26]));
+    ///     assert_eq!(624485u64, reader.read_uleb128_u64()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_uleb128_u64(&mut self) -> io::Result<u64> {
+        self.with_context("uleb128 u64", None, |reader| {
+            let decoder = leb128::Decoder::unsigned(64, reader.strict);
+            Ok(reader.read_leb128(decoder)? as u64)
+        })
+    }
+
+    /// Reads an unsigned 128-bit integer encoded as unsigned LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in a `u128`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xE5, 0x8E, 0x26]));
+    ///     assert_eq!(624485u128, reader.read_uleb128_u128()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_uleb128_u128(&mut self) -> io::Result<u128> {
+        self.with_context("uleb128 u128", None, |reader| {
+            let decoder = leb128::Decoder::unsigned(128, reader.strict);
+            reader.read_leb128(decoder)
+        })
+    }
+
+    /// Reads a signed 32-bit integer encoded as signed LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in an `i32`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xC0, 0xBB, 0x78]));
+    ///     assert_eq!(-123456i32, reader.read_sleb128_i32()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_sleb128_i32(&mut self) -> io::Result<i32> {
+        self.with_context("sleb128 i32", None, |reader| {
+            let decoder = leb128::Decoder::signed(32, reader.strict);
+            Ok(reader.read_leb128(decoder)? as i32)
+        })
+    }
+
+    /// Reads a signed 64-bit integer encoded as signed LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in an `i64`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xC0, 0xBB, 0x78]));
+    ///     assert_eq!(-123456i64, reader.read_sleb128_i64()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_sleb128_i64(&mut self) -> io::Result<i64> {
+        self.with_context("sleb128 i64", None, |reader| {
+            let decoder = leb128::Decoder::signed(64, reader.strict);
+            Ok(reader.read_leb128(decoder)? as i64)
+        })
+    }
+
+    /// Reads a signed 128-bit integer encoded as signed LEB128 from the
+    /// underlying reader.
+    ///
+    /// LEB128 encodings are read one byte at a time and do not depend on the
+    /// byte order of this `NumberReader`. If the reader is [strict], encodings
+    /// which are longer than necessary are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Read::read_exact`]. If the encoded value does not fit in an `i128`, or
+    /// if the reader is [strict] and the encoding is not canonical, an error of
+    /// the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0xC0, 0xBB, 0x78]));
+    ///     assert_eq!(-123456i128, reader.read_sleb128_i128()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_sleb128_i128(&mut self) -> io::Result<i128> {
+        self.with_context("sleb128 i128", None, |reader| {
+            let decoder = leb128::Decoder::signed(128, reader.strict);
+            Ok(reader.read_leb128(decoder)? as i128)
+        })
+    }
+
+    /// Reads a signed 32-bit integer encoded as zigzag unsigned LEB128 from
+    /// the underlying reader, such as a `sint32` of Protocol Buffers.
+    ///
+    /// The value is read with [`read_uleb128_u32`], and then decoded with
+    /// [`zigzag::decode_i32`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`read_uleb128_u32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F]));
+    ///     assert_eq!(-2, reader.read_zigzag_i32()?);
+    ///     assert_eq!(i32::MIN, reader.read_zigzag_i32()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_uleb128_u32`]: NumberReader::read_uleb128_u32
+    /// [`zigzag::decode_i32`]: crate::zigzag::decode_i32
+    #[inline]
+    pub fn read_zigzag_i32(&mut self) -> io::Result<i32> {
+        self.with_context("zigzag i32", None, |reader| {
+            Ok(zigzag::decode_i32(reader.read_uleb128_u32()?))
+        })
+    }
+
+    /// Reads a signed 64-bit integer encoded as zigzag unsigned LEB128 from
+    /// the underlying reader, such as a `sint64` of Protocol Buffers.
+    ///
+    /// The value is read with [`read_uleb128_u64`], and then decoded with
+    /// [`zigzag::decode_i64`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`read_uleb128_u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x03, 0x04]));
+    ///     assert_eq!(-2i64, reader.read_zigzag_i64()?);
+    ///     assert_eq!(2i64, reader.read_zigzag_i64()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_uleb128_u64`]: NumberReader::read_uleb128_u64
+    /// [`zigzag::decode_i64`]: crate::zigzag::decode_i64
+    #[inline]
+    pub fn read_zigzag_i64(&mut self) -> io::Result<i64> {
+        self.with_context("zigzag i64", None, |reader| {
+            Ok(zigzag::decode_i64(reader.read_uleb128_u64()?))
+        })
+    }
+
+    /// Reads an unsigned integer encoded with a variable-length `scheme` from
+    /// the underlying reader.
+    ///
+    /// If this reader is [strict], encodings which are longer than the
+    /// shortest one of their value are rejected. See the [`varint`] module for
+    /// the schemes which are provided.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. An error of the kind [`ErrorKind::InvalidData`]
+    /// is returned if the encoding is longer than the scheme allows, if its
+    /// value does not fit in a `u64`, or if this reader is strict and the
+    /// encoding is not canonical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::NumberReader;
+    /// use byte_order::varint::{CompactSize, Quic};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x7B, 0xBD, 0xFD, 0x03, 0x02]);
+    ///     let mut reader = NumberReader::new(src);
+    ///     assert_eq!(15293u64, reader.read_varint(Quic)?);
+    ///     assert_eq!(515u64, reader.read_varint(CompactSize)?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [strict]: NumberReader::set_strict
+    /// [`varint`]: crate::varint
+    /// [`Read::read_exact`]: Read::read_exact
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_varint<S: VarintScheme>(&mut self, scheme: S) -> io::Result<u64> {
+        let strict = self.strict;
+        self.with_context("varint", None, |reader| scheme.decode(reader, strict))
+    }
+
+    try_read_numbers! {
+        try_read_u8: u8, "an unsigned 8-bit integer";
+        try_read_i8: i8, "a signed 8-bit integer";
+        try_read_u16: u16, "an unsigned 16-bit integer";
+        try_read_i16: i16, "a signed 16-bit integer";
+        try_read_u32: u32, "an unsigned 32-bit integer";
+        try_read_i32: i32, "a signed 32-bit integer";
+        try_read_u64: u64, "an unsigned 64-bit integer";
+        try_read_i64: i64, "a signed 64-bit integer";
+        try_read_u128: u128, "an unsigned 128-bit integer";
+        try_read_i128: i128, "a signed 128-bit integer";
+        try_read_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        try_read_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    try_read_leb128! {
+        try_read_uleb128_u32 => read_uleb128_u32: u32, unsigned(32), "uleb128 u32";
+        try_read_uleb128_u64 => read_uleb128_u64: u64, unsigned(64), "uleb128 u64";
+        try_read_uleb128_u128 => read_uleb128_u128: u128, unsigned(128), "uleb128 u128";
+        try_read_sleb128_i32 => read_sleb128_i32: i32, signed(32), "sleb128 i32";
+        try_read_sleb128_i64 => read_sleb128_i64: i64, signed(64), "sleb128 i64";
+        try_read_sleb128_i128 => read_sleb128_i128: i128, signed(128), "sleb128 i128";
+    }
+
+    /// Reads a value like [`read_zigzag_i32`], or returns `None` if the
+    /// underlying reader is at end of file.
+    ///
+    /// See [`try_read_uleb128_u32`] for details.
+    ///
+    /// [`read_zigzag_i32`]: NumberReader::read_zigzag_i32
+    /// [`try_read_uleb128_u32`]: NumberReader::try_read_uleb128_u32
+    #[inline]
+    pub fn try_read_zigzag_i32(&mut self) -> io::Result<Option<i32>> {
+        let n = self.with_context("zigzag i32", None, |reader| reader.try_read_uleb128_u32())?;
+        Ok(n.map(zigzag::decode_i32))
+    }
+
+    /// Reads a value like [`read_zigzag_i64`], or returns `None` if the
+    /// underlying reader is at end of file.
+    ///
+    /// See [`try_read_uleb128_u64`] for details.
+    ///
+    /// [`read_zigzag_i64`]: NumberReader::read_zigzag_i64
+    /// [`try_read_uleb128_u64`]: NumberReader::try_read_uleb128_u64
+    #[inline]
+    pub fn try_read_zigzag_i64(&mut self) -> io::Result<Option<i64>> {
+        let n = self.with_context("zigzag i64", None, |reader| reader.try_read_uleb128_u64())?;
+        Ok(n.map(zigzag::decode_i64))
+    }
+
+    /// Reads a half-precision floating point number from the underlying reader,
+    /// widening it to an `f32`.
+    ///
+    /// The conversion to `f32` is exact, so subnormal numbers, infinities, and
+    /// NaN payloads are all preserved.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x4A, 0x40]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     assert_eq!(12.5f32, be_reader.read_f16_as_f32()?);
+    ///
+    ///     let le_src = Cursor::new(vec![0x40, 0x4A]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     assert_eq!(12.5f32, le_reader.read_f16_as_f32()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_f16_as_f32(&mut self) -> io::Result<f32> {
+        self.with_context("f16", Some(2), |reader| {
+            Ok(float::f16_to_f32(reader.read_u16()?))
+        })
+    }
+
+    /// Reads a sequence of half-precision floating point numbers from the
+    /// underlying reader, widening each of them to an `f32` and filling `dst`
+    /// entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst` are
+    /// unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x4A, 0x40, 0xBA, 0x00]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     let mut dst = [0f32; 2];
+    ///     be_reader.read_f16_as_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     let le_src = Cursor::new(vec![0x40, 0x4A, 0x00, 0xBA]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     let mut dst = [0f32; 2];
+    ///     le_reader.read_f16_as_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_f16_as_f32_into(&mut self, dst: &mut [f32]) -> io::Result<()> {
+        self.read_half_into(dst, "[f16]", float::f16_to_f32)
+    }
+
+    /// Reads a brain floating point number (bfloat16) from the underlying
+    /// reader, widening it to an `f32`.
+    ///
+    /// The conversion to `f32` is exact, so subnormal numbers, infinities, and
+    /// NaN payloads are all preserved.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x41, 0x48]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     assert_eq!(12.5f32, be_reader.read_bf16_as_f32()?);
+    ///
+    ///     let le_src = Cursor::new(vec![0x48, 0x41]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     assert_eq!(12.5f32, le_reader.read_bf16_as_f32()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_bf16_as_f32(&mut self) -> io::Result<f32> {
+        self.with_context("bf16", Some(2), |reader| {
+            Ok(float::bf16_to_f32(reader.read_u16()?))
+        })
+    }
+
+    /// Reads a sequence of brain floating point numbers (bfloat16) from the
+    /// underlying reader, widening each of them to an `f32` and filling `dst`
+    /// entirely.
+    ///
+    /// The bytes for the whole of `dst` are read with a single call to
+    /// [`Read::read_exact`] and are then converted in place.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`]. If an error is returned, the contents of `dst` are
+    /// unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let be_src = Cursor::new(vec![0x41, 0x48, 0xBF, 0x40]);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
+    ///     let mut dst = [0f32; 2];
+    ///     be_reader.read_bf16_as_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     let le_src = Cursor::new(vec![0x48, 0x41, 0x40, 0xBF]);
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
+    ///     let mut dst = [0f32; 2];
+    ///     le_reader.read_bf16_as_f32_into(&mut dst)?;
+    ///     assert_eq!([12.5, -0.75], dst);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_bf16_as_f32_into(&mut self, dst: &mut [f32]) -> io::Result<()> {
+        self.read_half_into(dst, "[bf16]", float::bf16_to_f32)
+    }
+
+    /// Reads an unsigned integer that is `nbytes` bytes wide from the
+    /// underlying reader.
+    ///
+    /// This is useful for formats which store integers in widths that have no
+    /// corresponding primitive type, such as 24-bit or 40-bit integers.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=8`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x1234567890u64, be_reader.read_uint(5)?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x9078563412u64, le_reader.read_uint(5)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_uint(&mut self, nbytes: usize) -> io::Result<u64> {
+        self.with_context("uint", Some(nbytes), |reader| {
+            Ok(reader.read_uint_bytes(nbytes, mem::size_of::<u64>())? as u64)
+        })
+    }
+
+    /// Reads a signed integer that is `nbytes` bytes wide from the underlying
+    /// reader, sign-extending it to 64 bits.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=8`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(-2i64, be_reader.read_int(5)?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(-0x100000001i64, le_reader.read_int(5)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_int(&mut self, nbytes: usize) -> io::Result<i64> {
+        self.with_context("int", Some(nbytes), |reader| {
+            Ok(reader.read_int_bytes(nbytes, mem::size_of::<i64>())? as i64)
         })
     }
 
-    /// Reads a IEEE754 double-precision floating point number from the
-    /// underlying reader.
+    /// Reads an unsigned integer that is `nbytes` bytes wide from the
+    /// underlying reader into a 128-bit integer.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=16`.
     ///
     /// # Errors
     ///
@@ -605,13 +2389,15 @@ impl<R: Read> NumberReader<R> {
     /// use byte_order::{ByteOrder, NumberReader};
     ///
     /// fn main() -> io::Result<()> {
-    ///     let be_src = Cursor::new(vec![0x40, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
-    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, be_src);
-    ///     assert_eq!(12.5f64, be_reader.read_f64()?);
+    ///     let src = Cursor::new(vec![
+    ///         0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90,
+    ///     ]);
     ///
-    ///     let le_src = Cursor::new(vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x29, 0x40]);
-    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, le_src);
-    ///     assert_eq!(12.5f64, le_reader.read_f64()?);
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x12345678901234567890u128, be_reader.read_uint128(10)?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x90785634129078563412u128, le_reader.read_uint128(10)?);
     ///
     ///     Ok(())
     /// }
@@ -619,19 +2405,564 @@ impl<R: Read> NumberReader<R> {
     ///
     /// [`Read::read_exact`]: Read::read_exact
     #[inline]
-    pub fn read_f64(&mut self) -> io::Result<f64> {
-        let mut buf = [0; mem::size_of::<f64>()];
-        self.inner.read_exact(&mut buf)?;
-        Ok(if let ByteOrder::LE = self.order {
-            f64::from_le_bytes(buf)
+    pub fn read_uint128(&mut self, nbytes: usize) -> io::Result<u128> {
+        self.with_context("uint128", Some(nbytes), |reader| {
+            reader.read_uint_bytes(nbytes, mem::size_of::<u128>())
+        })
+    }
+
+    /// Reads a signed integer that is `nbytes` bytes wide from the underlying
+    /// reader, sign-extending it to 128 bits.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=16`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![
+    ///         0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    ///     ]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(-2i128, be_reader.read_int128(10)?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(-0x1000000000000000001i128, le_reader.read_int128(10)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_int128(&mut self, nbytes: usize) -> io::Result<i128> {
+        self.with_context("int128", Some(nbytes), |reader| {
+            reader.read_int_bytes(nbytes, mem::size_of::<i128>())
+        })
+    }
+
+    /// Reads an unsigned 24-bit integer from the underlying reader.
+    ///
+    /// This is equivalent to calling [`read_uint`] with `nbytes` set to `3`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x123456u32, be_reader.read_u24()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x563412u32, le_reader.read_u24()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_uint`]: NumberReader::read_uint
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u24(&mut self) -> io::Result<u32> {
+        self.with_context("u24", Some(3), |reader| {
+            Ok(reader.read_uint_bytes(3, 3)? as u32)
+        })
+    }
+
+    /// Reads a signed 24-bit integer from the underlying reader, sign-extending
+    /// it to 32 bits.
+    ///
+    /// This is equivalent to calling [`read_int`] with `nbytes` set to `3`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(-2i32, be_reader.read_i24()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(-0x10001i32, le_reader.read_i24()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_int`]: NumberReader::read_int
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i24(&mut self) -> io::Result<i32> {
+        self.with_context("i24", Some(3), |reader| {
+            Ok(reader.read_int_bytes(3, 3)? as i32)
+        })
+    }
+
+    /// Reads an unsigned 48-bit integer from the underlying reader.
+    ///
+    /// This is equivalent to calling [`read_uint`] with `nbytes` set to `6`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(0x123456789012u64, be_reader.read_u48()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(0x129078563412u64, le_reader.read_u48()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_uint`]: NumberReader::read_uint
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_u48(&mut self) -> io::Result<u64> {
+        self.with_context("u48", Some(6), |reader| {
+            Ok(reader.read_uint_bytes(6, 6)? as u64)
+        })
+    }
+
+    /// Reads a signed 48-bit integer from the underlying reader, sign-extending
+    /// it to 64 bits.
+    ///
+    /// This is equivalent to calling [`read_int`] with `nbytes` set to `6`.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Read::read_exact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{ByteOrder, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut be_reader = NumberReader::with_order(ByteOrder::BE, src.clone());
+    ///     assert_eq!(-2i64, be_reader.read_i48()?);
+    ///
+    ///     let mut le_reader = NumberReader::with_order(ByteOrder::LE, src.clone());
+    ///     assert_eq!(-0x10000000001i64, le_reader.read_i48()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`read_int`]: NumberReader::read_int
+    /// [`Read::read_exact`]: Read::read_exact
+    #[inline]
+    pub fn read_i48(&mut self) -> io::Result<i64> {
+        self.with_context("i48", Some(6), |reader| {
+            Ok(reader.read_int_bytes(6, 6)? as i64)
+        })
+    }
+
+    /// Reads 16-bit floating point numbers with a single call to
+    /// [`Read::read_exact`], widening them in place with `to_f32`.
+    #[inline]
+    fn read_half_into(
+        &mut self,
+        dst: &mut [f32],
+        type_name: &'static str,
+        to_f32: fn(u16) -> f32,
+    ) -> io::Result<()> {
+        let len = dst.len();
+        self.with_context(type_name, Some(2 * len), |reader| {
+            reader.read_raw(&mut pod::as_bytes_mut(dst)[..2 * len])
+        })?;
+        float::widen_in_place(dst, self.order.byte_order(), to_f32);
+        Ok(())
+    }
+
+    /// Reads `nbytes` bytes from the underlying reader as an unsigned integer.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=max`.
+    #[inline]
+    fn read_uint_bytes(&mut self, nbytes: usize, max: usize) -> io::Result<u128> {
+        assert!(
+            (1..=max).contains(&nbytes),
+            "nbytes must be in the range 1..={}, but was {}",
+            max,
+            nbytes
+        );
+        let mut buf = [0; mem::size_of::<u128>()];
+        let buf = &mut buf[..nbytes];
+        self.read_raw(buf)?;
+        let acc = |n, &b| n << 8 | u128::from(b);
+        Ok(if let ByteOrder::LE = self.order.byte_order() {
+            buf.iter().rev().fold(0, acc)
         } else {
-            f64::from_be_bytes(buf)
+            buf.iter().fold(0, acc)
         })
     }
+
+    /// Reads `nbytes` bytes from the underlying reader as a signed integer,
+    /// sign-extending it to 128 bits.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=max`.
+    #[inline]
+    fn read_int_bytes(&mut self, nbytes: usize, max: usize) -> io::Result<i128> {
+        let n = self.read_uint_bytes(nbytes, max)?;
+        let shift = 128 - 8 * nbytes as u32;
+        Ok((n << shift) as i128 >> shift)
+    }
+
+    /// Fills `buf` from the underlying reader, counting the bytes if the
+    /// position is tracked.
+    #[inline]
+    fn read_raw(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        if self.position.is_some() {
+            return self.read_raw_partial(buf);
+        }
+        self.inner.read_exact(buf)
+    }
+
+    /// Fills `buf` from the underlying reader like [`read_raw`], but counts
+    /// the bytes that were read even if it fails, so that the position stays
+    /// in step with the underlying reader and errors can report how many
+    /// bytes were available.
+    ///
+    /// [`read_raw`]: NumberReader::read_raw
+    fn read_raw_partial(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        let mut filled = 0;
+        let result = loop {
+            if filled == buf.len() {
+                break Ok(());
+            }
+            match self.inner.read(&mut buf[filled..]) {
+                Ok(0) => {
+                    break Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.advance(filled);
+        result
+    }
+
+    /// Runs `f`, the body of a `read_*` method for `type_name`, describing
+    /// its error with a [`DecodeError`] if error context is enabled and no
+    /// other `read_*` method is running.
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    #[cfg(feature = "std")]
+    #[inline]
+    fn with_context<T, F>(
+        &mut self,
+        type_name: &'static str,
+        width: Option<usize>,
+        f: F,
+    ) -> io::Result<T>
+    where
+        F: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        let outermost = match &mut self.context {
+            Some(context) => context.enter(),
+            None => return f(self),
+        };
+        let start = self.position.unwrap_or(0);
+        let result = f(self);
+        if let Some(context) = &mut self.context {
+            context.exit();
+        }
+        match result {
+            Err(err) if outermost => Err(self.describe(err, start, type_name, width)),
+            result => result,
+        }
+    }
+
+    /// Runs `f`, the body of a `read_*` method for `type_name`.
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    fn with_context<T, F>(
+        &mut self,
+        type_name: &'static str,
+        width: Option<usize>,
+        f: F,
+    ) -> io::Result<T>
+    where
+        F: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        let _ = (type_name, width);
+        f(self)
+    }
+
+    /// Wraps `err` in a [`DecodeError`] for a read of `type_name` which began
+    /// at `start`, if error context is enabled and it is not one already.
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    #[cfg(feature = "std")]
+    pub(crate) fn describe(
+        &self,
+        err: io::Error,
+        start: u64,
+        type_name: &'static str,
+        width: Option<usize>,
+    ) -> io::Error {
+        match &self.context {
+            Some(context) => {
+                let available = self.position.unwrap_or(0).saturating_sub(start);
+                context.describe(err, start, type_name, width, available as usize)
+            }
+            None => err,
+        }
+    }
+
+    /// Adds `n` bytes to the position, if it is tracked.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        if let Some(position) = &mut self.position {
+            *position += n as u64;
+        }
+    }
+
+    /// Reads bytes from the underlying reader until `decoder` yields a value.
+    #[inline]
+    fn read_leb128(&mut self, mut decoder: leb128::Decoder) -> io::Result<u128> {
+        loop {
+            if let Some(n) = decoder.push(self.read_u8()?)? {
+                return Ok(n);
+            }
+        }
+    }
+
+    /// Fills `buf` from the underlying reader, returning `false` if the
+    /// reader is at end of file before the first byte, and an error of the
+    /// kind [`ErrorKind::UnexpectedEof`] if it is at end of file afterwards.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    fn read_unless_eof(&mut self, buf: &mut [u8]) -> io::Result<bool> {
+        if buf.is_empty() {
+            return Ok(true);
+        }
+        let n = loop {
+            match self.inner.read(buf) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        };
+        if n == 0 {
+            return Ok(false);
+        }
+        self.advance(n);
+        self.read_raw(&mut buf[n..])?;
+        Ok(true)
+    }
+
+    /// Reads bytes from the underlying reader until `decoder` yields a value,
+    /// returning `None` if the reader is at end of file before the first one.
+    #[inline]
+    fn try_read_leb128(&mut self, mut decoder: leb128::Decoder) -> io::Result<Option<u128>> {
+        let mut byte = [0];
+        if !self.read_unless_eof(&mut byte)? {
+            return Ok(None);
+        }
+        match decoder.push(byte[0])? {
+            Some(n) => Ok(Some(n)),
+            None => self.read_leb128(decoder).map(Some),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, O: Endianness> NumberReader<R, O> {
+    /// Returns whether this `NumberReader` describes the errors of its
+    /// `read_*` methods with a [`DecodeError`].
+    ///
+    /// This method is only available with the `std` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use byte_order::NumberReader;
+    ///
+    /// let reader = NumberReader::new(Cursor::new(vec![]));
+    /// assert!(!reader.has_error_context());
+    /// ```
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    pub fn has_error_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    /// Sets whether this `NumberReader` describes the errors of its `read_*`
+    /// methods with a [`DecodeError`].
+    ///
+    /// Error context is disabled by default, in which case errors are
+    /// returned exactly as the underlying reader returns them. When it is
+    /// enabled, every error returned by a `read_*` method, or by [`decode`],
+    /// is an [`io::Error`] of the same kind which wraps a [`DecodeError`]
+    /// recording the offset of the read, the type requested, the number of
+    /// bytes it required and that were available, and the path given with
+    /// [`with_field`].
+    ///
+    /// Offsets are taken from the [position] of this `NumberReader`, so
+    /// enabling error context also enables position tracking.
+    ///
+    /// This method is only available with the `std` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use byte_order::{DecodeError, NumberReader};
+    ///
+    /// let mut reader = NumberReader::new(Cursor::new(vec![0x12, 0x34]));
+    /// reader.set_error_context(true);
+    /// reader.read_u8().unwrap();
+    ///
+    /// let err = reader.read_f64().unwrap_err();
+    /// assert_eq!(
+    ///     "failed to read f64 at offset 1: expected 8 bytes, 1 available: \
+    ///      failed to fill whole buffer",
+    ///     err.to_string()
+    /// );
+    /// assert_eq!(1, DecodeError::from_io_error(&err).unwrap().available());
+    /// ```
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    /// [`decode`]: NumberReader::decode
+    /// [`with_field`]: NumberReader::with_field
+    /// [position]: NumberReader::position
+    pub fn set_error_context(&mut self, enabled: bool) {
+        if !enabled {
+            self.context = None;
+        } else if self.context.is_none() {
+            self.context = Some(ErrorContext::default());
+            self.set_track_position(true);
+        }
+    }
+
+    /// Runs `f` with this `NumberReader`, adding `name` to the path of the
+    /// field reported by any [`DecodeError`] that it returns.
+    ///
+    /// Calls may be nested, in which case the names are joined by `.`. If
+    /// error context is not enabled, `f` is simply run.
+    ///
+    /// This method is only available with the `std` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, Cursor};
+    /// use byte_order::{DecodeError, NumberReader};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut reader = NumberReader::new(Cursor::new(vec![0x01, 0x80]));
+    ///     reader.set_error_context(true);
+    ///
+    ///     let err = reader
+    ///         .with_field("header", |reader| {
+    ///             let version = reader.with_field("version", |reader| reader.read_u8())?;
+    ///             reader.with_field("flags", |reader| reader.read_uleb128_u32())?;
+    ///             Ok(version)
+    ///         })
+    ///         .unwrap_err();
+    ///
+    ///     let err = DecodeError::from_io_error(&err).unwrap();
+    ///     assert_eq!(Some("header.flags"), err.path());
+    ///     assert_eq!("uleb128 u32", err.type_name());
+    ///     assert_eq!(1, err.offset());
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`DecodeError`]: crate::DecodeError
+    pub fn with_field<T, F>(&mut self, name: &str, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        match &mut self.context {
+            Some(context) => context.push_field(name),
+            None => return f(self),
+        }
+        let value = f(self);
+        if let Some(context) = &mut self.context {
+            context.pop_field();
+        }
+        value
+    }
 }
 
-impl<R: Read> Read for NumberReader<R> {
+#[cfg(feature = "std")]
+impl<R: Read, O: Endianness> std::io::Read for NumberReader<R, O> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        self.inner.read(buf)
+        let n = self.inner.read(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.read_raw(buf)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<R: Read, O: Endianness> Read for NumberReader<R, O> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.read_raw(buf)
     }
 }
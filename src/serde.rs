@@ -0,0 +1,1157 @@
+//! A [`Serializer`] and [`Deserializer`] of `serde` for a compact binary
+//! layout.
+//!
+//! Values are written with a [`NumberWriter`] and read with a
+//! [`NumberReader`], so every integer and floating point number is encoded in
+//! the byte order that the serializer or deserializer takes upon
+//! initialization. The layout is not self-describing: nothing but the values
+//! themselves is written, and a value can only be read back as the type it
+//! was written as.
+//!
+//! | Type                                   | Layout                                    |
+//! |----------------------------------------|-------------------------------------------|
+//! | `bool`                                 | A single byte, either `0` or `1`          |
+//! | `u8` and `i8`                          | A single byte                             |
+//! | Other integers                         | Per the [`IntEncoding`]                   |
+//! | `f32` and `f64`                        | 4 and 8 bytes                             |
+//! | `char`                                 | Its scalar value, as a `u32`              |
+//! | Strings, byte arrays                   | A length prefix, followed by the bytes    |
+//! | `Option<T>`                            | A `bool`, followed by the `T` if `true`   |
+//! | Unit, unit structs                     | Nothing                                   |
+//! | Newtype structs                        | The inner value                           |
+//! | Sequences, maps                        | A length prefix, followed by the elements |
+//! | Tuples, tuple structs, structs         | Each field in order                       |
+//! | Enum variants                          | A tag, per the [`TagWidth`], then fields  |
+//!
+//! Length prefixes are encoded with the [`LengthPrefix`] set by
+//! [`Serializer::set_length_prefix`], which must match the one set on the
+//! [`Deserializer`]. The same goes for the [`IntEncoding`] and the
+//! [`TagWidth`].
+//!
+//! This module is only available with the `serde` feature enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_order::serde::{self, IntEncoding, Serializer};
+//! use byte_order::ByteOrder;
+//! use ::serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Reading {
+//!     sensor: u16,
+//!     values: Vec<i32>,
+//! }
+//!
+//! fn main() -> serde::Result<()> {
+//!     let reading = Reading { sensor: 7, values: vec![-1, 300] };
+//!
+//!     let bytes = serde::to_vec(ByteOrder::BE, &reading)?;
+//!     assert_eq!(serde::from_slice::<Reading>(ByteOrder::BE, &bytes)?, reading);
+//!
+//!     let mut serializer = Serializer::with_order(ByteOrder::BE, vec![]);
+//!     serializer.set_int_encoding(IntEncoding::Varint);
+//!     reading.serialize(&mut serializer)?;
+//!     assert_eq!(serializer.into_inner(), vec![0x07, 0x00, 0x00, 0x00, 0x02, 0x7F, 0xAC, 0x02]);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`NumberWriter`]: crate::NumberWriter
+//! [`NumberReader`]: crate::NumberReader
+//! [`LengthPrefix`]: crate::LengthPrefix
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, Visitor};
+use ::serde::ser::{self, Serialize};
+
+use crate::decode::read_byte_vec;
+use crate::io::{self, Read, Write};
+use crate::order::{ByteOrder, Endianness};
+use crate::prefix::LengthPrefix;
+use crate::read::NumberReader;
+use crate::write::NumberWriter;
+
+/// A specialized [`Result`] type for serialization and deserialization.
+///
+/// [`Result`]: core::result::Result
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The error type for serialization and deserialization.
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O error, or bytes which do not encode a value of the expected
+    /// type.
+    Io(io::Error),
+    /// An error raised by an implementation of [`Serialize`] or
+    /// [`Deserialize`].
+    ///
+    /// [`Serialize`]: ::serde::Serialize
+    /// [`Deserialize`]: ::serde::Deserialize
+    Custom(String),
+    /// A sequence or map whose length is not known before its elements are
+    /// serialized.
+    LengthRequired,
+    /// A value was deserialized without naming its type, which the layout of
+    /// this module does not record.
+    NotSelfDescribing,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => fmt::Display::fmt(e, f),
+            Error::Custom(msg) => f.write_str(msg),
+            Error::LengthRequired => f.write_str("sequence or map length must be known"),
+            Error::NotSelfDescribing => f.write_str("layout is not self-describing"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl de::StdError for Error {}
+
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// An enumeration of the encodings of integers wider than a byte.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IntEncoding {
+    /// Every integer takes as many bytes as its type, in the byte order of the
+    /// serializer or deserializer.
+    Fixed,
+    /// Unsigned integers are encoded as unsigned LEB128, and signed integers
+    /// as signed LEB128, so small values take fewer bytes.
+    Varint,
+}
+
+impl Default for IntEncoding {
+    #[inline]
+    fn default() -> IntEncoding {
+        IntEncoding::Fixed
+    }
+}
+
+/// An enumeration of the encodings of the tag which identifies the variant of
+/// an enum.
+///
+/// The tag is the index of the variant, in the order of declaration.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TagWidth {
+    /// An unsigned 8-bit integer.
+    U8,
+    /// An unsigned 16-bit integer.
+    U16,
+    /// An unsigned 32-bit integer.
+    U32,
+    /// An unsigned LEB128 integer of at most 32 bits.
+    Uleb128,
+}
+
+impl Default for TagWidth {
+    #[inline]
+    fn default() -> TagWidth {
+        TagWidth::U32
+    }
+}
+
+/// Serializes `value` into a new `Vec<u8>` with the given byte order and
+/// default settings.
+///
+/// # Errors
+///
+/// This function propagates any error returned by the implementation of
+/// [`Serialize`] for `T`, and returns [`Error::LengthRequired`] for sequences
+/// and maps whose length is not known in advance.
+///
+/// [`Serialize`]: ::serde::Serialize
+#[inline]
+pub fn to_vec<T: Serialize + ?Sized>(order: ByteOrder, value: &T) -> Result<Vec<u8>> {
+    let mut serializer = Serializer::with_order(order, Vec::new());
+    value.serialize(&mut serializer)?;
+    Ok(serializer.into_inner())
+}
+
+/// Serializes `value` into `writer` with the given byte order and default
+/// settings.
+///
+/// # Errors
+///
+/// This function propagates any error returned by the implementation of
+/// [`Serialize`] for `T` or by `writer`, and returns
+/// [`Error::LengthRequired`] for sequences and maps whose length is not known
+/// in advance.
+///
+/// [`Serialize`]: ::serde::Serialize
+#[inline]
+pub fn to_writer<W: Write, T: Serialize + ?Sized>(
+    order: ByteOrder,
+    writer: W,
+    value: &T,
+) -> Result<()> {
+    value.serialize(&mut Serializer::with_order(order, writer))
+}
+
+/// Deserializes a value of type `T` from the start of `bytes` with the given
+/// byte order and default settings.
+///
+/// Any bytes which follow the value are ignored.
+///
+/// # Errors
+///
+/// This function propagates any error returned by the implementation of
+/// [`Deserialize`] for `T`. If `bytes` ends before the value does, an error of
+/// the kind [`ErrorKind::UnexpectedEof`] is returned.
+///
+/// [`Deserialize`]: ::serde::Deserialize
+/// [`ErrorKind::UnexpectedEof`]: crate::io::ErrorKind::UnexpectedEof
+#[inline]
+pub fn from_slice<T: DeserializeOwned>(order: ByteOrder, bytes: &[u8]) -> Result<T> {
+    from_reader(order, bytes)
+}
+
+/// Deserializes a value of type `T` from `reader` with the given byte order
+/// and default settings.
+///
+/// # Errors
+///
+/// This function propagates any error returned by the implementation of
+/// [`Deserialize`] for `T` or by `reader`.
+///
+/// [`Deserialize`]: ::serde::Deserialize
+#[inline]
+pub fn from_reader<R: Read, T: DeserializeOwned>(order: ByteOrder, reader: R) -> Result<T> {
+    T::deserialize(&mut Deserializer::with_order(order, reader))
+}
+
+/// A `Serializer` writes values of any type that implements [`Serialize`] to
+/// a [writer].
+///
+/// See the [module documentation] for the layout of each type.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::serde::{Serializer, TagWidth};
+/// use byte_order::{ByteOrder, LengthPrefix};
+/// use ::serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// enum Command {
+///     Stop,
+///     Say(String),
+/// }
+///
+/// fn main() -> byte_order::serde::Result<()> {
+///     let mut serializer = Serializer::with_order(ByteOrder::LE, vec![]);
+///     serializer.set_length_prefix(LengthPrefix::U8);
+///     serializer.set_tag_width(TagWidth::U8);
+///
+///     Command::Say("hi".into()).serialize(&mut serializer)?;
+///     assert_eq!(serializer.into_inner(), vec![0x01, 0x02, b'h', b'i']);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Serialize`]: ::serde::Serialize
+/// [writer]: crate::io::Write
+/// [module documentation]: crate::serde
+pub struct Serializer<W: Write, O: Endianness = ByteOrder> {
+    writer: NumberWriter<W, O>,
+    int_encoding: IntEncoding,
+    tag_width: TagWidth,
+}
+
+impl<W: Write> Serializer<W> {
+    /// Creates a new `Serializer` which writes to `writer` with the target
+    /// platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: Serializer::with_order
+    #[inline]
+    pub fn new(writer: W) -> Serializer<W> {
+        Serializer::with_order(ByteOrder::NE, writer)
+    }
+}
+
+macro_rules! serialize_ints {
+    ($($name:ident: $ty:ty, $fixed:ident, $varint:ident $(as $wide:ty)?;)*) => {
+        $(
+            #[inline]
+            fn $name(self, v: $ty) -> Result<()> {
+                match self.int_encoding {
+                    IntEncoding::Fixed => self.writer.$fixed(v)?,
+                    IntEncoding::Varint => {
+                        $(let v = <$wide>::from(v);)?
+                        self.writer.$varint(v)?
+                    }
+                }
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<W: Write, O: Endianness> Serializer<W, O> {
+    /// Creates a new `Serializer` which writes to `writer` with the given byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::serde::Serializer;
+    /// use byte_order::{BigEndian, ByteOrder};
+    ///
+    /// let be_serializer = Serializer::with_order(ByteOrder::BE, vec![]);
+    /// let static_serializer = Serializer::with_order(BigEndian, vec![]);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, writer: W) -> Serializer<W, O> {
+        Serializer {
+            writer: NumberWriter::with_order(order, writer),
+            int_encoding: IntEncoding::default(),
+            tag_width: TagWidth::default(),
+        }
+    }
+
+    /// Consumes this `Serializer`, returning the underlying writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.writer.get_ref()
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying writer as doing so may corrupt the internal state of this
+    /// `Serializer`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        self.writer.get_mut()
+    }
+
+    /// Returns the encoding of integers wider than a byte.
+    #[inline]
+    pub fn int_encoding(&self) -> IntEncoding {
+        self.int_encoding
+    }
+
+    /// Sets the encoding of integers wider than a byte.
+    #[inline]
+    pub fn set_int_encoding(&mut self, int_encoding: IntEncoding) {
+        self.int_encoding = int_encoding;
+    }
+
+    /// Returns the encoding of the length that prefixes strings, byte arrays,
+    /// sequences, and maps.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.writer.length_prefix()
+    }
+
+    /// Sets the encoding of the length that prefixes strings, byte arrays,
+    /// sequences, and maps.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.writer.set_length_prefix(length_prefix);
+    }
+
+    /// Returns the encoding of the tag which identifies the variant of an
+    /// enum.
+    #[inline]
+    pub fn tag_width(&self) -> TagWidth {
+        self.tag_width
+    }
+
+    /// Sets the encoding of the tag which identifies the variant of an enum.
+    #[inline]
+    pub fn set_tag_width(&mut self, tag_width: TagWidth) {
+        self.tag_width = tag_width;
+    }
+
+    /// Writes the tag of the variant at `index`.
+    fn write_tag(&mut self, index: u32) -> Result<()> {
+        match self.tag_width {
+            TagWidth::U8 => self.writer.write_u8(narrow_tag(index)?)?,
+            TagWidth::U16 => self.writer.write_u16(narrow_tag(index)?)?,
+            TagWidth::U32 => self.writer.write_u32(index)?,
+            TagWidth::Uleb128 => self.writer.write_uleb128_u32(index)?,
+        }
+        Ok(())
+    }
+
+    /// Writes the length that prefixes a sequence or map.
+    fn write_len(&mut self, len: Option<usize>) -> Result<()> {
+        let len = len.ok_or(Error::LengthRequired)?;
+        self.writer.write_length(len)?;
+        Ok(())
+    }
+}
+
+#[inline]
+fn narrow_tag<T: TryFrom<u32>>(index: u32) -> Result<T> {
+    T::try_from(index).map_err(|_| {
+        Error::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "variant index does not fit in the tag",
+        ))
+    })
+}
+
+impl<W: Write, O: Endianness> ser::Serializer for &mut Serializer<W, O> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    #[inline]
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.writer.encode(&v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.writer.write_i8(v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.writer.write_u8(v)?;
+        Ok(())
+    }
+
+    serialize_ints! {
+        serialize_i16: i16, write_i16, write_sleb128_i32 as i32;
+        serialize_i32: i32, write_i32, write_sleb128_i32;
+        serialize_i64: i64, write_i64, write_sleb128_i64;
+        serialize_i128: i128, write_i128, write_sleb128_i128;
+        serialize_u16: u16, write_u16, write_uleb128_u32 as u32;
+        serialize_u32: u32, write_u32, write_uleb128_u32;
+        serialize_u64: u64, write_u64, write_uleb128_u64;
+        serialize_u128: u128, write_u128, write_uleb128_u128;
+    }
+
+    #[inline]
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.writer.write_f32(v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.writer.write_f64(v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_u32(v.into())
+    }
+
+    #[inline]
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.writer.encode(v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.writer.write_length(v.len())?;
+        self.writer.write_all(v)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_bool(false)
+    }
+
+    #[inline]
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        self.serialize_bool(true)?;
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.write_tag(variant_index)
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.write_tag(variant_index)?;
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        self.write_len(len)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.write_tag(variant_index)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_map(self, len: Option<usize>) -> Result<Self> {
+        self.write_len(len)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.write_tag(variant_index)?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! serialize_elements {
+    ($($trait:ident: $method:ident;)*) => {
+        $(
+            impl<W: Write, O: Endianness> ser::$trait for &mut Serializer<W, O> {
+                type Ok = ();
+                type Error = Error;
+
+                #[inline]
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+                    value.serialize(&mut **self)
+                }
+
+                #[inline]
+                fn end(self) -> Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+serialize_elements! {
+    SerializeSeq: serialize_element;
+    SerializeTuple: serialize_element;
+    SerializeTupleStruct: serialize_field;
+    SerializeTupleVariant: serialize_field;
+}
+
+macro_rules! serialize_fields {
+    ($($trait:ident;)*) => {
+        $(
+            impl<W: Write, O: Endianness> ser::$trait for &mut Serializer<W, O> {
+                type Ok = ();
+                type Error = Error;
+
+                #[inline]
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    _key: &'static str,
+                    value: &T,
+                ) -> Result<()> {
+                    value.serialize(&mut **self)
+                }
+
+                #[inline]
+                fn end(self) -> Result<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+serialize_fields! {
+    SerializeStruct;
+    SerializeStructVariant;
+}
+
+impl<W: Write, O: Endianness> ser::SerializeMap for &mut Serializer<W, O> {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        key.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A `Deserializer` reads values of any type that implements
+/// [`Deserialize`] from a [reader].
+///
+/// See the [module documentation] for the layout of each type. Since the
+/// layout is not self-describing, types which rely on
+/// `Deserializer::deserialize_any`, such as untagged enums, are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::serde::{Deserializer, TagWidth};
+/// use byte_order::{ByteOrder, LengthPrefix};
+/// use ::serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// enum Command {
+///     Stop,
+///     Say(String),
+/// }
+///
+/// fn main() -> byte_order::serde::Result<()> {
+///     let src: &[u8] = &[0x01, 0x02, b'h', b'i'];
+///     let mut deserializer = Deserializer::with_order(ByteOrder::LE, src);
+///     deserializer.set_length_prefix(LengthPrefix::U8);
+///     deserializer.set_tag_width(TagWidth::U8);
+///
+///     let command = Command::deserialize(&mut deserializer)?;
+///     assert_eq!(Command::Say("hi".into()), command);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Deserialize`]: ::serde::Deserialize
+/// [reader]: crate::io::Read
+/// [module documentation]: crate::serde
+pub struct Deserializer<R: Read, O: Endianness = ByteOrder> {
+    reader: NumberReader<R, O>,
+    int_encoding: IntEncoding,
+    tag_width: TagWidth,
+}
+
+impl<R: Read> Deserializer<R> {
+    /// Creates a new `Deserializer` which reads from `reader` with the target
+    /// platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: Deserializer::with_order
+    #[inline]
+    pub fn new(reader: R) -> Deserializer<R> {
+        Deserializer::with_order(ByteOrder::NE, reader)
+    }
+}
+
+macro_rules! deserialize_ints {
+    ($($name:ident: $ty:ty, $visit:ident, $fixed:ident, $varint:ident $(as $wide:ty)?;)*) => {
+        $(
+            #[inline]
+            fn $name<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                let v = match self.int_encoding {
+                    IntEncoding::Fixed => self.reader.$fixed()?,
+                    IntEncoding::Varint => {
+                        let v = self.reader.$varint()?;
+                        $(let v = narrow_int::<$wide, $ty>(v)?;)?
+                        v
+                    }
+                };
+                visitor.$visit(v)
+            }
+        )*
+    };
+}
+
+impl<R: Read, O: Endianness> Deserializer<R, O> {
+    /// Creates a new `Deserializer` which reads from `reader` with the given
+    /// byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::serde::Deserializer;
+    /// use byte_order::{BigEndian, ByteOrder};
+    ///
+    /// let be_deserializer = Deserializer::with_order(ByteOrder::BE, &[0x12][..]);
+    /// let static_deserializer = Deserializer::with_order(BigEndian, &[0x12][..]);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, reader: R) -> Deserializer<R, O> {
+        Deserializer {
+            reader: NumberReader::with_order(order, reader),
+            int_encoding: IntEncoding::default(),
+            tag_width: TagWidth::default(),
+        }
+    }
+
+    /// Consumes this `Deserializer`, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        self.reader.get_ref()
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader as doing so may corrupt the internal state of this
+    /// `Deserializer`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        self.reader.get_mut()
+    }
+
+    /// Returns the encoding of integers wider than a byte.
+    #[inline]
+    pub fn int_encoding(&self) -> IntEncoding {
+        self.int_encoding
+    }
+
+    /// Sets the encoding of integers wider than a byte.
+    #[inline]
+    pub fn set_int_encoding(&mut self, int_encoding: IntEncoding) {
+        self.int_encoding = int_encoding;
+    }
+
+    /// Returns the encoding of the length that prefixes strings, byte arrays,
+    /// sequences, and maps.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.reader.length_prefix()
+    }
+
+    /// Sets the encoding of the length that prefixes strings, byte arrays,
+    /// sequences, and maps.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.reader.set_length_prefix(length_prefix);
+    }
+
+    /// Returns the encoding of the tag which identifies the variant of an
+    /// enum.
+    #[inline]
+    pub fn tag_width(&self) -> TagWidth {
+        self.tag_width
+    }
+
+    /// Sets the encoding of the tag which identifies the variant of an enum.
+    #[inline]
+    pub fn set_tag_width(&mut self, tag_width: TagWidth) {
+        self.tag_width = tag_width;
+    }
+
+    /// Returns `true` if this `Deserializer` rejects non-canonical encodings
+    /// of variable-length integers.
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.reader.is_strict()
+    }
+
+    /// Sets whether this `Deserializer` rejects non-canonical encodings of
+    /// variable-length integers.
+    ///
+    /// See [`NumberReader::set_strict`] for details.
+    ///
+    /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.reader.set_strict(strict);
+    }
+
+    /// Reads the tag of a variant, returning its index.
+    fn read_tag(&mut self) -> Result<u32> {
+        let index = match self.tag_width {
+            TagWidth::U8 => self.reader.read_u8()?.into(),
+            TagWidth::U16 => self.reader.read_u16()?.into(),
+            TagWidth::U32 => self.reader.read_u32()?,
+            TagWidth::Uleb128 => self.reader.read_uleb128_u32()?,
+        };
+        Ok(index)
+    }
+
+    /// Reads an unsigned 32-bit integer with the integer encoding.
+    fn read_u32(&mut self) -> Result<u32> {
+        let n = match self.int_encoding {
+            IntEncoding::Fixed => self.reader.read_u32()?,
+            IntEncoding::Varint => self.reader.read_uleb128_u32()?,
+        };
+        Ok(n)
+    }
+}
+
+#[inline]
+fn narrow_int<T, U: TryFrom<T>>(n: T) -> Result<U> {
+    U::try_from(n).map_err(|_| {
+        Error::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "integer out of range for its type",
+        ))
+    })
+}
+
+impl<'de, R: Read, O: Endianness> de::Deserializer<'de> for &mut Deserializer<R, O> {
+    type Error = Error;
+
+    #[inline]
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::NotSelfDescribing)
+    }
+
+    #[inline]
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.reader.decode()?)
+    }
+
+    #[inline]
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i8(self.reader.read_i8()?)
+    }
+
+    #[inline]
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(self.reader.read_u8()?)
+    }
+
+    deserialize_ints! {
+        deserialize_i16: i16, visit_i16, read_i16, read_sleb128_i32 as i32;
+        deserialize_i32: i32, visit_i32, read_i32, read_sleb128_i32;
+        deserialize_i64: i64, visit_i64, read_i64, read_sleb128_i64;
+        deserialize_i128: i128, visit_i128, read_i128, read_sleb128_i128;
+        deserialize_u16: u16, visit_u16, read_u16, read_uleb128_u32 as u32;
+        deserialize_u32: u32, visit_u32, read_u32, read_uleb128_u32;
+        deserialize_u64: u64, visit_u64, read_u64, read_uleb128_u64;
+        deserialize_u128: u128, visit_u128, read_u128, read_uleb128_u128;
+    }
+
+    #[inline]
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(self.reader.read_f32()?)
+    }
+
+    #[inline]
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(self.reader.read_f64()?)
+    }
+
+    #[inline]
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let c = char::from_u32(self.read_u32()?)
+            .ok_or_else(|| Error::Io(io::Error::new(io::ErrorKind::InvalidData, "invalid char")))?;
+        visitor.visit_char(c)
+    }
+
+    #[inline]
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    #[inline]
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.reader.decode()?)
+    }
+
+    #[inline]
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    #[inline]
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.reader.read_length()?;
+        visitor.visit_byte_buf(read_byte_vec(&mut self.reader, len)?)
+    }
+
+    #[inline]
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.reader.decode()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    #[inline]
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    #[inline]
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    #[inline]
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.reader.read_length()?;
+        visitor.visit_seq(Access { de: self, len })
+    }
+
+    #[inline]
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(Access { de: self, len })
+    }
+
+    #[inline]
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_seq(Access { de: self, len })
+    }
+
+    #[inline]
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.reader.read_length()?;
+        visitor.visit_map(Access { de: self, len })
+    }
+
+    #[inline]
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let len = fields.len();
+        visitor.visit_seq(Access { de: self, len })
+    }
+
+    #[inline]
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(self)
+    }
+
+    #[inline]
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(self.read_tag()?)
+    }
+
+    #[inline]
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::NotSelfDescribing)
+    }
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The elements of a sequence, tuple, struct, or map, which is known to have
+/// `len` more of them.
+struct Access<'a, R: Read, O: Endianness> {
+    de: &'a mut Deserializer<R, O>,
+    len: usize,
+}
+
+impl<'de, R: Read, O: Endianness> de::SeqAccess<'de> for Access<'_, R, O> {
+    type Error = Error;
+
+    #[inline]
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, R: Read, O: Endianness> de::MapAccess<'de> for Access<'_, R, O> {
+    type Error = Error;
+
+    #[inline]
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    #[inline]
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, R: Read, O: Endianness> de::EnumAccess<'de> for &mut Deserializer<R, O> {
+    type Error = Error;
+    type Variant = Self;
+
+    #[inline]
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let value = seed.deserialize(&mut *self)?;
+        Ok((value, self))
+    }
+}
+
+impl<'de, R: Read, O: Endianness> de::VariantAccess<'de> for &mut Deserializer<R, O> {
+    type Error = Error;
+
+    #[inline]
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    #[inline]
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    #[inline]
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(Access { de: self, len })
+    }
+
+    #[inline]
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let len = fields.len();
+        visitor.visit_seq(Access { de: self, len })
+    }
+}
@@ -0,0 +1,908 @@
+use core::mem;
+use core::str;
+
+use crate::io;
+#[cfg(not(feature = "std"))]
+use crate::io::Read;
+
+use crate::decode::Decode;
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::read::NumberReader;
+use crate::varint::VarintScheme;
+
+/// A `SliceReader` reads numbers directly from a byte slice.
+///
+/// It provides the same `read_*` methods as a [`NumberReader`], but since the
+/// whole of the data is in memory, it can also borrow sub-slices of it with
+/// [`read_bytes`] and [`read_str`] instead of copying them, and it reports how
+/// much data remains with [`remaining`].
+///
+/// When a sequence of numbers is known to fit in the remaining data, a single
+/// check with [`ensure_remaining`] allows them to be read with the
+/// `get_*_unchecked` methods, which perform no further checks.
+///
+/// If a method of a `SliceReader` returns an error, its position is left
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, SliceReader};
+///
+/// fn main() -> io::Result<()> {
+///     let data = [0x00, 0x05, b'h', b'e', b'l', b'l', b'o', 0x12, 0x34];
+///     let mut reader = SliceReader::with_order(ByteOrder::BE, &data);
+///
+///     let len = reader.read_u16()?;
+///     let name: &str = reader.read_str(len.into())?;
+///     assert_eq!("hello", name);
+///
+///     reader.ensure_remaining(2)?;
+///     assert_eq!(0x1234, unsafe { reader.get_u16_unchecked() });
+///     assert!(reader.is_empty());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberReader`]: crate::NumberReader
+/// [`read_bytes`]: SliceReader::read_bytes
+/// [`read_str`]: SliceReader::read_str
+/// [`remaining`]: SliceReader::remaining
+/// [`ensure_remaining`]: SliceReader::ensure_remaining
+pub struct SliceReader<'a, O: Endianness = ByteOrder> {
+    data: &'a [u8],
+    pos: usize,
+    order: O,
+    strict: bool,
+    length_prefix: LengthPrefix,
+}
+
+impl<'a> SliceReader<'a> {
+    /// Creates a new `SliceReader` which reads from the start of `data` with
+    /// the target platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let reader = SliceReader::new(&[0xA1, 0xB2]);
+    /// assert_eq!(2, reader.remaining());
+    /// ```
+    ///
+    /// [`with_order`]: SliceReader::with_order
+    #[inline]
+    pub fn new(data: &'a [u8]) -> SliceReader<'a> {
+        SliceReader::with_order(ByteOrder::NE, data)
+    }
+}
+
+macro_rules! read_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the slice.")]
+            ///
+            /// # Errors
+            ///
+            /// If too few bytes remain, an error of the kind
+            /// [`ErrorKind::UnexpectedEof`] is returned.
+            ///
+            /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+            #[inline]
+            pub fn $name(&mut self) -> io::Result<$ty> {
+                self.read_number()
+            }
+        )*
+    };
+}
+
+macro_rules! read_numbers_into {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads a sequence of ", $desc, " from the slice, filling `dst` entirely.")]
+            ///
+            /// # Errors
+            ///
+            /// If too few bytes remain to fill `dst`, an error of the kind
+            /// [`ErrorKind::UnexpectedEof`] is returned and `dst` is left
+            /// unchanged.
+            ///
+            /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+            #[inline]
+            pub fn $name(&mut self, dst: &mut [$ty]) -> io::Result<()> {
+                self.read_into(dst)
+            }
+        )*
+    };
+}
+
+macro_rules! get_numbers_unchecked {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Reads ", $desc, " from the slice without checking that enough bytes remain.")]
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("At least `size_of::<", stringify!($ty), ">()` bytes must remain, which")]
+            /// can be checked ahead of time with [`ensure_remaining`].
+            ///
+            /// [`ensure_remaining`]: SliceReader::ensure_remaining
+            #[inline]
+            pub unsafe fn $name(&mut self) -> $ty {
+                self.get_unchecked()
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_to_reader {
+    ($($(#[$attr:meta])* fn $name:ident(&mut self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) -> io::Result<$ret> {
+                self.with_reader(|reader| reader.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl<'a, O: Endianness> SliceReader<'a, O> {
+    /// Creates a new `SliceReader` which reads from the start of `data` with
+    /// the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, SliceReader};
+    ///
+    /// let be_reader = SliceReader::with_order(ByteOrder::BE, &[]);
+    /// let le_reader = SliceReader::with_order(ByteOrder::LE, &[]);
+    /// let static_reader = SliceReader::with_order(BigEndian, &[]);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, data: &'a [u8]) -> SliceReader<'a, O> {
+        SliceReader {
+            data,
+            pos: 0,
+            order,
+            strict: false,
+            length_prefix: LengthPrefix::default(),
+        }
+    }
+
+    /// Consumes this `SliceReader`, returning the whole of the underlying
+    /// slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12, 0x34]);
+    /// reader.read_u8().unwrap();
+    /// assert_eq!(&[0x12, 0x34], reader.into_inner());
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Gets the whole of the underlying slice, including the bytes which have
+    /// already been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let reader = SliceReader::new(&[0x12, 0x34]);
+    /// assert_eq!(&[0x12, 0x34], reader.get_ref());
+    /// ```
+    #[inline]
+    pub fn get_ref(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the number of bytes which have been read from the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12, 0x34, 0x56]);
+    /// reader.read_u16().unwrap();
+    /// assert_eq!(2, reader.position());
+    /// ```
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the number of bytes which have been read from the slice, so that
+    /// the next read starts at `pos`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `pos` is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12, 0x34]);
+    /// reader.read_u8().unwrap();
+    /// reader.set_position(0);
+    /// assert_eq!(0x12, reader.read_u8().unwrap());
+    /// ```
+    #[inline]
+    pub fn set_position(&mut self, pos: usize) {
+        assert!(
+            pos <= self.data.len(),
+            "position {} is out of bounds for a slice of length {}",
+            pos,
+            self.data.len()
+        );
+        self.pos = pos;
+    }
+
+    /// Returns the number of bytes which remain to be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12, 0x34, 0x56]);
+    /// reader.read_u16().unwrap();
+    /// assert_eq!(1, reader.remaining());
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Returns the bytes which remain to be read, without consuming them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12, 0x34, 0x56]);
+    /// reader.read_u8().unwrap();
+    /// assert_eq!(&[0x34, 0x56], reader.remaining_slice());
+    /// ```
+    #[inline]
+    pub fn remaining_slice(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    /// Returns `true` if no bytes remain to be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(&[0x12]);
+    /// assert!(!reader.is_empty());
+    /// reader.read_u8().unwrap();
+    /// assert!(reader.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns whether this `SliceReader` rejects non-canonical encodings of
+    /// variable-length integers.
+    ///
+    /// See [`NumberReader::set_strict`] for details.
+    ///
+    /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether this `SliceReader` rejects non-canonical encodings of
+    /// variable-length integers.
+    ///
+    /// See [`NumberReader::set_strict`] for details.
+    ///
+    /// [`NumberReader::set_strict`]: crate::NumberReader::set_strict
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// read by this `SliceReader`.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values
+    /// read by this `SliceReader`.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Checks that at least `len` bytes remain to be read.
+    ///
+    /// A successful check allows that many bytes to be read with the
+    /// `get_*_unchecked` methods.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceReader};
+    ///
+    /// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    /// let mut reader = SliceReader::with_order(ByteOrder::BE, &data);
+    ///
+    /// assert!(reader.ensure_remaining(8).is_err());
+    ///
+    /// reader.ensure_remaining(6).unwrap();
+    /// let (a, b) = unsafe { (reader.get_u32_unchecked(), reader.get_u16_unchecked()) };
+    /// assert_eq!((0x12345678, 0x9ABC), (a, b));
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn ensure_remaining(&self, len: usize) -> io::Result<()> {
+        if self.remaining() < len {
+            return Err(eof());
+        }
+        Ok(())
+    }
+
+    /// Skips over `len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned.
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> io::Result<()> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Reads `len` bytes, borrowing them from the underlying slice rather than
+    /// copying them.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let data = [0x01, 0x02, 0x03];
+    /// let mut reader = SliceReader::new(&data);
+    ///
+    /// let bytes: &[u8] = reader.read_bytes(2).unwrap();
+    /// assert_eq!(&[0x01, 0x02], bytes);
+    /// assert!(reader.read_bytes(2).is_err());
+    /// assert_eq!(1, reader.remaining());
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        self.ensure_remaining(len)?;
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads `len` bytes of UTF-8, borrowing them from the underlying slice
+    /// rather than copying them.
+    ///
+    /// # Errors
+    ///
+    /// If fewer than `len` bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned. If the bytes are not valid
+    /// UTF-8, an error of the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceReader;
+    ///
+    /// let mut reader = SliceReader::new(b"hi\xFF");
+    /// assert_eq!("hi", reader.read_str(2).unwrap());
+    /// assert!(reader.read_str(1).is_err());
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    #[inline]
+    pub fn read_str(&mut self, len: usize) -> io::Result<&'a str> {
+        let pos = self.pos;
+        let bytes = self.read_bytes(len)?;
+        str::from_utf8(bytes).map_err(|_| {
+            self.pos = pos;
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream did not contain valid UTF-8",
+            )
+        })
+    }
+
+    /// Reads a length prefix, using the encoding set by
+    /// [`set_length_prefix`], followed by that many bytes, borrowing them
+    /// from the underlying slice.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned. If the length does not fit in
+    /// a `usize`, an error of the kind [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{LengthPrefix, SliceReader};
+    ///
+    /// let mut reader = SliceReader::new(&[0x02, 0xAB, 0xCD]);
+    /// reader.set_length_prefix(LengthPrefix::U8);
+    /// assert_eq!(&[0xAB, 0xCD], reader.read_prefixed_bytes().unwrap());
+    /// ```
+    ///
+    /// [`set_length_prefix`]: SliceReader::set_length_prefix
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    pub fn read_prefixed_bytes(&mut self) -> io::Result<&'a [u8]> {
+        let pos = self.pos;
+        let len = self.read_length()?;
+        let result = self.read_bytes(len);
+        if result.is_err() {
+            self.pos = pos;
+        }
+        result
+    }
+
+    /// Reads a length prefix, using the encoding set by
+    /// [`set_length_prefix`], followed by that many bytes of UTF-8, borrowing
+    /// them from the underlying slice.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned. If the length does not fit in
+    /// a `usize`, or the bytes are not valid UTF-8, an error of the kind
+    /// [`ErrorKind::InvalidData`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceReader};
+    ///
+    /// let data = [0x00, 0x00, 0x00, 0x02, b'h', b'i'];
+    /// let mut reader = SliceReader::with_order(ByteOrder::BE, &data);
+    /// assert_eq!("hi", reader.read_prefixed_str().unwrap());
+    /// ```
+    ///
+    /// [`set_length_prefix`]: SliceReader::set_length_prefix
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    pub fn read_prefixed_str(&mut self) -> io::Result<&'a str> {
+        let pos = self.pos;
+        let len = self.read_length()?;
+        let result = self.read_str(len);
+        if result.is_err() {
+            self.pos = pos;
+        }
+        result
+    }
+
+    /// Reads a number of any primitive type from the slice.
+    ///
+    /// The type of number to read is chosen by the type parameter `T`, which
+    /// is usually inferred.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, SliceReader};
+    ///
+    /// let mut reader = SliceReader::with_order(BigEndian, &[0x12, 0x34, 0x56]);
+    /// assert_eq!(0x1234, reader.read_number::<u16>().unwrap());
+    /// assert!(reader.read_number::<u16>().is_err());
+    /// assert_eq!(0x56, reader.read_number::<u8>().unwrap());
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_number<T: Number>(&mut self) -> io::Result<T> {
+        self.ensure_remaining(mem::size_of::<T>())?;
+        // SAFETY: enough bytes remain, as was just checked.
+        Ok(unsafe { self.get_unchecked() })
+    }
+
+    /// Reads a sequence of numbers of any primitive type from the slice,
+    /// filling `dst` entirely.
+    ///
+    /// # Errors
+    ///
+    /// If too few bytes remain to fill `dst`, an error of the kind
+    /// [`ErrorKind::UnexpectedEof`] is returned and `dst` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceReader};
+    ///
+    /// let mut reader = SliceReader::with_order(ByteOrder::LE, &[0x12, 0x34, 0x56, 0x78]);
+    /// let mut dst = [0u16; 2];
+    /// reader.read_into(&mut dst).unwrap();
+    /// assert_eq!([0x3412, 0x7856], dst);
+    /// ```
+    ///
+    /// [`ErrorKind::UnexpectedEof`]: io::ErrorKind::UnexpectedEof
+    #[inline]
+    pub fn read_into<T: Number>(&mut self, dst: &mut [T]) -> io::Result<()> {
+        let bytes = self.read_bytes(mem::size_of_val(dst))?;
+        pod::as_bytes_mut(dst).copy_from_slice(bytes);
+        if self.order.byte_order() != ByteOrder::NE {
+            for n in dst {
+                *n = n.swap_bytes();
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a number of any primitive type from the slice without checking
+    /// that enough bytes remain.
+    ///
+    /// # Safety
+    ///
+    /// At least `size_of::<T>()` bytes must remain, which can be checked ahead
+    /// of time with [`ensure_remaining`].
+    ///
+    /// [`ensure_remaining`]: SliceReader::ensure_remaining
+    #[inline]
+    pub unsafe fn get_unchecked<T: Number>(&mut self) -> T {
+        let mut buf = T::Bytes::default();
+        let len = buf.as_ref().len();
+        buf.as_mut()
+            .copy_from_slice(self.data.get_unchecked(self.pos..self.pos + len));
+        self.pos += len;
+        T::from_bytes(buf, self.order.byte_order())
+    }
+
+    /// Reads a value of any type that implements [`Decode`] from the slice.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Decode::decode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceReader};
+    ///
+    /// let mut reader = SliceReader::with_order(ByteOrder::BE, &[0x01, 0x12, 0x34]);
+    /// let (flag, n): (bool, u16) = reader.decode().unwrap();
+    /// assert_eq!((true, 0x1234), (flag, n));
+    /// ```
+    ///
+    /// [`Decode`]: crate::Decode
+    /// [`Decode::decode`]: crate::Decode::decode
+    #[inline]
+    pub fn decode<T: Decode>(&mut self) -> io::Result<T> {
+        self.with_reader(|reader| reader.decode())
+    }
+
+    delegate_to_reader! {
+        /// Reads the length that prefixes a variable-sized value from the
+        /// slice, using the encoding set by [`set_length_prefix`].
+        ///
+        /// See [`NumberReader::read_length`] for details.
+        ///
+        /// [`set_length_prefix`]: SliceReader::set_length_prefix
+        /// [`NumberReader::read_length`]: crate::NumberReader::read_length
+        fn read_length(&mut self) -> usize;
+    }
+
+    read_numbers! {
+        read_u8: u8, "an unsigned 8-bit integer";
+        read_i8: i8, "a signed 8-bit integer";
+        read_u16: u16, "an unsigned 16-bit integer";
+        read_i16: i16, "a signed 16-bit integer";
+        read_u32: u32, "an unsigned 32-bit integer";
+        read_i32: i32, "a signed 32-bit integer";
+        read_u64: u64, "an unsigned 64-bit integer";
+        read_i64: i64, "a signed 64-bit integer";
+        read_u128: u128, "an unsigned 128-bit integer";
+        read_i128: i128, "a signed 128-bit integer";
+        read_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        read_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    read_numbers_into! {
+        read_u16_into: u16, "unsigned 16-bit integers";
+        read_i16_into: i16, "signed 16-bit integers";
+        read_u32_into: u32, "unsigned 32-bit integers";
+        read_i32_into: i32, "signed 32-bit integers";
+        read_u64_into: u64, "unsigned 64-bit integers";
+        read_i64_into: i64, "signed 64-bit integers";
+        read_u128_into: u128, "unsigned 128-bit integers";
+        read_i128_into: i128, "signed 128-bit integers";
+        read_f32_into: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+        read_f64_into: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+    }
+
+    get_numbers_unchecked! {
+        get_u8_unchecked: u8, "an unsigned 8-bit integer";
+        get_i8_unchecked: i8, "a signed 8-bit integer";
+        get_u16_unchecked: u16, "an unsigned 16-bit integer";
+        get_i16_unchecked: i16, "a signed 16-bit integer";
+        get_u32_unchecked: u32, "an unsigned 32-bit integer";
+        get_i32_unchecked: i32, "a signed 32-bit integer";
+        get_u64_unchecked: u64, "an unsigned 64-bit integer";
+        get_i64_unchecked: i64, "a signed 64-bit integer";
+        get_u128_unchecked: u128, "an unsigned 128-bit integer";
+        get_i128_unchecked: i128, "a signed 128-bit integer";
+        get_f32_unchecked: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        get_f64_unchecked: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    delegate_to_reader! {
+        /// Reads an unsigned 32-bit integer encoded as unsigned LEB128 from the
+        /// slice.
+        ///
+        /// See [`NumberReader::read_uleb128_u32`] for details.
+        ///
+        /// [`NumberReader::read_uleb128_u32`]: crate::NumberReader::read_uleb128_u32
+        fn read_uleb128_u32(&mut self) -> u32;
+
+        /// Reads an unsigned 64-bit integer encoded as unsigned LEB128 from the
+        /// slice.
+        ///
+        /// See [`NumberReader::read_uleb128_u64`] for details.
+        ///
+        /// [`NumberReader::read_uleb128_u64`]: crate::NumberReader::read_uleb128_u64
+        fn read_uleb128_u64(&mut self) -> u64;
+
+        /// Reads an unsigned 128-bit integer encoded as unsigned LEB128 from
+        /// the slice.
+        ///
+        /// See [`NumberReader::read_uleb128_u128`] for details.
+        ///
+        /// [`NumberReader::read_uleb128_u128`]: crate::NumberReader::read_uleb128_u128
+        fn read_uleb128_u128(&mut self) -> u128;
+
+        /// Reads a signed 32-bit integer encoded as signed LEB128 from the
+        /// slice.
+        ///
+        /// See [`NumberReader::read_sleb128_i32`] for details.
+        ///
+        /// [`NumberReader::read_sleb128_i32`]: crate::NumberReader::read_sleb128_i32
+        fn read_sleb128_i32(&mut self) -> i32;
+
+        /// Reads a signed 64-bit integer encoded as signed LEB128 from the
+        /// slice.
+        ///
+        /// See [`NumberReader::read_sleb128_i64`] for details.
+        ///
+        /// [`NumberReader::read_sleb128_i64`]: crate::NumberReader::read_sleb128_i64
+        fn read_sleb128_i64(&mut self) -> i64;
+
+        /// Reads a signed 128-bit integer encoded as signed LEB128 from the
+        /// slice.
+        ///
+        /// See [`NumberReader::read_sleb128_i128`] for details.
+        ///
+        /// [`NumberReader::read_sleb128_i128`]: crate::NumberReader::read_sleb128_i128
+        fn read_sleb128_i128(&mut self) -> i128;
+
+        /// Reads a signed 32-bit integer encoded as zigzag unsigned LEB128
+        /// from the slice.
+        ///
+        /// See [`NumberReader::read_zigzag_i32`] for details.
+        ///
+        /// [`NumberReader::read_zigzag_i32`]: crate::NumberReader::read_zigzag_i32
+        fn read_zigzag_i32(&mut self) -> i32;
+
+        /// Reads a signed 64-bit integer encoded as zigzag unsigned LEB128
+        /// from the slice.
+        ///
+        /// See [`NumberReader::read_zigzag_i64`] for details.
+        ///
+        /// [`NumberReader::read_zigzag_i64`]: crate::NumberReader::read_zigzag_i64
+        fn read_zigzag_i64(&mut self) -> i64;
+
+        /// Reads a half-precision floating point number from the slice,
+        /// widening it to an `f32`.
+        ///
+        /// See [`NumberReader::read_f16_as_f32`] for details.
+        ///
+        /// [`NumberReader::read_f16_as_f32`]: crate::NumberReader::read_f16_as_f32
+        fn read_f16_as_f32(&mut self) -> f32;
+
+        /// Reads a sequence of half-precision floating point numbers from the
+        /// slice, widening each of them to an `f32` and filling `dst` entirely.
+        ///
+        /// See [`NumberReader::read_f16_as_f32_into`] for details.
+        ///
+        /// [`NumberReader::read_f16_as_f32_into`]: crate::NumberReader::read_f16_as_f32_into
+        fn read_f16_as_f32_into(&mut self, dst: &mut [f32]) -> ();
+
+        /// Reads a brain floating point number (bfloat16) from the slice,
+        /// widening it to an `f32`.
+        ///
+        /// See [`NumberReader::read_bf16_as_f32`] for details.
+        ///
+        /// [`NumberReader::read_bf16_as_f32`]: crate::NumberReader::read_bf16_as_f32
+        fn read_bf16_as_f32(&mut self) -> f32;
+
+        /// Reads a sequence of brain floating point numbers (bfloat16) from the
+        /// slice, widening each of them to an `f32` and filling `dst` entirely.
+        ///
+        /// See [`NumberReader::read_bf16_as_f32_into`] for details.
+        ///
+        /// [`NumberReader::read_bf16_as_f32_into`]: crate::NumberReader::read_bf16_as_f32_into
+        fn read_bf16_as_f32_into(&mut self, dst: &mut [f32]) -> ();
+
+        /// Reads an unsigned integer that is `nbytes` bytes wide from the
+        /// slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// See [`NumberReader::read_uint`] for details.
+        ///
+        /// [`NumberReader::read_uint`]: crate::NumberReader::read_uint
+        fn read_uint(&mut self, nbytes: usize) -> u64;
+
+        /// Reads a signed integer that is `nbytes` bytes wide from the slice,
+        /// sign-extending it to 64 bits.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// See [`NumberReader::read_int`] for details.
+        ///
+        /// [`NumberReader::read_int`]: crate::NumberReader::read_int
+        fn read_int(&mut self, nbytes: usize) -> i64;
+
+        /// Reads an unsigned integer that is `nbytes` bytes wide from the
+        /// slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// See [`NumberReader::read_uint128`] for details.
+        ///
+        /// [`NumberReader::read_uint128`]: crate::NumberReader::read_uint128
+        fn read_uint128(&mut self, nbytes: usize) -> u128;
+
+        /// Reads a signed integer that is `nbytes` bytes wide from the slice,
+        /// sign-extending it to 128 bits.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// See [`NumberReader::read_int128`] for details.
+        ///
+        /// [`NumberReader::read_int128`]: crate::NumberReader::read_int128
+        fn read_int128(&mut self, nbytes: usize) -> i128;
+
+        /// Reads an unsigned 24-bit integer from the slice.
+        ///
+        /// See [`NumberReader::read_u24`] for details.
+        ///
+        /// [`NumberReader::read_u24`]: crate::NumberReader::read_u24
+        fn read_u24(&mut self) -> u32;
+
+        /// Reads a signed 24-bit integer from the slice.
+        ///
+        /// See [`NumberReader::read_i24`] for details.
+        ///
+        /// [`NumberReader::read_i24`]: crate::NumberReader::read_i24
+        fn read_i24(&mut self) -> i32;
+
+        /// Reads an unsigned 48-bit integer from the slice.
+        ///
+        /// See [`NumberReader::read_u48`] for details.
+        ///
+        /// [`NumberReader::read_u48`]: crate::NumberReader::read_u48
+        fn read_u48(&mut self) -> u64;
+
+        /// Reads a signed 48-bit integer from the slice.
+        ///
+        /// See [`NumberReader::read_i48`] for details.
+        ///
+        /// [`NumberReader::read_i48`]: crate::NumberReader::read_i48
+        fn read_i48(&mut self) -> i64;
+    }
+
+    /// Reads an unsigned integer encoded with a variable-length `scheme` from
+    /// the slice.
+    ///
+    /// See [`NumberReader::read_varint`] for details.
+    ///
+    /// [`NumberReader::read_varint`]: crate::NumberReader::read_varint
+    #[inline]
+    pub fn read_varint<S: VarintScheme>(&mut self, scheme: S) -> io::Result<u64> {
+        self.with_reader(|reader| reader.read_varint(scheme))
+    }
+
+    /// Runs `f` with a [`NumberReader`] over the remaining bytes, with the
+    /// same settings as this `SliceReader`, and advances past the bytes it
+    /// read if it succeeds.
+    #[inline]
+    fn with_reader<T, F>(&mut self, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut NumberReader<&mut &'a [u8]>) -> io::Result<T>,
+    {
+        let mut rest = self.remaining_slice();
+        let mut reader = NumberReader::with_order(self.order.byte_order(), &mut rest);
+        reader.set_strict(self.strict);
+        reader.set_length_prefix(self.length_prefix);
+        let value = f(&mut reader)?;
+        self.pos = self.data.len() - rest.len();
+        Ok(value)
+    }
+}
+
+#[cold]
+fn eof() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "not enough bytes remain in the slice",
+    )
+}
+
+#[cfg(feature = "std")]
+impl<O: Endianness> std::io::Read for SliceReader<'_, O> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.remaining());
+        buf[..len].copy_from_slice(&self.data[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<O: Endianness> Read for SliceReader<'_, O> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.remaining());
+        buf[..len].copy_from_slice(&self.data[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
@@ -0,0 +1,803 @@
+use core::mem;
+
+#[cfg(not(feature = "std"))]
+use crate::io::Write;
+use crate::io::{Error, ErrorKind, Result};
+
+use crate::encode::{self, Encode};
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::varint::VarintScheme;
+use crate::write::NumberWriter;
+
+/// The size of the buffer that variable-length values are encoded into before
+/// they are copied to the slice, which is enough for any of them.
+const STAGE_LEN: usize = 32;
+
+/// A `SliceWriter` writes numbers directly into a fixed-size byte slice.
+///
+/// It provides the same `write_*` methods as a [`NumberWriter`], and keeps
+/// track of how many bytes have been written with [`position`]. When a value
+/// does not fit in the space that remains, an error of the kind
+/// [`ErrorKind::WriteZero`] is returned, and none of the value is written.
+///
+/// Numbers can also be written at an explicit offset with the `write_*_at`
+/// methods, which is useful for filling in a length or checksum field once
+/// the rest of a packet has been written.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use byte_order::{ByteOrder, SliceWriter};
+///
+/// fn main() -> io::Result<()> {
+///     let mut buf = [0u8; 8];
+///     let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+///
+///     writer.write_u16(0)?;
+///     writer.write_bytes(b"ping")?;
+///     let len = writer.position() as u16;
+///     writer.write_u16_at(0, len)?;
+///
+///     assert!(writer.write_u32(0x12345678).is_err());
+///     assert_eq!(6, writer.position());
+///     assert_eq!(&[0x00, 0x06, b'p', b'i', b'n', b'g'], writer.written());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`position`]: SliceWriter::position
+/// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+pub struct SliceWriter<'a, O: Endianness = ByteOrder> {
+    buf: &'a mut [u8],
+    pos: usize,
+    order: O,
+    length_prefix: LengthPrefix,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Creates a new `SliceWriter` which writes to the start of `buf` with the
+    /// target platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceWriter;
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let writer = SliceWriter::new(&mut buf);
+    /// assert_eq!(4, writer.remaining());
+    /// ```
+    ///
+    /// [`with_order`]: SliceWriter::with_order
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> SliceWriter<'a> {
+        SliceWriter::with_order(ByteOrder::NE, buf)
+    }
+}
+
+macro_rules! write_numbers {
+    ($($name:ident, $name_at:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the slice.")]
+            ///
+            /// # Errors
+            ///
+            /// If too little space remains, an error of the kind
+            /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+            ///
+            /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+            #[inline]
+            pub fn $name(&mut self, n: $ty) -> Result<()> {
+                self.write_number(n)
+            }
+
+            #[doc = concat!("Writes ", $desc, " at `offset` bytes from the start of the slice.")]
+            ///
+            /// The position of this `SliceWriter` is not changed.
+            ///
+            /// # Errors
+            ///
+            /// If the number does not fit in the slice at `offset`, an error
+            /// of the kind [`ErrorKind::WriteZero`] is returned and nothing is
+            /// written.
+            ///
+            /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+            #[inline]
+            pub fn $name_at(&mut self, offset: usize, n: $ty) -> Result<()> {
+                self.write_at(offset, n)
+            }
+        )*
+    };
+}
+
+macro_rules! write_numbers_slice {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a sequence of ", $desc, " to the slice.")]
+            ///
+            /// # Errors
+            ///
+            /// If too little space remains for the whole of `src`, an error of
+            /// the kind [`ErrorKind::WriteZero`] is returned and nothing is
+            /// written.
+            ///
+            /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+            #[inline]
+            pub fn $name(&mut self, src: &[$ty]) -> Result<()> {
+                self.write_slice(src)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_to_writer {
+    ($($(#[$attr:meta])* fn $name:ident(&mut self $(, $arg:ident: $ty:ty)*);)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) -> Result<()> {
+                self.staged(|writer| writer.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl<'a, O: Endianness> SliceWriter<'a, O> {
+    /// Creates a new `SliceWriter` which writes to the start of `buf` with the
+    /// given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let be_writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let static_writer = SliceWriter::with_order(BigEndian, &mut buf);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O, buf: &'a mut [u8]) -> SliceWriter<'a, O> {
+        SliceWriter {
+            buf,
+            pos: 0,
+            order,
+            length_prefix: LengthPrefix::default(),
+        }
+    }
+
+    /// Consumes this `SliceWriter`, returning the whole of the underlying
+    /// slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceWriter;
+    ///
+    /// let mut buf = [0u8; 2];
+    /// let mut writer = SliceWriter::new(&mut buf);
+    /// writer.write_u8(0x12).unwrap();
+    /// assert_eq!(&[0x12, 0x00], writer.into_inner());
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> &'a mut [u8] {
+        self.buf
+    }
+
+    /// Gets a reference to the whole of the underlying slice.
+    #[inline]
+    pub fn get_ref(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Gets a mutable reference to the whole of the underlying slice.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut [u8] {
+        self.buf
+    }
+
+    /// Returns the bytes which have been written to the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut writer = SliceWriter::with_order(ByteOrder::LE, &mut buf);
+    /// writer.write_u16(0x1234).unwrap();
+    /// assert_eq!(&[0x34, 0x12], writer.written());
+    /// ```
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Returns the number of bytes which have been written to the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceWriter;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut writer = SliceWriter::new(&mut buf);
+    /// writer.write_u32(0).unwrap();
+    /// assert_eq!(4, writer.position());
+    /// ```
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Sets the number of bytes which have been written to the slice, so that
+    /// the next write starts at `pos`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `pos` is greater than the length of the slice.
+    #[inline]
+    pub fn set_position(&mut self, pos: usize) {
+        assert!(
+            pos <= self.buf.len(),
+            "position {} is out of bounds for a slice of length {}",
+            pos,
+            self.buf.len()
+        );
+        self.pos = pos;
+    }
+
+    /// Returns the number of bytes which can still be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceWriter;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let mut writer = SliceWriter::new(&mut buf);
+    /// writer.write_u16(0).unwrap();
+    /// assert_eq!(6, writer.remaining());
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Returns `true` if no more bytes can be written.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// written by this `SliceWriter`.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values
+    /// written by this `SliceWriter`.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Writes all of `bytes` to the slice.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::SliceWriter;
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let mut writer = SliceWriter::new(&mut buf);
+    /// writer.write_bytes(b"abc").unwrap();
+    /// assert!(writer.write_bytes(b"de").is_err());
+    /// assert_eq!(b"abc", writer.written());
+    /// ```
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.write_bytes_at(self.pos, bytes)?;
+        self.pos += bytes.len();
+        Ok(())
+    }
+
+    /// Writes all of `bytes` at `offset` bytes from the start of the slice.
+    ///
+    /// The position of this `SliceWriter` is not changed.
+    ///
+    /// # Errors
+    ///
+    /// If `bytes` does not fit in the slice at `offset`, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_bytes_at(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        self.slot(offset, bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes a number of any primitive type to the slice.
+    ///
+    /// Each of the named `write_*` methods, such as [`write_u32`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 3];
+    /// let mut writer = SliceWriter::with_order(BigEndian, &mut buf);
+    /// writer.write_number(0x1234u16).unwrap();
+    /// assert!(writer.write_number(0x5678u16).is_err());
+    /// writer.write_number(0x56u8).unwrap();
+    /// assert_eq!(&[0x12, 0x34, 0x56], writer.written());
+    /// ```
+    ///
+    /// [`write_u32`]: SliceWriter::write_u32
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_number<T: Number>(&mut self, n: T) -> Result<()> {
+        self.write_at(self.pos, n)?;
+        self.pos += mem::size_of::<T>();
+        Ok(())
+    }
+
+    /// Writes a number of any primitive type at `offset` bytes from the start
+    /// of the slice.
+    ///
+    /// The position of this `SliceWriter` is not changed. Each of the named
+    /// `write_*_at` methods, such as [`write_u32_at`], is equivalent to
+    /// calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If the number does not fit in the slice at `offset`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    /// writer.write_at(2, 0x1234u16).unwrap();
+    /// assert!(writer.write_at(3, 0x1234u16).is_err());
+    /// assert_eq!(0, writer.position());
+    /// assert_eq!(&[0x00, 0x00, 0x12, 0x34], writer.get_ref());
+    /// ```
+    ///
+    /// [`write_u32_at`]: SliceWriter::write_u32_at
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_at<T: Number>(&mut self, offset: usize, n: T) -> Result<()> {
+        let bytes = n.to_bytes(self.order.byte_order());
+        self.write_bytes_at(offset, bytes.as_ref())
+    }
+
+    /// Writes a sequence of numbers of any primitive type to the slice.
+    ///
+    /// Each of the named `write_*_slice` methods, such as
+    /// [`write_u32_slice`], is equivalent to calling this method with the
+    /// corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let mut writer = SliceWriter::with_order(ByteOrder::LE, &mut buf);
+    /// writer.write_slice(&[0x1234i16, 0x5678]).unwrap();
+    /// assert_eq!(&[0x34, 0x12, 0x78, 0x56], writer.written());
+    /// ```
+    ///
+    /// [`write_u32_slice`]: SliceWriter::write_u32_slice
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_slice<T: Number>(&mut self, src: &[T]) -> Result<()> {
+        let order = self.order.byte_order();
+        let len = mem::size_of_val(src);
+        let dst = self.slot(self.pos, len)?;
+        match order {
+            order if order == ByteOrder::NE => dst.copy_from_slice(pod::as_bytes(src)),
+            ByteOrder::BE => fill(dst, src, |n: T| n.to_bytes(ByteOrder::BE)),
+            ByteOrder::LE => fill(dst, src, |n: T| n.to_bytes(ByteOrder::LE)),
+        }
+        self.pos += len;
+        Ok(())
+    }
+
+    /// Writes a value of any type that implements [`Encode`] to the slice.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Encode::encode`]. If too
+    /// little space remains for the whole value, an error of the kind
+    /// [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// The value is encoded twice, first to measure it and then into the
+    /// slice, so [`Encode::encode`] must write the same bytes each time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, SliceWriter};
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    /// writer.encode(&(true, 0x1234u16)).unwrap();
+    /// assert_eq!(&[0x01, 0x12, 0x34], writer.written());
+    ///
+    /// assert!(writer.encode(&0x5678u16).is_err());
+    /// assert_eq!(3, writer.position());
+    /// ```
+    ///
+    /// [`Encode`]: crate::Encode
+    /// [`Encode::encode`]: crate::Encode::encode
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let len = encode::encoded_len(value, self.order.byte_order(), self.length_prefix)?;
+        self.slot(self.pos, len)?;
+        self.unstaged(|writer| writer.encode(value))
+    }
+
+    delegate_to_writer! {
+        /// Writes the length that prefixes a variable-sized value to the
+        /// slice, using the encoding set by [`set_length_prefix`].
+        ///
+        /// See [`NumberWriter::write_length`] for details.
+        ///
+        /// [`set_length_prefix`]: SliceWriter::set_length_prefix
+        /// [`NumberWriter::write_length`]: crate::NumberWriter::write_length
+        fn write_length(&mut self, len: usize);
+    }
+
+    write_numbers! {
+        write_u8, write_u8_at: u8, "an unsigned 8-bit integer";
+        write_i8, write_i8_at: i8, "a signed 8-bit integer";
+        write_u16, write_u16_at: u16, "an unsigned 16-bit integer";
+        write_i16, write_i16_at: i16, "a signed 16-bit integer";
+        write_u32, write_u32_at: u32, "an unsigned 32-bit integer";
+        write_i32, write_i32_at: i32, "a signed 32-bit integer";
+        write_u64, write_u64_at: u64, "an unsigned 64-bit integer";
+        write_i64, write_i64_at: i64, "a signed 64-bit integer";
+        write_u128, write_u128_at: u128, "an unsigned 128-bit integer";
+        write_i128, write_i128_at: i128, "a signed 128-bit integer";
+        write_f32, write_f32_at: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        write_f64, write_f64_at: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    write_numbers_slice! {
+        write_u16_slice: u16, "unsigned 16-bit integers";
+        write_i16_slice: i16, "signed 16-bit integers";
+        write_u32_slice: u32, "unsigned 32-bit integers";
+        write_i32_slice: i32, "signed 32-bit integers";
+        write_u64_slice: u64, "unsigned 64-bit integers";
+        write_i64_slice: i64, "signed 64-bit integers";
+        write_u128_slice: u128, "unsigned 128-bit integers";
+        write_i128_slice: i128, "signed 128-bit integers";
+        write_f32_slice: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+        write_f64_slice: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+    }
+
+    delegate_to_writer! {
+        /// Writes an unsigned 32-bit integer to the slice, encoded as unsigned
+        /// LEB128.
+        ///
+        /// See [`NumberWriter::write_uleb128_u32`] for details.
+        ///
+        /// [`NumberWriter::write_uleb128_u32`]: crate::NumberWriter::write_uleb128_u32
+        fn write_uleb128_u32(&mut self, n: u32);
+
+        /// Writes an unsigned 64-bit integer to the slice, encoded as unsigned
+        /// LEB128.
+        ///
+        /// See [`NumberWriter::write_uleb128_u64`] for details.
+        ///
+        /// [`NumberWriter::write_uleb128_u64`]: crate::NumberWriter::write_uleb128_u64
+        fn write_uleb128_u64(&mut self, n: u64);
+
+        /// Writes an unsigned 128-bit integer to the slice, encoded as
+        /// unsigned LEB128.
+        ///
+        /// See [`NumberWriter::write_uleb128_u128`] for details.
+        ///
+        /// [`NumberWriter::write_uleb128_u128`]: crate::NumberWriter::write_uleb128_u128
+        fn write_uleb128_u128(&mut self, n: u128);
+
+        /// Writes a signed 32-bit integer to the slice, encoded as signed
+        /// LEB128.
+        ///
+        /// See [`NumberWriter::write_sleb128_i32`] for details.
+        ///
+        /// [`NumberWriter::write_sleb128_i32`]: crate::NumberWriter::write_sleb128_i32
+        fn write_sleb128_i32(&mut self, n: i32);
+
+        /// Writes a signed 64-bit integer to the slice, encoded as signed
+        /// LEB128.
+        ///
+        /// See [`NumberWriter::write_sleb128_i64`] for details.
+        ///
+        /// [`NumberWriter::write_sleb128_i64`]: crate::NumberWriter::write_sleb128_i64
+        fn write_sleb128_i64(&mut self, n: i64);
+
+        /// Writes a signed 128-bit integer to the slice, encoded as signed
+        /// LEB128.
+        ///
+        /// See [`NumberWriter::write_sleb128_i128`] for details.
+        ///
+        /// [`NumberWriter::write_sleb128_i128`]: crate::NumberWriter::write_sleb128_i128
+        fn write_sleb128_i128(&mut self, n: i128);
+
+        /// Writes a signed 32-bit integer to the slice, encoded as zigzag
+        /// unsigned LEB128.
+        ///
+        /// See [`NumberWriter::write_zigzag_i32`] for details.
+        ///
+        /// [`NumberWriter::write_zigzag_i32`]: crate::NumberWriter::write_zigzag_i32
+        fn write_zigzag_i32(&mut self, n: i32);
+
+        /// Writes a signed 64-bit integer to the slice, encoded as zigzag
+        /// unsigned LEB128.
+        ///
+        /// See [`NumberWriter::write_zigzag_i64`] for details.
+        ///
+        /// [`NumberWriter::write_zigzag_i64`]: crate::NumberWriter::write_zigzag_i64
+        fn write_zigzag_i64(&mut self, n: i64);
+
+        /// Writes an `f32` to the slice, narrowed to a half-precision floating
+        /// point number.
+        ///
+        /// See [`NumberWriter::write_f32_as_f16`] for details.
+        ///
+        /// [`NumberWriter::write_f32_as_f16`]: crate::NumberWriter::write_f32_as_f16
+        fn write_f32_as_f16(&mut self, n: f32);
+
+        /// Writes an `f32` to the slice, narrowed to a brain floating point
+        /// number (bfloat16).
+        ///
+        /// See [`NumberWriter::write_f32_as_bf16`] for details.
+        ///
+        /// [`NumberWriter::write_f32_as_bf16`]: crate::NumberWriter::write_f32_as_bf16
+        fn write_f32_as_bf16(&mut self, n: f32);
+
+        /// Writes the low `nbytes` bytes of an unsigned integer to the slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// See [`NumberWriter::write_uint`] for details.
+        ///
+        /// [`NumberWriter::write_uint`]: crate::NumberWriter::write_uint
+        fn write_uint(&mut self, n: u64, nbytes: usize);
+
+        /// Writes the low `nbytes` bytes of a signed integer to the slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// See [`NumberWriter::write_int`] for details.
+        ///
+        /// [`NumberWriter::write_int`]: crate::NumberWriter::write_int
+        fn write_int(&mut self, n: i64, nbytes: usize);
+
+        /// Writes the low `nbytes` bytes of an unsigned 128-bit integer to the
+        /// slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// See [`NumberWriter::write_uint128`] for details.
+        ///
+        /// [`NumberWriter::write_uint128`]: crate::NumberWriter::write_uint128
+        fn write_uint128(&mut self, n: u128, nbytes: usize);
+
+        /// Writes the low `nbytes` bytes of a signed 128-bit integer to the
+        /// slice.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// See [`NumberWriter::write_int128`] for details.
+        ///
+        /// [`NumberWriter::write_int128`]: crate::NumberWriter::write_int128
+        fn write_int128(&mut self, n: i128, nbytes: usize);
+
+        /// Writes an unsigned 24-bit integer to the slice.
+        ///
+        /// See [`NumberWriter::write_u24`] for details.
+        ///
+        /// [`NumberWriter::write_u24`]: crate::NumberWriter::write_u24
+        fn write_u24(&mut self, n: u32);
+
+        /// Writes a signed 24-bit integer to the slice.
+        ///
+        /// See [`NumberWriter::write_i24`] for details.
+        ///
+        /// [`NumberWriter::write_i24`]: crate::NumberWriter::write_i24
+        fn write_i24(&mut self, n: i32);
+
+        /// Writes an unsigned 48-bit integer to the slice.
+        ///
+        /// See [`NumberWriter::write_u48`] for details.
+        ///
+        /// [`NumberWriter::write_u48`]: crate::NumberWriter::write_u48
+        fn write_u48(&mut self, n: u64);
+
+        /// Writes a signed 48-bit integer to the slice.
+        ///
+        /// See [`NumberWriter::write_i48`] for details.
+        ///
+        /// [`NumberWriter::write_i48`]: crate::NumberWriter::write_i48
+        fn write_i48(&mut self, n: i64);
+    }
+
+    /// Writes `n` to the slice, encoded with a variable-length `scheme`.
+    ///
+    /// See [`NumberWriter::write_varint`] for details.
+    ///
+    /// [`NumberWriter::write_varint`]: crate::NumberWriter::write_varint
+    #[inline]
+    pub fn write_varint<S: VarintScheme>(&mut self, scheme: S, n: u64) -> Result<()> {
+        self.staged(|writer| writer.write_varint(scheme, n))
+    }
+
+    /// Writes a sequence of `f32` values to the slice, narrowing each of them
+    /// to a half-precision floating point number.
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_f32_as_f16_slice(&mut self, src: &[f32]) -> Result<()> {
+        self.slot(self.pos, 2 * src.len())?;
+        self.unstaged(|writer| writer.write_f32_as_f16_slice(src))
+    }
+
+    /// Writes a sequence of `f32` values to the slice, narrowing each of them
+    /// to a brain floating point number (bfloat16).
+    ///
+    /// # Errors
+    ///
+    /// If too little space remains for the whole of `src`, an error of the
+    /// kind [`ErrorKind::WriteZero`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::WriteZero`]: crate::io::ErrorKind::WriteZero
+    #[inline]
+    pub fn write_f32_as_bf16_slice(&mut self, src: &[f32]) -> Result<()> {
+        self.slot(self.pos, 2 * src.len())?;
+        self.unstaged(|writer| writer.write_f32_as_bf16_slice(src))
+    }
+
+    /// Returns the `len` bytes of the slice at `offset`, or a "buffer full"
+    /// error if they are out of bounds.
+    #[inline]
+    fn slot(&mut self, offset: usize, len: usize) -> Result<&mut [u8]> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.buf.len() => Ok(&mut self.buf[offset..end]),
+            _ => Err(full()),
+        }
+    }
+
+    /// Runs `f` with a [`NumberWriter`] over a small buffer, and then copies
+    /// what it wrote to the slice, so that nothing is written if it does not
+    /// fit.
+    #[inline]
+    fn staged<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut NumberWriter<&mut &mut [u8]>) -> Result<()>,
+    {
+        let mut stage = [0; STAGE_LEN];
+        let mut rest = &mut stage[..];
+        let mut writer = NumberWriter::with_order(self.order.byte_order(), &mut rest);
+        writer.set_length_prefix(self.length_prefix);
+        f(&mut writer)?;
+        let len = STAGE_LEN - rest.len();
+        self.write_bytes(&stage[..len])
+    }
+
+    /// Runs `f` with a [`NumberWriter`] over the remaining bytes of the slice,
+    /// and advances past the bytes it wrote if it succeeds.
+    #[inline]
+    fn unstaged<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut NumberWriter<&mut &mut [u8]>) -> Result<()>,
+    {
+        let order = self.order.byte_order();
+        let mut rest = &mut self.buf[self.pos..];
+        let len = rest.len();
+        let mut writer = NumberWriter::with_order(order, &mut rest);
+        writer.set_length_prefix(self.length_prefix);
+        f(&mut writer)?;
+        self.pos += len - rest.len();
+        Ok(())
+    }
+}
+
+/// Fills `dst` with the bytes of each number in `src`, as converted by
+/// `to_bytes`.
+#[inline]
+fn fill<T: Number>(dst: &mut [u8], src: &[T], to_bytes: impl Fn(T) -> T::Bytes) {
+    for (chunk, &n) in dst.chunks_exact_mut(mem::size_of::<T>()).zip(src) {
+        chunk.copy_from_slice(to_bytes(n).as_ref());
+    }
+}
+
+#[cold]
+fn full() -> Error {
+    Error::new(ErrorKind::WriteZero, "buffer full")
+}
+
+#[cfg(feature = "std")]
+impl<O: Endianness> std::io::Write for SliceWriter<'_, O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = buf.len().min(self.remaining());
+        self.write_bytes(&buf[..len])?;
+        Ok(len)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.write_bytes(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<O: Endianness> Write for SliceWriter<'_, O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = buf.len().min(self.remaining());
+        self.write_bytes(&buf[..len])?;
+        Ok(len)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.write_bytes(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
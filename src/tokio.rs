@@ -0,0 +1,145 @@
+//! Asynchronous readers and writers built on the [`AsyncRead`] and
+//! [`AsyncWrite`] traits of `tokio`.
+//!
+//! [`AsyncNumberReader`] and [`AsyncNumberWriter`] provide every `read_*` and
+//! `write_*` method of [`NumberReader`] and [`NumberWriter`] as an `async fn`,
+//! with the same byte order and length prefix settings.
+//!
+//! This module is only available with the `tokio` feature enabled.
+//!
+//! [`NumberReader`]: crate::NumberReader
+//! [`NumberWriter`]: crate::NumberWriter
+
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use ::tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::async_io::{async_reader, async_writer};
+use crate::io;
+use crate::order::Endianness;
+
+async_reader! {
+    /// An `AsyncNumberReader` wraps a tokio [`AsyncRead`] and provides
+    /// methods for reading numbers.
+    ///
+    /// Like a [`NumberReader`], an `AsyncNumberReader` takes byte order as a
+    /// parameter upon initialization. Since its methods take `&mut self`, the
+    /// underlying reader must be [`Unpin`]; other readers can be wrapped in a
+    /// `Box::pin` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::tokio::AsyncNumberReader;
+    /// use byte_order::ByteOrder;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() -> io::Result<()> {
+    ///     let src: &[u8] = &[0x12, 0x34, 0xE5, 0x8E, 0x26];
+    ///     let mut reader = AsyncNumberReader::with_order(ByteOrder::BE, src);
+    ///
+    ///     assert_eq!(0x1234u16, reader.read_u16().await?);
+    ///     assert_eq!(624485u32, reader.read_uleb128_u32().await?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`NumberReader`]: crate::NumberReader
+    AsyncRead
+}
+
+async_writer! {
+    /// An `AsyncNumberWriter` wraps a tokio [`AsyncWrite`] and provides
+    /// methods for writing numbers.
+    ///
+    /// Like a [`NumberWriter`], an `AsyncNumberWriter` takes byte order as a
+    /// parameter upon initialization. Since its methods take `&mut self`, the
+    /// underlying writer must be [`Unpin`]; other writers can be wrapped in a
+    /// `Box::pin` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::tokio::AsyncNumberWriter;
+    /// use byte_order::ByteOrder;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() -> io::Result<()> {
+    ///     let mut writer = AsyncNumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///
+    ///     writer.write_u16(0x1234).await?;
+    ///     writer.write_uleb128_u32(624485).await?;
+    ///     assert_eq!(writer.into_inner(), vec![0x34, 0x12, 0xE5, 0x8E, 0x26]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`NumberWriter`]: crate::NumberWriter
+    AsyncWrite
+}
+
+#[inline]
+fn poll_read_some<R: AsyncRead + Unpin>(
+    src: &mut R,
+    cx: &mut Context<'_>,
+    buf: &mut [u8],
+) -> Poll<io::Result<usize>> {
+    let mut buf = ReadBuf::new(buf);
+    ready!(Pin::new(src).poll_read(cx, &mut buf))?;
+    Poll::Ready(Ok(buf.filled().len()))
+}
+
+#[inline]
+fn poll_write_some<W: AsyncWrite + Unpin>(
+    dst: &mut W,
+    cx: &mut Context<'_>,
+    buf: &[u8],
+) -> Poll<io::Result<usize>> {
+    Pin::new(dst).poll_write(cx, buf)
+}
+
+impl<R: AsyncRead + Unpin, O: Endianness + Unpin> AsyncRead for AsyncNumberReader<R, O> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.pending.is_empty() {
+            let n = this.pending.len().min(buf.remaining());
+            buf.put_slice(&this.pending.filled()[..n]);
+            this.pending.consume(n);
+            return Poll::Ready(Ok(()));
+        }
+        Pin::new(&mut this.inner).poll_read(cx, buf)
+    }
+}
+
+impl<W: AsyncWrite + Unpin, O: Endianness + Unpin> AsyncWrite for AsyncNumberWriter<W, O> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
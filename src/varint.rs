@@ -0,0 +1,546 @@
+//! Variable-length integer encodings other than LEB128.
+//!
+//! Every encoding is described by a [`VarintScheme`], which is passed to
+//! [`NumberReader::read_varint`] and [`NumberWriter::write_varint`] along with
+//! the value. The schemes provided here are:
+//!
+//! - [`Quic`], the variable-length integers of QUIC, whose first byte carries
+//!   the length in its two most significant bits.
+//! - [`Sqlite`], the big-endian varints of SQLite's file format, which take at
+//!   most nine bytes.
+//! - [`CompactSize`], the little-endian lengths of Bitcoin, with a marker byte
+//!   of `0xFD`, `0xFE`, or `0xFF` before wider values.
+//! - [`Vlq`], the big-endian base-128 quantities of MIDI files and of ASN.1
+//!   tags and object identifiers.
+//! - [`Uleb128`], unsigned LEB128, for code that is generic over the scheme.
+//!
+//! Every scheme writes the shortest encoding of a value. When a
+//! [`NumberReader`] is [strict], longer encodings of a value are rejected, so
+//! that each value has exactly one accepted encoding. Encodings longer than
+//! the scheme allows, or of values which do not fit in a `u64`, are always
+//! rejected.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::io::{self, Cursor};
+//! use byte_order::{NumberReader, NumberWriter};
+//! use byte_order::varint::{CompactSize, Quic, Vlq};
+//!
+//! fn main() -> io::Result<()> {
+//!     let mut writer = NumberWriter::new(vec![]);
+//!     writer.write_varint(Quic, 15293)?;
+//!     writer.write_varint(CompactSize, 515)?;
+//!     writer.write_varint(Vlq::MIDI, 0x3FFF)?;
+//!     let bytes = writer.into_inner();
+//!     assert_eq!(bytes, vec![0x7B, 0xBD, 0xFD, 0x03, 0x02, 0xFF, 0x7F]);
+//!
+//!     let mut reader = NumberReader::new(Cursor::new(bytes));
+//!     reader.set_strict(true);
+//!     assert_eq!(15293, reader.read_varint(Quic)?);
+//!     assert_eq!(515, reader.read_varint(CompactSize)?);
+//!     assert_eq!(0x3FFF, reader.read_varint(Vlq::MIDI)?);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`NumberReader::read_varint`]: crate::NumberReader::read_varint
+//! [`NumberWriter::write_varint`]: crate::NumberWriter::write_varint
+//! [`NumberReader`]: crate::NumberReader
+//! [strict]: crate::NumberReader::set_strict
+
+use crate::io::{self, Read};
+use crate::leb128;
+
+/// The largest number of bytes that any scheme may use to encode a value,
+/// which is the length of the buffer passed to [`VarintScheme::encode`].
+pub const MAX_LEN: usize = 10;
+
+/// A variable-length encoding of unsigned 64-bit integers.
+///
+/// # Examples
+///
+/// A scheme which encodes values below `0x80` as a single byte and all others
+/// as `0x80` followed by a big-endian `u64`:
+///
+/// ```
+/// use std::io::{self, Cursor, ErrorKind};
+/// use byte_order::io::Read;
+/// use byte_order::varint::{self, VarintScheme};
+/// use byte_order::{NumberReader, NumberWriter};
+///
+/// struct Escaped;
+///
+/// impl VarintScheme for Escaped {
+///     fn max_len(&self) -> usize {
+///         9
+///     }
+///
+///     fn max_value(&self) -> u64 {
+///         u64::MAX
+///     }
+///
+///     fn encode(&self, n: u64, buf: &mut [u8; varint::MAX_LEN]) -> io::Result<usize> {
+///         if n < 0x80 {
+///             buf[0] = n as u8;
+///             return Ok(1);
+///         }
+///         buf[0] = 0x80;
+///         buf[1..9].copy_from_slice(&n.to_be_bytes());
+///         Ok(9)
+///     }
+///
+///     fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+///         let mut byte = [0];
+///         src.read_exact(&mut byte)?;
+///         if byte[0] < 0x80 {
+///             return Ok(byte[0].into());
+///         }
+///         let mut bytes = [0; 8];
+///         src.read_exact(&mut bytes)?;
+///         let n = u64::from_be_bytes(bytes);
+///         if strict && n < 0x80 {
+///             return Err(io::Error::new(ErrorKind::InvalidData, "non-canonical encoding"));
+///         }
+///         Ok(n)
+///     }
+/// }
+///
+/// fn main() -> io::Result<()> {
+///     let mut writer = NumberWriter::new(vec![]);
+///     writer.write_varint(Escaped, 0x7F)?;
+///     writer.write_varint(Escaped, 0x80)?;
+///     let bytes = writer.into_inner();
+///     assert_eq!(10, bytes.len());
+///
+///     let mut reader = NumberReader::new(Cursor::new(bytes));
+///     assert_eq!(0x7F, reader.read_varint(Escaped)?);
+///     assert_eq!(0x80, reader.read_varint(Escaped)?);
+///     Ok(())
+/// }
+/// ```
+pub trait VarintScheme {
+    /// Returns the largest number of bytes that an encoding may take, which
+    /// must not exceed [`MAX_LEN`].
+    fn max_len(&self) -> usize;
+
+    /// Returns the largest value that can be encoded.
+    fn max_value(&self) -> u64;
+
+    /// Encodes `n` into the start of `buf`, returning the number of bytes
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// If `n` is greater than [`max_value`], an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned.
+    ///
+    /// [`max_value`]: VarintScheme::max_value
+    /// [`ErrorKind::InvalidInput`]: io::ErrorKind::InvalidInput
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize>;
+
+    /// Decodes a value from `src`, reading no more bytes than its encoding
+    /// takes.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by `src`. An error of the
+    /// kind [`ErrorKind::InvalidData`] is returned if the encoding is longer
+    /// than [`max_len`], if its value does not fit in a `u64`, or if `strict`
+    /// is `true` and the encoding is not the shortest one of its value.
+    ///
+    /// [`max_len`]: VarintScheme::max_len
+    /// [`ErrorKind::InvalidData`]: io::ErrorKind::InvalidData
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64>;
+}
+
+/// The variable-length integers of QUIC, as defined in RFC 9000.
+///
+/// The two most significant bits of the first byte give the length of the
+/// encoding, which is 1, 2, 4, or 8 bytes, and the remaining bits hold the
+/// value in big-endian byte order. Values up to `2^62 - 1` can be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::NumberReader;
+/// use byte_order::varint::Quic;
+///
+/// fn main() -> io::Result<()> {
+///     let bytes = vec![0x25, 0x40, 0x25, 0x9D, 0x7F, 0x3E, 0x7D];
+///     let mut reader = NumberReader::new(Cursor::new(bytes));
+///     assert_eq!(37, reader.read_varint(Quic)?);
+///     assert_eq!(37, reader.read_varint(Quic)?);
+///     assert_eq!(494878333, reader.read_varint(Quic)?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Quic;
+
+impl VarintScheme for Quic {
+    #[inline]
+    fn max_len(&self) -> usize {
+        8
+    }
+
+    #[inline]
+    fn max_value(&self) -> u64 {
+        (1 << 62) - 1
+    }
+
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize> {
+        let (len, tag) = match n {
+            0..=0x3F => (1, 0x00),
+            0x40..=0x3FFF => (2, 0x40),
+            0x4000..=0x3FFF_FFFF => (4, 0x80),
+            0x4000_0000..=0x3FFF_FFFF_FFFF_FFFF => (8, 0xC0),
+            _ => return Err(too_large()),
+        };
+        buf[..len].copy_from_slice(&n.to_be_bytes()[8 - len..]);
+        buf[0] |= tag;
+        Ok(len)
+    }
+
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+        let first = read_byte(src)?;
+        let len = 1 << (first >> 6);
+        let mut bytes = [0; 8];
+        bytes[8 - len] = first & 0x3F;
+        src.read_exact(&mut bytes[9 - len..])?;
+        let n = u64::from_be_bytes(bytes);
+
+        if strict && len > 1 && n >> (4 * len - 2) == 0 {
+            return Err(invalid("non-canonical QUIC varint encoding"));
+        }
+        Ok(n)
+    }
+}
+
+/// The big-endian varints of SQLite's file format.
+///
+/// Each of the first eight bytes holds seven bits of the value, with the most
+/// significant bit set on every byte but the last. If all eight bytes have it
+/// set, a ninth byte holds the eight least significant bits of the value, so
+/// every `u64` can be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::NumberReader;
+/// use byte_order::varint::Sqlite;
+///
+/// fn main() -> io::Result<()> {
+///     let mut bytes = vec![0x81, 0x00];
+///     bytes.extend(&[0xFF; 9]);
+///     let mut reader = NumberReader::new(Cursor::new(bytes));
+///     assert_eq!(0x80, reader.read_varint(Sqlite)?);
+///     assert_eq!(u64::MAX, reader.read_varint(Sqlite)?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Sqlite;
+
+impl VarintScheme for Sqlite {
+    #[inline]
+    fn max_len(&self) -> usize {
+        9
+    }
+
+    #[inline]
+    fn max_value(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize> {
+        if n >> 56 != 0 {
+            buf[8] = n as u8;
+            let mut rest = n >> 8;
+            for byte in buf[..8].iter_mut().rev() {
+                *byte = (rest & 0x7F) as u8 | 0x80;
+                rest >>= 7;
+            }
+            return Ok(9);
+        }
+        Ok(encode_vlq(n, buf))
+    }
+
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+        let mut n = 0;
+        for i in 0..8 {
+            let byte = read_byte(src)?;
+            n = n << 7 | u64::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                if strict && i > 0 && n >> (7 * i) == 0 {
+                    return Err(invalid("non-canonical SQLite varint encoding"));
+                }
+                return Ok(n);
+            }
+        }
+
+        // A nine-byte encoding is only the shortest one for values which do
+        // not fit in the 56 bits of an eight-byte encoding.
+        if strict && n >> 48 == 0 {
+            return Err(invalid("non-canonical SQLite varint encoding"));
+        }
+        Ok(n << 8 | u64::from(read_byte(src)?))
+    }
+}
+
+/// The `CompactSize` integers of Bitcoin's serialization format.
+///
+/// Values below `0xFD` are encoded as a single byte. Larger values are encoded
+/// as a marker byte of `0xFD`, `0xFE`, or `0xFF`, followed by the value as a
+/// little-endian `u16`, `u32`, or `u64` respectively.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor};
+/// use byte_order::NumberReader;
+/// use byte_order::varint::CompactSize;
+///
+/// fn main() -> io::Result<()> {
+///     let bytes = vec![0xFC, 0xFD, 0xFD, 0x00, 0xFE, 0x00, 0x00, 0x01, 0x00];
+///     let mut reader = NumberReader::new(Cursor::new(bytes));
+///     assert_eq!(0xFC, reader.read_varint(CompactSize)?);
+///     assert_eq!(0xFD, reader.read_varint(CompactSize)?);
+///     assert_eq!(0x10000, reader.read_varint(CompactSize)?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CompactSize;
+
+impl VarintScheme for CompactSize {
+    #[inline]
+    fn max_len(&self) -> usize {
+        9
+    }
+
+    #[inline]
+    fn max_value(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize> {
+        let (marker, width) = match n {
+            0..=0xFC => {
+                buf[0] = n as u8;
+                return Ok(1);
+            }
+            0xFD..=0xFFFF => (0xFD, 2),
+            0x1_0000..=0xFFFF_FFFF => (0xFE, 4),
+            _ => (0xFF, 8),
+        };
+        buf[0] = marker;
+        buf[1..=width].copy_from_slice(&n.to_le_bytes()[..width]);
+        Ok(1 + width)
+    }
+
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+        let (width, min) = match read_byte(src)? {
+            0xFD => (2, 0xFD),
+            0xFE => (4, 0x1_0000),
+            0xFF => (8, 0x1_0000_0000),
+            byte => return Ok(byte.into()),
+        };
+        let mut bytes = [0; 8];
+        src.read_exact(&mut bytes[..width])?;
+        let n = u64::from_le_bytes(bytes);
+
+        if strict && n < min {
+            return Err(invalid("non-canonical CompactSize encoding"));
+        }
+        Ok(n)
+    }
+}
+
+/// Big-endian base-128 variable-length quantities, as used by MIDI files and
+/// by the tags and object identifiers of ASN.1.
+///
+/// Each byte holds seven bits of the value, most significant first, with the
+/// most significant bit set on every byte but the last. The number of bytes
+/// is limited by the scheme: MIDI allows at most four, while [`Vlq::new`]
+/// allows enough for any `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Cursor, ErrorKind};
+/// use byte_order::NumberReader;
+/// use byte_order::varint::Vlq;
+///
+/// fn main() -> io::Result<()> {
+///     let bytes = vec![0x81, 0x80, 0x80, 0x80, 0x00];
+///
+///     let mut reader = NumberReader::new(Cursor::new(bytes.clone()));
+///     assert_eq!(0x1000_0000, reader.read_varint(Vlq::new())?);
+///
+///     let mut reader = NumberReader::new(Cursor::new(bytes));
+///     let err = reader.read_varint(Vlq::MIDI).unwrap_err();
+///     assert_eq!(ErrorKind::InvalidData, err.kind());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Vlq {
+    max_len: usize,
+}
+
+impl Vlq {
+    /// The variable-length quantities of Standard MIDI Files, which take at
+    /// most four bytes and so encode values up to `0x0FFF_FFFF`.
+    pub const MIDI: Vlq = Vlq { max_len: 4 };
+
+    /// Creates a `Vlq` which allows enough bytes to encode any `u64`.
+    #[inline]
+    pub const fn new() -> Vlq {
+        Vlq { max_len: MAX_LEN }
+    }
+
+    /// Creates a `Vlq` which allows at most `max_len` bytes per encoding.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_len` is not in the range `1..=10`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::varint::{VarintScheme, Vlq};
+    ///
+    /// assert_eq!(Vlq::MIDI, Vlq::with_max_len(4));
+    /// assert_eq!(0x3FFF, Vlq::with_max_len(2).max_value());
+    /// ```
+    #[inline]
+    pub fn with_max_len(max_len: usize) -> Vlq {
+        assert!(
+            (1..=MAX_LEN).contains(&max_len),
+            "a VLQ must allow between 1 and 10 bytes"
+        );
+        Vlq { max_len }
+    }
+}
+
+impl Default for Vlq {
+    #[inline]
+    fn default() -> Vlq {
+        Vlq::new()
+    }
+}
+
+impl VarintScheme for Vlq {
+    #[inline]
+    fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    #[inline]
+    fn max_value(&self) -> u64 {
+        match self.max_len * 7 {
+            bits if bits >= 64 => u64::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize> {
+        if n > self.max_value() {
+            return Err(too_large());
+        }
+        Ok(encode_vlq(n, buf))
+    }
+
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+        let mut n: u64 = 0;
+        for i in 0..self.max_len {
+            let byte = read_byte(src)?;
+            if strict && i == 0 && byte == 0x80 {
+                return Err(invalid("non-canonical VLQ encoding"));
+            }
+            if n >> 57 != 0 {
+                return Err(invalid("VLQ encoding does not fit in a u64"));
+            }
+            n = n << 7 | u64::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(invalid("VLQ encoding exceeds the maximum length"))
+    }
+}
+
+/// Unsigned LEB128 integers of at most 64 bits, as read by
+/// [`NumberReader::read_uleb128_u64`].
+///
+/// This scheme allows code that is generic over a [`VarintScheme`] to use
+/// LEB128 as well.
+///
+/// [`NumberReader::read_uleb128_u64`]: crate::NumberReader::read_uleb128_u64
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Uleb128;
+
+impl VarintScheme for Uleb128 {
+    #[inline]
+    fn max_len(&self) -> usize {
+        MAX_LEN
+    }
+
+    #[inline]
+    fn max_value(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn encode(&self, n: u64, buf: &mut [u8; MAX_LEN]) -> io::Result<usize> {
+        let mut encoded = [0; leb128::MAX_LEN];
+        let len = leb128::encode_unsigned(n.into(), &mut encoded);
+        buf[..len].copy_from_slice(&encoded[..len]);
+        Ok(len)
+    }
+
+    fn decode<R: Read + ?Sized>(&self, src: &mut R, strict: bool) -> io::Result<u64> {
+        let mut decoder = leb128::Decoder::unsigned(64, strict);
+        loop {
+            if let Some(n) = decoder.push(read_byte(src)?)? {
+                return Ok(n as u64);
+            }
+        }
+    }
+}
+
+/// Encodes `n` as a big-endian base-128 quantity into `buf`, returning the
+/// number of bytes used.
+#[inline]
+fn encode_vlq(n: u64, buf: &mut [u8; MAX_LEN]) -> usize {
+    let bits = 64 - n.leading_zeros() as usize;
+    let len = bits.max(1).div_ceil(7);
+    for (i, byte) in buf[..len].iter_mut().enumerate() {
+        let shift = 7 * (len - 1 - i);
+        *byte = (n >> shift) as u8 & 0x7F | 0x80;
+    }
+    buf[len - 1] &= 0x7F;
+    len
+}
+
+#[inline]
+fn read_byte<R: Read + ?Sized>(src: &mut R) -> io::Result<u8> {
+    let mut byte = [0];
+    src.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+#[cold]
+fn too_large() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "value is too large for the varint scheme",
+    )
+}
+
+#[cold]
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
@@ -0,0 +1,587 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::io::Result;
+#[cfg(not(feature = "std"))]
+use crate::io::Write;
+
+use crate::encode::Encode;
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::varint::VarintScheme;
+use crate::write::NumberWriter;
+
+/// A `VecWriter` writes numbers to the end of a `Vec<u8>`.
+///
+/// Since writing to a `Vec<u8>` cannot fail, the `write_*` methods of a
+/// `VecWriter` return nothing rather than an [`io::Result`], unlike those of
+/// a [`NumberWriter`]. Only the methods whose input may not be representable,
+/// such as [`write_u24`] and [`encode`], still return a result.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::{ByteOrder, VecWriter};
+///
+/// let mut writer = VecWriter::with_capacity(ByteOrder::BE, 16);
+/// writer.write_u16(0x1234);
+/// writer.write_u32_slice(&[1, 2]);
+/// writer.write_uleb128_u32(300);
+///
+/// assert_eq!(
+///     writer.into_inner(),
+///     vec![0x12, 0x34, 0, 0, 0, 1, 0, 0, 0, 2, 0xAC, 0x02]
+/// );
+/// ```
+///
+/// [`io::Result`]: crate::io::Result
+/// [`NumberWriter`]: crate::NumberWriter
+/// [`write_u24`]: VecWriter::write_u24
+/// [`encode`]: VecWriter::encode
+pub struct VecWriter<O: Endianness = ByteOrder> {
+    vec: Vec<u8>,
+    order: O,
+    length_prefix: LengthPrefix,
+}
+
+impl VecWriter {
+    /// Creates a new, empty `VecWriter` which writes with the target
+    /// platform's native endianness.
+    ///
+    /// Portable code should use [`with_order`], as appropriate, instead.
+    ///
+    /// [`with_order`]: VecWriter::with_order
+    #[inline]
+    pub fn new() -> VecWriter {
+        VecWriter::with_order(ByteOrder::NE)
+    }
+}
+
+impl Default for VecWriter {
+    #[inline]
+    fn default() -> VecWriter {
+        VecWriter::new()
+    }
+}
+
+macro_rules! write_numbers {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes ", $desc, " to the end of the vector.")]
+            #[inline]
+            pub fn $name(&mut self, n: $ty) {
+                self.write_number(n)
+            }
+        )*
+    };
+}
+
+macro_rules! write_numbers_slice {
+    ($($name:ident: $ty:ty, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a sequence of ", $desc, " to the end of the vector.")]
+            #[inline]
+            pub fn $name(&mut self, src: &[$ty]) {
+                self.write_slice(src)
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_to_writer {
+    ($($(#[$attr:meta])* fn $name:ident(&mut self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) -> $ret {
+                self.with_writer(|writer| writer.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_infallible {
+    ($($(#[$attr:meta])* fn $name:ident(&mut self $(, $arg:ident: $ty:ty)*);)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $name(&mut self $(, $arg: $ty)*) {
+                let result = self.with_writer(|writer| writer.$name($($arg),*));
+                debug_assert!(result.is_ok());
+            }
+        )*
+    };
+}
+
+impl<O: Endianness> VecWriter<O> {
+    /// Creates a new, empty `VecWriter` which writes with the given byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, VecWriter};
+    ///
+    /// let be_writer = VecWriter::with_order(ByteOrder::BE);
+    /// let static_writer = VecWriter::with_order(BigEndian);
+    /// ```
+    #[inline]
+    pub fn with_order(order: O) -> VecWriter<O> {
+        VecWriter::from_vec(order, Vec::new())
+    }
+
+    /// Creates a new, empty `VecWriter` which writes with the given byte
+    /// order, and has space for at least `capacity` bytes before it
+    /// reallocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, VecWriter};
+    ///
+    /// let writer = VecWriter::with_capacity(ByteOrder::LE, 64);
+    /// assert!(writer.capacity() >= 64);
+    /// assert!(writer.is_empty());
+    /// ```
+    #[inline]
+    pub fn with_capacity(order: O, capacity: usize) -> VecWriter<O> {
+        VecWriter::from_vec(order, Vec::with_capacity(capacity))
+    }
+
+    /// Creates a `VecWriter` which writes to the end of an existing vector
+    /// with the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, VecWriter};
+    ///
+    /// let mut writer = VecWriter::from_vec(ByteOrder::BE, vec![0xFF]);
+    /// writer.write_u16(0x1234);
+    /// assert_eq!(writer.into_inner(), vec![0xFF, 0x12, 0x34]);
+    /// ```
+    #[inline]
+    pub fn from_vec(order: O, vec: Vec<u8>) -> VecWriter<O> {
+        VecWriter {
+            vec,
+            order,
+            length_prefix: LengthPrefix::default(),
+        }
+    }
+
+    /// Consumes this `VecWriter`, returning the underlying vector.
+    #[inline]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.vec
+    }
+
+    /// Gets a reference to the underlying vector.
+    #[inline]
+    pub fn get_ref(&self) -> &Vec<u8> {
+        &self.vec
+    }
+
+    /// Gets a mutable reference to the underlying vector.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.vec
+    }
+
+    /// Returns the bytes which have been written.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.vec
+    }
+
+    /// Returns the number of bytes which have been written.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if no bytes have been written.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns the number of bytes the underlying vector can hold without
+    /// reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Reserves space for at least `additional` more bytes to be written
+    /// without reallocating.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the new capacity overflows `usize`.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Removes every byte which has been written, keeping the capacity of the
+    /// underlying vector.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// written by this `VecWriter`.
+    #[inline]
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values
+    /// written by this `VecWriter`.
+    #[inline]
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Writes all of `bytes` to the end of the vector.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.vec.extend_from_slice(bytes);
+    }
+
+    /// Writes a number of any primitive type to the end of the vector.
+    ///
+    /// Each of the named `write_*` methods, such as [`write_u32`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, VecWriter};
+    ///
+    /// let mut writer = VecWriter::with_order(ByteOrder::BE);
+    /// writer.write_number(0x1234u16);
+    /// writer.write_number(-1i8);
+    /// assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0xFF]);
+    /// ```
+    ///
+    /// [`write_u32`]: VecWriter::write_u32
+    #[inline]
+    pub fn write_number<T: Number>(&mut self, n: T) {
+        self.vec
+            .extend_from_slice(n.to_bytes(self.order.byte_order()).as_ref());
+    }
+
+    /// Writes a sequence of numbers of any primitive type to the end of the
+    /// vector.
+    ///
+    /// Space for the whole of `src` is reserved up front. Each of the named
+    /// `write_*_slice` methods, such as [`write_u32_slice`], is equivalent to
+    /// calling this method with the corresponding type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{ByteOrder, VecWriter};
+    ///
+    /// let mut writer = VecWriter::with_order(ByteOrder::LE);
+    /// writer.write_slice(&[0x1234i16, 0x5678]);
+    /// assert_eq!(writer.into_inner(), vec![0x34, 0x12, 0x78, 0x56]);
+    /// ```
+    ///
+    /// [`write_u32_slice`]: VecWriter::write_u32_slice
+    #[inline]
+    pub fn write_slice<T: Number>(&mut self, src: &[T]) {
+        self.vec.reserve(mem::size_of_val(src));
+        match self.order.byte_order() {
+            order if order == ByteOrder::NE => self.vec.extend_from_slice(pod::as_bytes(src)),
+            ByteOrder::BE => extend(&mut self.vec, src, |n: T| n.to_bytes(ByteOrder::BE)),
+            ByteOrder::LE => extend(&mut self.vec, src, |n: T| n.to_bytes(ByteOrder::LE)),
+        }
+    }
+
+    /// Writes a value of any type that implements [`Encode`] to the end of the
+    /// vector.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Encode::encode`].
+    ///
+    /// [`Encode`]: crate::Encode
+    /// [`Encode::encode`]: crate::Encode::encode
+    #[inline]
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.with_writer(|writer| writer.encode(value))
+    }
+
+    write_numbers! {
+        write_u8: u8, "an unsigned 8-bit integer";
+        write_i8: i8, "a signed 8-bit integer";
+        write_u16: u16, "an unsigned 16-bit integer";
+        write_i16: i16, "a signed 16-bit integer";
+        write_u32: u32, "an unsigned 32-bit integer";
+        write_i32: i32, "a signed 32-bit integer";
+        write_u64: u64, "an unsigned 64-bit integer";
+        write_i64: i64, "a signed 64-bit integer";
+        write_u128: u128, "an unsigned 128-bit integer";
+        write_i128: i128, "a signed 128-bit integer";
+        write_f32: f32, "an IEEE754 single-precision (4 bytes) floating point number";
+        write_f64: f64, "an IEEE754 double-precision (8 bytes) floating point number";
+    }
+
+    write_numbers_slice! {
+        write_u16_slice: u16, "unsigned 16-bit integers";
+        write_i16_slice: i16, "signed 16-bit integers";
+        write_u32_slice: u32, "unsigned 32-bit integers";
+        write_i32_slice: i32, "signed 32-bit integers";
+        write_u64_slice: u64, "unsigned 64-bit integers";
+        write_i64_slice: i64, "signed 64-bit integers";
+        write_u128_slice: u128, "unsigned 128-bit integers";
+        write_i128_slice: i128, "signed 128-bit integers";
+        write_f32_slice: f32, "IEEE754 single-precision (4 bytes) floating point numbers";
+        write_f64_slice: f64, "IEEE754 double-precision (8 bytes) floating point numbers";
+    }
+
+    delegate_infallible! {
+        /// Writes an unsigned 32-bit integer to the end of the vector, encoded
+        /// as unsigned LEB128.
+        fn write_uleb128_u32(&mut self, n: u32);
+
+        /// Writes an unsigned 64-bit integer to the end of the vector, encoded
+        /// as unsigned LEB128.
+        fn write_uleb128_u64(&mut self, n: u64);
+
+        /// Writes an unsigned 128-bit integer to the end of the vector,
+        /// encoded as unsigned LEB128.
+        fn write_uleb128_u128(&mut self, n: u128);
+
+        /// Writes a signed 32-bit integer to the end of the vector, encoded as
+        /// signed LEB128.
+        fn write_sleb128_i32(&mut self, n: i32);
+
+        /// Writes a signed 64-bit integer to the end of the vector, encoded as
+        /// signed LEB128.
+        fn write_sleb128_i64(&mut self, n: i64);
+
+        /// Writes a signed 128-bit integer to the end of the vector, encoded as
+        /// signed LEB128.
+        fn write_sleb128_i128(&mut self, n: i128);
+
+        /// Writes a signed 32-bit integer to the end of the vector, encoded as
+        /// zigzag unsigned LEB128.
+        fn write_zigzag_i32(&mut self, n: i32);
+
+        /// Writes a signed 64-bit integer to the end of the vector, encoded as
+        /// zigzag unsigned LEB128.
+        fn write_zigzag_i64(&mut self, n: i64);
+
+        /// Writes an `f32` to the end of the vector, narrowed to a
+        /// half-precision floating point number.
+        fn write_f32_as_f16(&mut self, n: f32);
+
+        /// Writes a sequence of `f32` values to the end of the vector,
+        /// narrowing each of them to a half-precision floating point number.
+        fn write_f32_as_f16_slice(&mut self, src: &[f32]);
+
+        /// Writes an `f32` to the end of the vector, narrowed to a brain
+        /// floating point number (bfloat16).
+        fn write_f32_as_bf16(&mut self, n: f32);
+
+        /// Writes a sequence of `f32` values to the end of the vector,
+        /// narrowing each of them to a brain floating point number (bfloat16).
+        fn write_f32_as_bf16_slice(&mut self, src: &[f32]);
+    }
+
+    /// Writes `n` to the end of the vector, encoded with a variable-length
+    /// `scheme`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` is too large for the scheme, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    #[inline]
+    pub fn write_varint<S: VarintScheme>(&mut self, scheme: S, n: u64) -> Result<()> {
+        self.with_writer(|writer| writer.write_varint(scheme, n))
+    }
+
+    delegate_to_writer! {
+        /// Writes the length that prefixes a variable-sized value to the end
+        /// of the vector, using the encoding set by [`set_length_prefix`].
+        ///
+        /// # Errors
+        ///
+        /// If `len` does not fit in the length prefix, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`set_length_prefix`]: VecWriter::set_length_prefix
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_length(&mut self, len: usize) -> Result<()>;
+
+        /// Writes an unsigned integer to the end of the vector using only
+        /// `nbytes` bytes.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in `nbytes` bytes, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_uint(&mut self, n: u64, nbytes: usize) -> Result<()>;
+
+        /// Writes a signed integer to the end of the vector using only
+        /// `nbytes` bytes.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=8`.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in `nbytes` bytes, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_int(&mut self, n: i64, nbytes: usize) -> Result<()>;
+
+        /// Writes an unsigned 128-bit integer to the end of the vector using
+        /// only `nbytes` bytes.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in `nbytes` bytes, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_uint128(&mut self, n: u128, nbytes: usize) -> Result<()>;
+
+        /// Writes a signed 128-bit integer to the end of the vector using only
+        /// `nbytes` bytes.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `nbytes` is not in the range `1..=16`.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in `nbytes` bytes, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_int128(&mut self, n: i128, nbytes: usize) -> Result<()>;
+
+        /// Writes an unsigned 24-bit integer to the end of the vector.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in 24 bits, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_u24(&mut self, n: u32) -> Result<()>;
+
+        /// Writes a signed 24-bit integer to the end of the vector.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in 24 bits, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_i24(&mut self, n: i32) -> Result<()>;
+
+        /// Writes an unsigned 48-bit integer to the end of the vector.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in 48 bits, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_u48(&mut self, n: u64) -> Result<()>;
+
+        /// Writes a signed 48-bit integer to the end of the vector.
+        ///
+        /// # Errors
+        ///
+        /// If `n` does not fit in 48 bits, an error of the kind
+        /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+        ///
+        /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+        fn write_i48(&mut self, n: i64) -> Result<()>;
+    }
+
+    /// Runs `f` with a [`NumberWriter`] over the underlying vector, with the
+    /// same settings as this `VecWriter`.
+    #[inline]
+    fn with_writer<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut NumberWriter<&mut Vec<u8>>) -> T,
+    {
+        let mut writer = NumberWriter::with_order(self.order.byte_order(), &mut self.vec);
+        writer.set_length_prefix(self.length_prefix);
+        f(&mut writer)
+    }
+}
+
+/// Appends the bytes of each number in `src`, as converted by `to_bytes`, to
+/// `vec`.
+#[inline]
+fn extend<T: Number>(vec: &mut Vec<u8>, src: &[T], to_bytes: impl Fn(T) -> T::Bytes) {
+    for &n in src {
+        vec.extend_from_slice(to_bytes(n).as_ref());
+    }
+}
+
+impl<O: Endianness> From<VecWriter<O>> for Vec<u8> {
+    #[inline]
+    fn from(writer: VecWriter<O>) -> Vec<u8> {
+        writer.vec
+    }
+}
+
+#[cfg(feature = "std")]
+impl<O: Endianness> std::io::Write for VecWriter<O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.vec.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.vec.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<O: Endianness> Write for VecWriter<O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.vec.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.vec.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,6 +1,21 @@
-use std::io::{Result, Write};
+use core::convert::TryFrom;
+use core::mem;
 
-use crate::order::ByteOrder;
+use crate::io::{Error, ErrorKind, Result, Write};
+
+use crate::encode::Encode;
+use crate::float;
+use crate::leb128;
+use crate::number::Number;
+use crate::order::{ByteOrder, Endianness};
+use crate::pod;
+use crate::prefix::LengthPrefix;
+use crate::varint::{self, VarintScheme};
+use crate::zigzag;
+
+/// The size, in bytes, of the stack buffer used to convert the byte order of
+/// slices before they are written.
+const SWAP_BUF_LEN: usize = 1024;
 
 /// A `NumberWriter` wraps a [writer] and provides methods for writing numbers.
 ///
@@ -63,9 +78,11 @@ use crate::order::ByteOrder;
 /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
 /// [`NumberWriter::new`]: NumberWriter::new
 /// [`NumberWriter::with_order`]: NumberWriter::with_order
-pub struct NumberWriter<W: Write> {
+pub struct NumberWriter<W: Write, O: Endianness = ByteOrder> {
     inner: W,
-    order: ByteOrder,
+    order: O,
+    length_prefix: LengthPrefix,
+    position: Option<u64>,
 }
 
 impl<W: Write> NumberWriter<W> {
@@ -73,10 +90,17 @@ impl<W: Write> NumberWriter<W> {
     pub fn new(w: W) -> NumberWriter<W> {
         NumberWriter::with_order(ByteOrder::NE, w)
     }
+}
 
+impl<W: Write, O: Endianness> NumberWriter<W, O> {
     #[inline]
-    pub fn with_order(order: ByteOrder, w: W) -> NumberWriter<W> {
-        NumberWriter { inner: w, order }
+    pub fn with_order(order: O, w: W) -> NumberWriter<W, O> {
+        NumberWriter {
+            inner: w,
+            order,
+            length_prefix: LengthPrefix::default(),
+            position: None,
+        }
     }
 
     /// Consumes this `NumberReader`, returning the underlying value.
@@ -127,6 +151,382 @@ impl<W: Write> NumberWriter<W> {
         &mut self.inner
     }
 
+    /// Returns the encoding of the length that prefixes variable-sized values
+    /// written by this `NumberWriter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{LengthPrefix, NumberWriter};
+    ///
+    /// let writer = NumberWriter::new(vec![]);
+    /// assert_eq!(LengthPrefix::U32, writer.length_prefix());
+    /// ```
+    pub fn length_prefix(&self) -> LengthPrefix {
+        self.length_prefix
+    }
+
+    /// Sets the encoding of the length that prefixes variable-sized values,
+    /// such as `Vec<T>` and `String`, written by this `NumberWriter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{LengthPrefix, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.set_length_prefix(LengthPrefix::U8);
+    ///     writer.encode("hi")?;
+    ///     assert_eq!(writer.into_inner(), vec![0x02, b'h', b'i']);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_length_prefix(&mut self, length_prefix: LengthPrefix) {
+        self.length_prefix = length_prefix;
+    }
+
+    /// Returns the number of bytes written through this `NumberWriter` since
+    /// position tracking was enabled or last reset, or `None` if it is not
+    /// tracked.
+    ///
+    /// The position is counted by this `NumberWriter` itself, so the
+    /// underlying writer does not need to implement `Seek`. It includes the
+    /// bytes written by every `write_*` method and by the [`Write`]
+    /// implementation of this `NumberWriter`. When a write fails, the bytes
+    /// that the underlying writer accepted before it failed are still
+    /// counted, so the position always matches what it was given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert_eq!(None, writer.position());
+    ///
+    ///     writer.set_track_position(true);
+    ///     writer.write_u32(0x12345678)?;
+    ///     writer.write_uleb128_u64(300)?;
+    ///     assert_eq!(Some(6), writer.position());
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write`]: Write
+    pub fn position(&self) -> Option<u64> {
+        self.position
+    }
+
+    /// Sets whether this `NumberWriter` counts the bytes written through it.
+    ///
+    /// Position tracking is disabled by default. Enabling it starts the count
+    /// at zero, unless it is already enabled, in which case the count is
+    /// kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::NumberWriter;
+    ///
+    /// let mut writer = NumberWriter::new(vec![]);
+    /// writer.set_track_position(true);
+    /// assert_eq!(Some(0), writer.position());
+    ///
+    /// writer.set_track_position(false);
+    /// assert_eq!(None, writer.position());
+    /// ```
+    pub fn set_track_position(&mut self, track: bool) {
+        self.position = match (track, self.position) {
+            (true, position) => position.or(Some(0)),
+            (false, _) => None,
+        };
+    }
+
+    /// Resets the count of bytes written through this `NumberWriter` to zero,
+    /// if position tracking is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.set_track_position(true);
+    ///     writer.write_u16(0x1234)?;
+    ///     writer.reset_position();
+    ///     writer.write_u8(0x56)?;
+    ///     assert_eq!(Some(1), writer.position());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn reset_position(&mut self) {
+        if self.position.is_some() {
+            self.position = Some(0);
+        }
+    }
+
+    /// Returns the byte order that this `NumberWriter` writes numbers with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_order::{BigEndian, ByteOrder, NumberWriter};
+    ///
+    /// let writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    /// assert_eq!(ByteOrder::LE, writer.order());
+    ///
+    /// let static_writer = NumberWriter::with_order(BigEndian, vec![]);
+    /// assert_eq!(ByteOrder::BE, static_writer.order());
+    /// ```
+    pub fn order(&self) -> ByteOrder {
+        self.order.byte_order()
+    }
+
+    /// Sets the byte order that this `NumberWriter` writes numbers with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     writer.write_u16(0x1234)?;
+    ///     writer.set_order(ByteOrder::LE);
+    ///     writer.write_u16(0x1234)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0x34, 0x12]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_order(&mut self, order: O) {
+        self.order = order;
+    }
+
+    /// Calls `f` with this `NumberWriter` set to the given byte order, and
+    /// then restores the byte order it had before, returning the result of
+    /// `f`.
+    ///
+    /// Unlike [`by_order`], the byte order can only be changed to another
+    /// value of the same type, but `f` receives this very `NumberWriter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     writer.with_scoped_order(ByteOrder::BE, |w| w.write_u16(0x1234))?;
+    ///     writer.write_u16(0x1234)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0x34, 0x12]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`by_order`]: NumberWriter::by_order
+    pub fn with_scoped_order<T, F>(&mut self, order: O, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let previous = mem::replace(&mut self.order, order);
+        let result = f(self);
+        self.order = previous;
+        result
+    }
+
+    /// Creates a `NumberWriter` which writes to this one with a different
+    /// byte order, keeping every other setting of this `NumberWriter`.
+    ///
+    /// This is useful for formats which store a few values in a byte order
+    /// other than the one used by the rest of the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{BigEndian, ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     writer.by_order(BigEndian).write_u16(0x1234)?;
+    ///     writer.write_u16(0x1234)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0x34, 0x12]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn by_order<P: Endianness>(&mut self, order: P) -> NumberWriter<&mut Self, P> {
+        let length_prefix = self.length_prefix;
+        let position = self.position;
+        NumberWriter {
+            inner: self,
+            order,
+            length_prefix,
+            position,
+        }
+    }
+
+    /// Writes a number of any primitive type to the underlying writer.
+    ///
+    /// Each of the named `write_*` methods, such as [`write_u32`], is
+    /// equivalent to calling this method with the corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     writer.write_number(0x1234u16)?;
+    ///     writer.write_number(-1i8)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x12, 0x34, 0xFF]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_u32`]: NumberWriter::write_u32
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_number<T: Number>(&mut self, n: T) -> Result<()> {
+        self.write_raw(n.to_bytes(self.order.byte_order()).as_ref())
+    }
+
+    /// Writes a sequence of numbers of any primitive type to the underlying
+    /// writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk. Each of the named `write_*_slice` methods, such as
+    /// [`write_u32_slice`], is equivalent to calling this method with the
+    /// corresponding type.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     writer.write_slice(&[0x1234i16, 0x5678])?;
+    ///     assert_eq!(writer.into_inner(), vec![0x34, 0x12, 0x78, 0x56]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`write_u32_slice`]: NumberWriter::write_u32_slice
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_slice<T: Number>(&mut self, src: &[T]) -> Result<()> {
+        match self.order.byte_order() {
+            order if order == ByteOrder::NE => self.write_raw(pod::as_bytes(src)),
+            ByteOrder::BE => self.write_chunked(src, |n: T| n.to_bytes(ByteOrder::BE)),
+            ByteOrder::LE => self.write_chunked(src, |n: T| n.to_bytes(ByteOrder::LE)),
+        }
+    }
+
+    /// Writes a value of any type that implements [`Encode`] to the
+    /// underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error returned by [`Encode::encode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     writer.encode(&(true, [0x1234u16, 0x5678]))?;
+    ///     assert_eq!(writer.into_inner(), vec![0x01, 0x12, 0x34, 0x56, 0x78]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Encode`]: crate::Encode
+    /// [`Encode::encode`]: crate::Encode::encode
+    #[inline]
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.encode(self)
+    }
+
+    /// Writes the length that prefixes a variable-sized value to the
+    /// underlying writer, using the encoding set by [`set_length_prefix`].
+    ///
+    /// This is useful when implementing [`Encode`] for collections.
+    ///
+    /// # Errors
+    ///
+    /// If `len` is too large for the length prefix, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, LengthPrefix, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     writer.set_length_prefix(LengthPrefix::U16);
+    ///     writer.write_length(3)?;
+    ///     assert!(writer.write_length(0x10000).is_err());
+    ///     assert_eq!(writer.into_inner(), vec![0x00, 0x03]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`set_length_prefix`]: NumberWriter::set_length_prefix
+    /// [`Encode`]: crate::Encode
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    pub fn write_length(&mut self, len: usize) -> Result<()> {
+        let len = u64::try_from(len)
+            .ok()
+            .filter(|&len| len <= self.length_prefix.max_len())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    "length does not fit in the length prefix",
+                )
+            })?;
+        match self.length_prefix {
+            LengthPrefix::U8 => self.write_u8(len as u8),
+            LengthPrefix::U16 => self.write_u16(len as u16),
+            LengthPrefix::U32 => self.write_u32(len as u32),
+            LengthPrefix::U64 => self.write_u64(len),
+            LengthPrefix::Uleb128 => self.write_uleb128_u64(len),
+        }
+    }
+
     /// Writes an unsigned 8-bit integer to the underlying writer.
     ///
     /// **Note:** Since this method reads a single byte, no byte order
@@ -154,7 +554,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_u8(&mut self, n: u8) -> Result<()> {
-        self.inner.write_all(&[n])
+        self.write_number(n)
     }
 
     /// Writes an signed 8-bit integer to the underlying writer.
@@ -184,7 +584,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_i8(&mut self, n: i8) -> Result<()> {
-        self.write_u8(n as u8)
+        self.write_number(n)
     }
 
     /// Writes an unsigned 16-bit integer to the underlying writer.
@@ -218,11 +618,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_u16(&mut self, n: u16) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
 
     /// Writes a signed 16-bit integer to the underlying writer.
@@ -256,7 +652,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_i16(&mut self, n: i16) -> Result<()> {
-        self.write_u16(n as u16)
+        self.write_number(n)
     }
 
     /// Writes an unsigned 32-bit integer to the underlying writer.
@@ -290,11 +686,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_u32(&mut self, n: u32) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
 
     /// Writes a signed 32-bit integer to the underlying writer.
@@ -328,7 +720,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_i32(&mut self, n: i32) -> Result<()> {
-        self.write_u32(n as u32)
+        self.write_number(n)
     }
 
     /// Writes an unsigned 64-bit integer to the underlying writer.
@@ -362,11 +754,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_u64(&mut self, n: u64) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
 
     /// Writes a signed 64-bit integer to the underlying writer.
@@ -400,7 +788,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_i64(&mut self, n: i64) -> Result<()> {
-        self.write_u64(n as u64)
+        self.write_number(n)
     }
 
     /// Writes an unsigned 128-bit integer to the underlying writer.
@@ -446,11 +834,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_u128(&mut self, n: u128) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
 
     /// Writes a signed 128-bit integer to the underlying writer.
@@ -496,7 +880,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_i128(&mut self, n: i128) -> Result<()> {
-        self.write_u128(n as u128)
+        self.write_number(n)
     }
 
     /// Writes a IEEE754 single-precision floating point number to the
@@ -531,11 +915,7 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_f32(&mut self, n: f32) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
 
     /// Writes a IEEE754 double-precision floating point number to the
@@ -570,17 +950,1513 @@ impl<W: Write> NumberWriter<W> {
     /// [`Write::write_all`]: Write::write_all
     #[inline]
     pub fn write_f64(&mut self, n: f64) -> Result<()> {
-        let bytes = match self.order {
-            ByteOrder::BE => n.to_be_bytes(),
-            ByteOrder::LE => n.to_le_bytes(),
-        };
-        self.inner.write_all(&bytes)
+        self.write_number(n)
     }
-}
 
-impl<W: Write> Write for NumberWriter<W> {
-    fn write(&mut self, buf: &[u8]) -> Result<usize> {
-        self.inner.write(buf)
+    /// Writes a sequence of unsigned 16-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x1234, 0x5678];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u16_slice(&src)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x12, 0x34, 0x56, 0x78]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u16_slice(&src)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x34, 0x12, 0x78, 0x56]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u16_slice(&mut self, src: &[u16]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of signed 16-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x1234, 0x5678];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i16_slice(&src)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x12, 0x34, 0x56, 0x78]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i16_slice(&src)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x34, 0x12, 0x78, 0x56]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i16_slice(&mut self, src: &[i16]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of unsigned 32-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x12345678, 0x90123456];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u32_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u32_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![0x78, 0x56, 0x34, 0x12, 0x56, 0x34, 0x12, 0x90]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u32_slice(&mut self, src: &[u32]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of signed 32-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x12345678, 0x12345678];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i32_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i32_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i32_slice(&mut self, src: &[i32]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of unsigned 64-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x1234567890123456, 0x7890123456789012];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u64_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![
+    ///             0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///             0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///         ]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u64_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![
+    ///             0x56, 0x34, 0x12, 0x90, 0x78, 0x56, 0x34, 0x12,
+    ///             0x12, 0x90, 0x78, 0x56, 0x34, 0x12, 0x90, 0x78,
+    ///         ]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u64_slice(&mut self, src: &[u64]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of signed 64-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [0x1234567812345678, 0x1234567812345678];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i64_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         ]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i64_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///         ]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i64_slice(&mut self, src: &[i64]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of unsigned 128-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [
+    ///         0x12345678901234567890123456789012,
+    ///         0x34567890123456789012345678901234,
+    ///     ];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u128_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![
+    ///             0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56,
+    ///             0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12,
+    ///             0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78,
+    ///             0x90, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34,
+    ///         ]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u128_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![
+    ///             0x12, 0x90, 0x78, 0x56, 0x34, 0x12, 0x90, 0x78,
+    ///             0x56, 0x34, 0x12, 0x90, 0x78, 0x56, 0x34, 0x12,
+    ///             0x34, 0x12, 0x90, 0x78, 0x56, 0x34, 0x12, 0x90,
+    ///             0x78, 0x56, 0x34, 0x12, 0x90, 0x78, 0x56, 0x34,
+    ///         ]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u128_slice(&mut self, src: &[u128]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of signed 128-bit integers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [
+    ///         0x12345678123456781234567812345678,
+    ///         0x12345678123456781234567812345678,
+    ///     ];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i128_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///             0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x56, 0x78,
+    ///         ]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i128_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///             0x78, 0x56, 0x34, 0x12, 0x78, 0x56, 0x34, 0x12,
+    ///         ]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i128_slice(&mut self, src: &[i128]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of IEEE754 single-precision floating point numbers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [12.5, -0.75];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f32_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![0x41, 0x48, 0x00, 0x00, 0xBF, 0x40, 0x00, 0x00]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f32_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![0x00, 0x00, 0x48, 0x41, 0x00, 0x00, 0x40, 0xBF]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f32_slice(&mut self, src: &[f32]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes a sequence of IEEE754 double-precision floating point numbers to the underlying writer.
+    ///
+    /// If the byte order of this `NumberWriter` is [`ByteOrder::NE`], the
+    /// bytes of `src` are written directly with a single call to
+    /// [`Write::write_all`]. Otherwise, the numbers are converted in chunks
+    /// through a fixed-size buffer, with one call to [`Write::write_all`] per
+    /// chunk.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [12.5, -0.75];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f64_slice(&src)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![
+    ///             0x40, 0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///             0xBF, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///         ]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f64_slice(&src)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![
+    ///             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x29, 0x40,
+    ///             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE8, 0xBF,
+    ///         ]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ByteOrder::NE`]: ByteOrder::NE
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f64_slice(&mut self, src: &[f64]) -> Result<()> {
+        self.write_slice(src)
+    }
+
+    /// Writes an unsigned 32-bit integer to the underlying writer, encoded as
+    /// unsigned LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_uleb128_u32(624485)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xE5, 0x8E, 0x26]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_uleb128_u32(&mut self, n: u32) -> Result<()> {
+        self.write_uleb128(n.into())
+    }
+
+    /// Writes an unsigned 64-bit integer to the underlying writer, encoded as
+    /// unsigned LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_uleb128_u64(624485)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xE5, 0x8E, 0x26]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_uleb128_u64(&mut self, n: u64) -> Result<()> {
+        self.write_uleb128(n.into())
+    }
+
+    /// Writes an unsigned 128-bit integer to the underlying writer, encoded as
+    /// unsigned LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_uleb128_u128(624485)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xE5, 0x8E, 0x26]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_uleb128_u128(&mut self, n: u128) -> Result<()> {
+        self.write_uleb128(n)
+    }
+
+    /// Writes a signed 32-bit integer to the underlying writer, encoded as
+    /// signed LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_sleb128_i32(-123456)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xC0, 0xBB, 0x78]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_sleb128_i32(&mut self, n: i32) -> Result<()> {
+        self.write_sleb128(n.into())
+    }
+
+    /// Writes a signed 64-bit integer to the underlying writer, encoded as
+    /// signed LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_sleb128_i64(-123456)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xC0, 0xBB, 0x78]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_sleb128_i64(&mut self, n: i64) -> Result<()> {
+        self.write_sleb128(n.into())
+    }
+
+    /// Writes a signed 128-bit integer to the underlying writer, encoded as
+    /// signed LEB128.
+    ///
+    /// LEB128 encodings do not depend on the byte order of this `NumberWriter`.
+    /// The shortest possible encoding of `n` is always written.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_sleb128_i128(-123456)?;
+    ///     assert_eq!(writer.into_inner(), vec![0xC0, 0xBB, 0x78]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_sleb128_i128(&mut self, n: i128) -> Result<()> {
+        self.write_sleb128(n)
+    }
+
+    /// Writes a signed 32-bit integer to the underlying writer, encoded as
+    /// zigzag unsigned LEB128, such as a `sint32` of Protocol Buffers.
+    ///
+    /// The value is encoded with [`zigzag::encode_i32`], and then written with
+    /// [`write_uleb128_u32`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_zigzag_i32(-2)?;
+    ///     writer.write_zigzag_i32(i32::MIN)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x03, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`zigzag::encode_i32`]: crate::zigzag::encode_i32
+    /// [`write_uleb128_u32`]: NumberWriter::write_uleb128_u32
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_zigzag_i32(&mut self, n: i32) -> Result<()> {
+        self.write_uleb128_u32(zigzag::encode_i32(n))
+    }
+
+    /// Writes a signed 64-bit integer to the underlying writer, encoded as
+    /// zigzag unsigned LEB128, such as a `sint64` of Protocol Buffers.
+    ///
+    /// The value is encoded with [`zigzag::encode_i64`], and then written with
+    /// [`write_uleb128_u64`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_zigzag_i64(-2)?;
+    ///     writer.write_zigzag_i64(2)?;
+    ///     assert_eq!(writer.into_inner(), vec![0x03, 0x04]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`zigzag::encode_i64`]: crate::zigzag::encode_i64
+    /// [`write_uleb128_u64`]: NumberWriter::write_uleb128_u64
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_zigzag_i64(&mut self, n: i64) -> Result<()> {
+        self.write_uleb128_u64(zigzag::encode_i64(n))
+    }
+
+    /// Writes `n` to the underlying writer, encoded with a variable-length
+    /// `scheme`.
+    ///
+    /// The shortest encoding of `n` is always written, with a single call to
+    /// [`Write::write_all`]. See the [`varint`] module for the schemes which
+    /// are provided.
+    ///
+    /// # Errors
+    ///
+    /// If `n` is too large for the scheme, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::NumberWriter;
+    /// use byte_order::varint::{Sqlite, Vlq};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     writer.write_varint(Sqlite, 0x80)?;
+    ///     writer.write_varint(Vlq::MIDI, 0x2000)?;
+    ///     assert!(writer.write_varint(Vlq::MIDI, 0x1000_0000).is_err());
+    ///     assert_eq!(writer.into_inner(), vec![0x81, 0x00, 0xC0, 0x00]);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`varint`]: crate::varint
+    /// [`ErrorKind::InvalidInput`]: ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_varint<S: VarintScheme>(&mut self, scheme: S, n: u64) -> Result<()> {
+        let mut buf = [0; varint::MAX_LEN];
+        let len = scheme.encode(n, &mut buf)?;
+        self.write_raw(&buf[..len])
+    }
+
+    /// Writes an `f32` to the underlying writer, narrowing it to a
+    /// half-precision floating point number.
+    ///
+    /// The number is rounded to the nearest representable value, with ties
+    /// rounding to even. Numbers too large to be represented become infinity,
+    /// and NaN stays NaN.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 12.5f32;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f32_as_f16(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x4A, 0x40]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f32_as_f16(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x40, 0x4A]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f32_as_f16(&mut self, n: f32) -> Result<()> {
+        self.write_u16(float::f32_to_f16(n))
+    }
+
+    /// Writes a sequence of `f32` numbers to the underlying writer, narrowing
+    /// each of them to a half-precision floating point number.
+    ///
+    /// The numbers are converted in chunks through a fixed-size buffer, with
+    /// one call to [`Write::write_all`] per chunk. Rounding behaves as in
+    /// [`write_f32_as_f16`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [12.5, -0.75];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f32_as_f16_slice(&src)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x4A, 0x40, 0xBA, 0x00]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f32_as_f16_slice(&src)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x40, 0x4A, 0x00, 0xBA]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_f32_as_f16`]: NumberWriter::write_f32_as_f16
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f32_as_f16_slice(&mut self, src: &[f32]) -> Result<()> {
+        let to_bytes: fn(f32) -> [u8; 2] = match self.order.byte_order() {
+            ByteOrder::BE => |n| float::f32_to_f16(n).to_be_bytes(),
+            ByteOrder::LE => |n| float::f32_to_f16(n).to_le_bytes(),
+        };
+        self.write_chunked(src, to_bytes)
+    }
+
+    /// Writes an `f32` to the underlying writer, narrowing it to a brain
+    /// floating point number (bfloat16).
+    ///
+    /// The number is rounded to the nearest representable value, with ties
+    /// rounding to even. Numbers too large to be represented become infinity,
+    /// and NaN stays NaN.
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal call to
+    /// [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 12.5f32;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f32_as_bf16(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x41, 0x48]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f32_as_bf16(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x48, 0x41]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f32_as_bf16(&mut self, n: f32) -> Result<()> {
+        self.write_u16(float::f32_to_bf16(n))
+    }
+
+    /// Writes a sequence of `f32` numbers to the underlying writer, narrowing
+    /// each of them to a brain floating point number (bfloat16).
+    ///
+    /// The numbers are converted in chunks through a fixed-size buffer, with
+    /// one call to [`Write::write_all`] per chunk. Rounding behaves as in
+    /// [`write_f32_as_bf16`].
+    ///
+    /// # Errors
+    ///
+    /// This method propagates any error recieved from the internal calls to
+    /// [`Write::write_all`]. If an error is returned, an unspecified prefix of
+    /// `src` may have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let src = [12.5, -0.75];
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_f32_as_bf16_slice(&src)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x41, 0x48, 0xBF, 0x40]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_f32_as_bf16_slice(&src)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x48, 0x41, 0x40, 0xBF]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_f32_as_bf16`]: NumberWriter::write_f32_as_bf16
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_f32_as_bf16_slice(&mut self, src: &[f32]) -> Result<()> {
+        let to_bytes: fn(f32) -> [u8; 2] = match self.order.byte_order() {
+            ByteOrder::BE => |n| float::f32_to_bf16(n).to_be_bytes(),
+            ByteOrder::LE => |n| float::f32_to_bf16(n).to_le_bytes(),
+        };
+        self.write_chunked(src, to_bytes)
+    }
+
+    /// Writes an unsigned integer to the underlying writer using only `nbytes`
+    /// bytes.
+    ///
+    /// This is useful for formats which store integers in widths that have no
+    /// corresponding primitive type, such as 24-bit or 40-bit integers.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=8`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in `nbytes` bytes, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 0x1234567890u64;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_uint(n, 5)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x12, 0x34, 0x56, 0x78, 0x90]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_uint(n, 5)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x90, 0x78, 0x56, 0x34, 0x12]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_uint(n, 4).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_uint(&mut self, n: u64, nbytes: usize) -> Result<()> {
+        self.write_uint_bytes(n.into(), nbytes, mem::size_of::<u64>())
+    }
+
+    /// Writes a signed integer to the underlying writer using only `nbytes`
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=8`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in `nbytes` bytes, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = -2i64;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_int(n, 5)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_int(n, 5)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_int(-129, 1).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_int(&mut self, n: i64, nbytes: usize) -> Result<()> {
+        self.write_int_bytes(n.into(), nbytes, mem::size_of::<i64>())
+    }
+
+    /// Writes an unsigned 128-bit integer to the underlying writer using only
+    /// `nbytes` bytes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=16`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in `nbytes` bytes, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 0x12345678901234567890u128;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_uint128(n, 10)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56, 0x78, 0x90]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_uint128(n, 10)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![0x90, 0x78, 0x56, 0x34, 0x12, 0x90, 0x78, 0x56, 0x34, 0x12]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_uint128(&mut self, n: u128, nbytes: usize) -> Result<()> {
+        self.write_uint_bytes(n, nbytes, mem::size_of::<u128>())
+    }
+
+    /// Writes a signed 128-bit integer to the underlying writer using only
+    /// `nbytes` bytes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=16`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in `nbytes` bytes, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = -2i128;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_int128(n, 10)?;
+    ///     assert_eq!(
+    ///         be_writer.into_inner(),
+    ///         vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]
+    ///     );
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_int128(n, 10)?;
+    ///     assert_eq!(
+    ///         le_writer.into_inner(),
+    ///         vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_int128(&mut self, n: i128, nbytes: usize) -> Result<()> {
+        self.write_int_bytes(n, nbytes, mem::size_of::<i128>())
+    }
+
+    /// Writes an unsigned 24-bit integer to the underlying writer.
+    ///
+    /// This is equivalent to calling [`write_uint`] with `nbytes` set to `3`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in 24 bits, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 0x123456u32;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u24(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x12, 0x34, 0x56]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u24(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x56, 0x34, 0x12]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_u24(0x1000000).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_uint`]: NumberWriter::write_uint
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u24(&mut self, n: u32) -> Result<()> {
+        self.write_uint_bytes(n.into(), 3, 3)
+    }
+
+    /// Writes a signed 24-bit integer to the underlying writer.
+    ///
+    /// This is equivalent to calling [`write_int`] with `nbytes` set to `3`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in 24 bits, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = -2i32;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i24(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i24(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0xFE, 0xFF, 0xFF]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_i24(0x800000).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_int`]: NumberWriter::write_int
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i24(&mut self, n: i32) -> Result<()> {
+        self.write_int_bytes(n.into(), 3, 3)
+    }
+
+    /// Writes an unsigned 48-bit integer to the underlying writer.
+    ///
+    /// This is equivalent to calling [`write_uint`] with `nbytes` set to `6`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in 48 bits, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = 0x123456789012u64;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_u48(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0x12, 0x34, 0x56, 0x78, 0x90, 0x12]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_u48(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0x12, 0x90, 0x78, 0x56, 0x34, 0x12]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_u48(0x1000000000000).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_uint`]: NumberWriter::write_uint
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_u48(&mut self, n: u64) -> Result<()> {
+        self.write_uint_bytes(n.into(), 6, 6)
+    }
+
+    /// Writes a signed 48-bit integer to the underlying writer.
+    ///
+    /// This is equivalent to calling [`write_int`] with `nbytes` set to `6`.
+    ///
+    /// # Errors
+    ///
+    /// If `n` does not fit in 48 bits, an error of the kind
+    /// [`ErrorKind::InvalidInput`] is returned and nothing is written.
+    /// Otherwise, this method propagates any error recieved from the internal
+    /// call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use byte_order::{ByteOrder, NumberWriter};
+    ///
+    /// fn main() -> io::Result<()> {
+    ///     let n = -2i64;
+    ///
+    ///     let mut be_writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    ///     be_writer.write_i48(n)?;
+    ///     assert_eq!(be_writer.into_inner(), vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+    ///
+    ///     let mut le_writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    ///     le_writer.write_i48(n)?;
+    ///     assert_eq!(le_writer.into_inner(), vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    ///
+    ///     let mut writer = NumberWriter::new(vec![]);
+    ///     assert!(writer.write_i48(-0x800000000001).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`write_int`]: NumberWriter::write_int
+    /// [`ErrorKind::InvalidInput`]: crate::io::ErrorKind::InvalidInput
+    /// [`Write::write_all`]: Write::write_all
+    #[inline]
+    pub fn write_i48(&mut self, n: i64) -> Result<()> {
+        self.write_int_bytes(n.into(), 6, 6)
+    }
+
+    /// Writes `src` with `to_bytes`, converting the numbers in chunks through
+    /// a fixed-size buffer. The bytes produced by `to_bytes` must be an array.
+    #[inline]
+    fn write_chunked<T: Copy, B: AsRef<[u8]>>(
+        &mut self,
+        src: &[T],
+        to_bytes: impl Fn(T) -> B,
+    ) -> Result<()> {
+        let width = mem::size_of::<B>();
+        let mut buf = [0; SWAP_BUF_LEN];
+        for chunk in src.chunks(SWAP_BUF_LEN / width) {
+            let len = chunk.len() * width;
+            for (dst, &n) in buf[..len].chunks_exact_mut(width).zip(chunk) {
+                dst.copy_from_slice(to_bytes(n).as_ref());
+            }
+            self.write_raw(&buf[..len])?;
+        }
+        Ok(())
+    }
+
+    /// Writes all of `buf` to the underlying writer, counting the bytes if the
+    /// position is tracked.
+    #[inline]
+    fn write_raw(&mut self, buf: &[u8]) -> Result<()> {
+        if self.position.is_some() {
+            return self.write_raw_partial(buf);
+        }
+        self.inner.write_all(buf)
+    }
+
+    /// Writes all of `buf` to the underlying writer like [`write_raw`], but
+    /// counts the bytes that were written even if it fails, so that the
+    /// position stays in step with the underlying writer.
+    ///
+    /// [`write_raw`]: NumberWriter::write_raw
+    fn write_raw_partial(&mut self, buf: &[u8]) -> Result<()> {
+        let mut written = 0;
+        let result = loop {
+            if written == buf.len() {
+                break Ok(());
+            }
+            match self.inner.write(&buf[written..]) {
+                Ok(0) => {
+                    break Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => written += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.advance(written);
+        result
+    }
+
+    /// Adds `n` bytes to the position, if it is tracked.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        if let Some(position) = &mut self.position {
+            *position += n as u64;
+        }
+    }
+
+    /// Writes `n` as an unsigned LEB128 integer with a single call to
+    /// [`Write::write_all`].
+    #[inline]
+    fn write_uleb128(&mut self, n: u128) -> Result<()> {
+        let mut buf = [0; leb128::MAX_LEN];
+        let len = leb128::encode_unsigned(n, &mut buf);
+        self.write_raw(&buf[..len])
+    }
+
+    /// Writes `n` as a signed LEB128 integer with a single call to
+    /// [`Write::write_all`].
+    #[inline]
+    fn write_sleb128(&mut self, n: i128) -> Result<()> {
+        let mut buf = [0; leb128::MAX_LEN];
+        let len = leb128::encode_signed(n, &mut buf);
+        self.write_raw(&buf[..len])
+    }
+
+    /// Writes the low `nbytes` bytes of `n`, failing if the remaining bytes
+    /// are not zero.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=max`.
+    #[inline]
+    fn write_uint_bytes(&mut self, n: u128, nbytes: usize, max: usize) -> Result<()> {
+        assert!(
+            (1..=max).contains(&nbytes),
+            "nbytes must be in the range 1..={}, but was {}",
+            max,
+            nbytes
+        );
+        if nbytes < mem::size_of::<u128>() && n >> (8 * nbytes) != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "integer does not fit in the requested number of bytes",
+            ));
+        }
+        self.write_low_bytes(n, nbytes)
+    }
+
+    /// Writes the low `nbytes` bytes of `n`, failing if the remaining bytes
+    /// are not a sign extension of the written ones.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `nbytes` is not in the range `1..=max`.
+    #[inline]
+    fn write_int_bytes(&mut self, n: i128, nbytes: usize, max: usize) -> Result<()> {
+        assert!(
+            (1..=max).contains(&nbytes),
+            "nbytes must be in the range 1..={}, but was {}",
+            max,
+            nbytes
+        );
+        let shift = 128 - 8 * nbytes as u32;
+        if (n << shift) >> shift != n {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "integer does not fit in the requested number of bytes",
+            ));
+        }
+        self.write_low_bytes(n as u128, nbytes)
+    }
+
+    /// Writes the `nbytes` least significant bytes of `n`.
+    #[inline]
+    fn write_low_bytes(&mut self, n: u128, nbytes: usize) -> Result<()> {
+        match self.order.byte_order() {
+            ByteOrder::BE => self.write_raw(&n.to_be_bytes()[16 - nbytes..]),
+            ByteOrder::LE => self.write_raw(&n.to_le_bytes()[..nbytes]),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write, O: Endianness> std::io::Write for NumberWriter<W, O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.write_raw(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl<W: Write, O: Endianness> Write for NumberWriter<W, O> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.write_raw(buf)
     }
 
     fn flush(&mut self) -> Result<()> {
@@ -0,0 +1,99 @@
+//! Zigzag encoding of signed integers, as used by the `sint32` and `sint64`
+//! types of Protocol Buffers.
+//!
+//! Zigzag encoding maps signed integers to unsigned ones so that numbers with
+//! a small magnitude, whether positive or negative, map to small numbers:
+//! `0` maps to `0`, `-1` to `1`, `1` to `2`, `-2` to `3`, and so on. When the
+//! result is then encoded as unsigned LEB128, small negative numbers take as
+//! few bytes as small positive ones.
+//!
+//! [`NumberReader::read_zigzag_i32`] and [`NumberWriter::write_zigzag_i32`],
+//! along with their 64-bit counterparts, combine these functions with unsigned
+//! LEB128. The functions are `const`, so they can also be used to build
+//! encoded constants.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_order::zigzag;
+//!
+//! const MINUS_TWO: u32 = zigzag::encode_i32(-2);
+//! assert_eq!(3, MINUS_TWO);
+//! assert_eq!(-2, zigzag::decode_i32(MINUS_TWO));
+//!
+//! assert_eq!(u64::MAX, zigzag::encode_i64(i64::MIN));
+//! assert_eq!(i64::MAX, zigzag::decode_i64(u64::MAX - 1));
+//! ```
+//!
+//! [`NumberReader::read_zigzag_i32`]: crate::NumberReader::read_zigzag_i32
+//! [`NumberWriter::write_zigzag_i32`]: crate::NumberWriter::write_zigzag_i32
+
+/// Maps a signed 32-bit integer to an unsigned one with zigzag encoding.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::zigzag;
+///
+/// assert_eq!(0, zigzag::encode_i32(0));
+/// assert_eq!(1, zigzag::encode_i32(-1));
+/// assert_eq!(2, zigzag::encode_i32(1));
+/// assert_eq!(u32::MAX, zigzag::encode_i32(i32::MIN));
+/// ```
+#[inline]
+pub const fn encode_i32(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+/// Maps an unsigned 32-bit integer back to the signed one that it encodes
+/// with zigzag encoding.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::zigzag;
+///
+/// assert_eq!(0, zigzag::decode_i32(0));
+/// assert_eq!(-1, zigzag::decode_i32(1));
+/// assert_eq!(1, zigzag::decode_i32(2));
+/// assert_eq!(i32::MIN, zigzag::decode_i32(u32::MAX));
+/// ```
+#[inline]
+pub const fn decode_i32(n: u32) -> i32 {
+    ((n >> 1) as i32) ^ -((n & 1) as i32)
+}
+
+/// Maps a signed 64-bit integer to an unsigned one with zigzag encoding.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::zigzag;
+///
+/// assert_eq!(0, zigzag::encode_i64(0));
+/// assert_eq!(1, zigzag::encode_i64(-1));
+/// assert_eq!(2, zigzag::encode_i64(1));
+/// assert_eq!(u64::MAX, zigzag::encode_i64(i64::MIN));
+/// ```
+#[inline]
+pub const fn encode_i64(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+/// Maps an unsigned 64-bit integer back to the signed one that it encodes
+/// with zigzag encoding.
+///
+/// # Examples
+///
+/// ```
+/// use byte_order::zigzag;
+///
+/// assert_eq!(0, zigzag::decode_i64(0));
+/// assert_eq!(-1, zigzag::decode_i64(1));
+/// assert_eq!(1, zigzag::decode_i64(2));
+/// assert_eq!(i64::MIN, zigzag::decode_i64(u64::MAX));
+/// ```
+#[inline]
+pub const fn decode_i64(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
@@ -0,0 +1,177 @@
+//! Framing of partial, oversized, and adjusted frames by the length-prefixed
+//! codec, in both directions.
+
+#![cfg(feature = "codec")]
+
+use std::io::ErrorKind;
+
+use byte_order::codec::LengthPrefixedCodec;
+use byte_order::{ByteOrder, LengthPrefix};
+use bytes::{Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+fn be_codec(length_prefix: LengthPrefix) -> LengthPrefixedCodec {
+    let mut codec = LengthPrefixedCodec::with_order(ByteOrder::BE);
+    codec.set_length_prefix(length_prefix);
+    codec
+}
+
+fn encode(codec: &mut LengthPrefixedCodec, frame: &[u8]) -> std::io::Result<BytesMut> {
+    let mut dst = BytesMut::new();
+    codec.encode(frame, &mut dst)?;
+    Ok(dst)
+}
+
+#[test]
+fn waits_for_partial_headers() {
+    for &(length_prefix, ref bytes) in &[
+        (LengthPrefix::U16, vec![0x00, 0x02, 0xAB, 0xCD]),
+        (LengthPrefix::U32, vec![0x00, 0x00, 0x00, 0x02, 0xAB, 0xCD]),
+        (
+            LengthPrefix::U64,
+            vec![0, 0, 0, 0, 0, 0, 0, 0x02, 0xAB, 0xCD],
+        ),
+        (LengthPrefix::Uleb128, vec![0x82, 0x00, 0xAB, 0xCD]),
+    ] {
+        let mut codec = be_codec(length_prefix);
+        let header_len = bytes.len() - 2;
+        for len in 0..header_len {
+            let mut src = BytesMut::from(&bytes[..len]);
+            assert_eq!(None, codec.decode(&mut src).unwrap(), "{:?}", length_prefix);
+            assert_eq!(&bytes[..len], &src[..], "{:?}", length_prefix);
+        }
+        let mut src = BytesMut::from(&bytes[..]);
+        assert_eq!(&[0xAB, 0xCD], &codec.decode(&mut src).unwrap().unwrap()[..]);
+        assert!(src.is_empty());
+    }
+}
+
+#[test]
+fn reserves_space_for_partial_bodies() {
+    let mut codec = be_codec(LengthPrefix::U16);
+    let mut src = BytesMut::from(&[0x01, 0x00, 0xAA][..]);
+    assert_eq!(None, codec.decode(&mut src).unwrap());
+    assert_eq!(&[0x01, 0x00, 0xAA], &src[..]);
+    assert!(src.capacity() >= 2 + 0x100, "{}", src.capacity());
+
+    src.extend_from_slice(&[0xBB; 0xFF]);
+    src.extend_from_slice(&[0x00]);
+    let frame = codec.decode(&mut src).unwrap().unwrap();
+    assert_eq!(0x100, frame.len());
+    assert_eq!(&[0x00], &src[..]);
+}
+
+#[test]
+fn decodes_consecutive_frames() {
+    let mut codec = be_codec(LengthPrefix::U8);
+    let mut src = BytesMut::from(&[0x01, 0xAA, 0x00, 0x02, 0xBB, 0xCC][..]);
+    assert_eq!(&[0xAA], &codec.decode(&mut src).unwrap().unwrap()[..]);
+    assert!(codec.decode(&mut src).unwrap().unwrap().is_empty());
+    assert_eq!(&[0xBB, 0xCC], &codec.decode(&mut src).unwrap().unwrap()[..]);
+    assert_eq!(None, codec.decode(&mut src).unwrap());
+}
+
+#[test]
+fn rejects_frames_over_the_maximum() {
+    let mut codec = be_codec(LengthPrefix::U32);
+    codec.set_max_frame_len(4);
+
+    let mut src = BytesMut::from(&[0x00, 0x00, 0x00, 0x04, 1, 2, 3, 4][..]);
+    assert_eq!(4, codec.decode(&mut src).unwrap().unwrap().len());
+
+    // The header alone is enough to reject a frame.
+    let mut src = BytesMut::from(&[0x00, 0x00, 0x00, 0x05][..]);
+    let err = codec.decode(&mut src).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+
+    let mut src = BytesMut::from(&[0xFF, 0xFF, 0xFF, 0xFF][..]);
+    let err = codec.decode(&mut src).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+
+    assert_eq!(8, encode(&mut codec, &[1, 2, 3, 4]).unwrap().len());
+    let err = encode(&mut codec, &[1, 2, 3, 4, 5]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn adjusts_lengths() {
+    // A length prefix which also counts itself.
+    let mut codec = be_codec(LengthPrefix::U16);
+    codec.set_length_adjustment(-2);
+    let dst = encode(&mut codec, b"ping").unwrap();
+    assert_eq!(&[0x00, 0x06, b'p', b'i', b'n', b'g'], &dst[..]);
+    let mut src = dst;
+    assert_eq!(&b"ping"[..], &codec.decode(&mut src).unwrap().unwrap()[..]);
+
+    let mut src = BytesMut::from(&[0x00, 0x01][..]);
+    let err = codec.decode(&mut src).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+
+    // A length prefix which counts a trailer that is part of the payload.
+    let mut codec = be_codec(LengthPrefix::U8);
+    codec.set_length_adjustment(2);
+    let dst = encode(&mut codec, b"ping").unwrap();
+    assert_eq!(&[0x02, b'p', b'i', b'n', b'g'], &dst[..]);
+    let mut src = dst;
+    assert_eq!(&b"ping"[..], &codec.decode(&mut src).unwrap().unwrap()[..]);
+
+    let err = encode(&mut codec, b"p").unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+
+    let mut src = BytesMut::from(&[0xFF][..]);
+    assert_eq!(None, codec.decode(&mut src).unwrap());
+    assert!(src.capacity() >= 1 + 0xFF + 2);
+}
+
+#[test]
+fn rejects_lengths_too_large_for_the_prefix() {
+    let mut codec = be_codec(LengthPrefix::U8);
+    assert_eq!(256, encode(&mut codec, &[0; 255]).unwrap().len());
+
+    let mut dst = BytesMut::new();
+    let err = codec
+        .encode(Bytes::from(vec![0; 256]), &mut dst)
+        .unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+    assert!(dst.is_empty());
+}
+
+#[test]
+fn checks_uleb128_prefixes() {
+    let mut codec = be_codec(LengthPrefix::Uleb128);
+    assert_eq!(&[0xAC, 0x02], &encode(&mut codec, &[0; 300]).unwrap()[..2]);
+
+    let mut src = BytesMut::from(&[0x81, 0x00, 0xAA][..]);
+    assert_eq!(&[0xAA], &codec.decode(&mut src).unwrap().unwrap()[..]);
+
+    codec.set_strict(true);
+    let mut src = BytesMut::from(&[0x81, 0x00, 0xAA][..]);
+    let err = codec.decode(&mut src).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+
+    for &strict in &[false, true] {
+        codec.set_strict(strict);
+        let mut src = BytesMut::from(&[0x80; 11][..]);
+        let err = codec.decode(&mut src).unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+    }
+}
+
+#[test]
+fn encodes_bytes_and_slices_alike() {
+    for &order in &[ByteOrder::BE, ByteOrder::LE] {
+        let mut codec = LengthPrefixedCodec::with_order(order);
+        let mut from_bytes = BytesMut::new();
+        codec
+            .encode(Bytes::from_static(b"pong"), &mut from_bytes)
+            .unwrap();
+        let from_slice = encode(&mut codec, b"pong").unwrap();
+        assert_eq!(from_bytes, from_slice);
+
+        let len = match order {
+            ByteOrder::BE => [0x00, 0x00, 0x00, 0x04],
+            ByteOrder::LE => [0x04, 0x00, 0x00, 0x00],
+        };
+        assert_eq!(&len, &from_slice[..4]);
+    }
+}
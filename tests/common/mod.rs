@@ -0,0 +1,11 @@
+//! Helpers shared by the integration tests.
+
+/// Unsigned values at and around every power of two, including both extremes.
+pub fn boundaries_u64() -> Vec<u64> {
+    let mut values = vec![0, 1, u64::MAX - 1, u64::MAX];
+    for shift in 1..64 {
+        let power = 1u64 << shift;
+        values.extend(&[power - 1, power, power + 1]);
+    }
+    values
+}
@@ -0,0 +1,326 @@
+//! Round trips of derived `Decode` and `Encode` implementations through every
+//! attribute, and the errors they return for data they cannot represent.
+
+#![cfg(all(feature = "derive", feature = "std"))]
+
+use std::fmt::Debug;
+use std::io::{self, Cursor, ErrorKind};
+
+use byte_order::{ByteOrder, Decode, Encode, NumberReader, NumberWriter};
+
+fn encode<T: Encode>(order: ByteOrder, value: &T) -> io::Result<Vec<u8>> {
+    let mut writer = NumberWriter::with_order(order, vec![]);
+    writer.encode(value)?;
+    Ok(writer.into_inner())
+}
+
+fn decode<T: Decode>(order: ByteOrder, bytes: &[u8]) -> io::Result<T> {
+    let mut reader = NumberReader::with_order(order, Cursor::new(bytes));
+    let value = reader.decode()?;
+    assert_eq!(bytes.len() as u64, reader.into_inner().position());
+    Ok(value)
+}
+
+/// Checks that `value` is written as `bytes`, and read back from them.
+fn round_trip<T>(order: ByteOrder, value: T, bytes: &[u8])
+where
+    T: Decode + Encode + Debug + PartialEq,
+{
+    assert_eq!(bytes, &encode(order, &value).unwrap()[..], "{:?}", value);
+    assert_eq!(value, decode::<T>(order, bytes).unwrap());
+}
+
+macro_rules! tagged_enum {
+    ($name:ident, $tag:literal) => {
+        #[derive(Debug, Decode, Encode, PartialEq)]
+        #[byte_order(tag = $tag)]
+        #[repr(u8)]
+        enum $name {
+            A,
+            B(u8),
+            C = 0x80,
+            D { x: u8 },
+        }
+    };
+}
+
+tagged_enum!(Tag8, "u8");
+tagged_enum!(Tag16, "u16");
+tagged_enum!(Tag32, "u32");
+tagged_enum!(Tag64, "u64");
+tagged_enum!(TagLeb, "uleb128");
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+enum DefaultTag {
+    A,
+    B(u8),
+}
+
+#[test]
+fn writes_tags_with_each_width() {
+    let be = ByteOrder::BE;
+    let le = ByteOrder::LE;
+
+    round_trip(be, Tag8::A, &[0x00]);
+    round_trip(be, Tag8::B(7), &[0x01, 0x07]);
+    round_trip(be, Tag8::C, &[0x80]);
+    round_trip(be, Tag8::D { x: 7 }, &[0x81, 0x07]);
+
+    round_trip(be, Tag16::B(7), &[0x00, 0x01, 0x07]);
+    round_trip(le, Tag16::C, &[0x80, 0x00]);
+    round_trip(be, Tag16::D { x: 7 }, &[0x00, 0x81, 0x07]);
+
+    round_trip(be, Tag32::B(7), &[0, 0, 0, 0x01, 0x07]);
+    round_trip(le, Tag32::D { x: 7 }, &[0x81, 0, 0, 0, 0x07]);
+    round_trip(be, DefaultTag::B(7), &[0, 0, 0, 0x01, 0x07]);
+
+    round_trip(be, Tag64::A, &[0; 8]);
+    round_trip(le, Tag64::C, &[0x80, 0, 0, 0, 0, 0, 0, 0]);
+
+    // The byte order does not apply to LEB128 tags.
+    for &order in &[be, le] {
+        round_trip(order, TagLeb::B(7), &[0x01, 0x07]);
+        round_trip(order, TagLeb::C, &[0x80, 0x01]);
+        round_trip(order, TagLeb::D { x: 7 }, &[0x81, 0x01, 0x07]);
+    }
+}
+
+#[test]
+fn rejects_unknown_tags() {
+    let be = ByteOrder::BE;
+
+    let err = decode::<Tag8>(be, &[0x02]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert_eq!("Tag8: unknown tag 2", err.to_string());
+
+    let err = decode::<Tag16>(be, &[0x01, 0x00]).unwrap_err();
+    assert_eq!("Tag16: unknown tag 256", err.to_string());
+
+    let err = decode::<TagLeb>(be, &[0xFF, 0x7F]).unwrap_err();
+    assert_eq!("TagLeb: unknown tag 16383", err.to_string());
+
+    let err = decode::<Tag64>(be, &[0, 0, 0, 0]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(err.to_string().starts_with("Tag64: "), "{}", err);
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct Padded {
+    #[byte_order(pad_before = 1)]
+    a: u8,
+    #[byte_order(pad_after = 2)]
+    b: u8,
+    #[byte_order(pad_before = 1, pad_after = 1)]
+    c: u8,
+}
+
+#[test]
+fn pads_fields() {
+    let value = Padded { a: 1, b: 2, c: 3 };
+    round_trip(ByteOrder::BE, value, &[0, 1, 2, 0, 0, 0, 3, 0]);
+
+    // Padding is skipped whatever it holds.
+    let value: Padded = decode(ByteOrder::BE, &[9, 1, 2, 9, 9, 9, 3, 9]).unwrap();
+    assert_eq!(Padded { a: 1, b: 2, c: 3 }, value);
+
+    let err = decode::<Padded>(ByteOrder::BE, &[0, 1, 2, 0]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(err.to_string().starts_with("Padded.b: "), "{}", err);
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct Mixed {
+    native: u16,
+    #[byte_order(order = "BE")]
+    big: u16,
+    #[byte_order(order = "LE")]
+    little: u16,
+    #[byte_order(length_prefix = "u8")]
+    name: String,
+    #[byte_order(length_prefix = "uleb128")]
+    data: Vec<u16>,
+    tail: Vec<u8>,
+}
+
+#[test]
+fn overrides_order_and_length_prefix_per_field() {
+    let value = || Mixed {
+        native: 0x0102,
+        big: 0x0304,
+        little: 0x0506,
+        name: String::from("hi"),
+        data: vec![0x0708],
+        tail: vec![0x09],
+    };
+    round_trip(
+        ByteOrder::BE,
+        value(),
+        &[
+            0x01, 0x02, 0x03, 0x04, 0x06, 0x05, 0x02, b'h', b'i', 0x01, 0x07, 0x08, 0, 0, 0, 1,
+            0x09,
+        ],
+    );
+    round_trip(
+        ByteOrder::LE,
+        value(),
+        &[
+            0x02, 0x01, 0x03, 0x04, 0x06, 0x05, 0x02, b'h', b'i', 0x01, 0x08, 0x07, 1, 0, 0, 0,
+            0x09,
+        ],
+    );
+
+    let mut long = value();
+    long.name = "x".repeat(256);
+    let err = encode(ByteOrder::BE, &long).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+    assert!(err.to_string().starts_with("Mixed.name: "), "{}", err);
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+#[byte_order(magic = 0xCAFE_u16)]
+struct Versioned {
+    #[byte_order(magic = 2)]
+    version: u8,
+    #[byte_order(magic = b"OK")]
+    status: [u8; 2],
+    len: u8,
+}
+
+#[test]
+fn checks_magic() {
+    let value = || Versioned {
+        version: 2,
+        status: *b"OK",
+        len: 1,
+    };
+    round_trip(ByteOrder::BE, value(), &[0xCA, 0xFE, 2, b'O', b'K', 1]);
+    round_trip(ByteOrder::LE, value(), &[0xFE, 0xCA, 2, b'O', b'K', 1]);
+
+    let err = decode::<Versioned>(ByteOrder::LE, &[0xCA, 0xFE, 2, b'O', b'K', 1]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "Versioned: invalid magic, expected 51966 but found 65226",
+        err.to_string()
+    );
+
+    let err = decode::<Versioned>(ByteOrder::BE, &[0xCA, 0xFE, 3, b'O', b'K', 1]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "Versioned.version: invalid magic, expected 2 but found 3",
+        err.to_string()
+    );
+
+    let err = decode::<Versioned>(ByteOrder::BE, &[0xCA, 0xFE, 2, b'N', b'O', 1]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert!(err.to_string().starts_with("Versioned.status: "), "{}", err);
+
+    // A field is never written with a value other than its magic.
+    let mut wrong = value();
+    wrong.version = 3;
+    let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    let err = writer.encode(&wrong).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+    assert_eq!(
+        "Versioned.version: invalid magic, expected 2 but found 3",
+        err.to_string()
+    );
+    assert_eq!(vec![0xCA, 0xFE], writer.into_inner());
+
+    let mut wrong = value();
+    wrong.status = *b"NO";
+    let err = encode(ByteOrder::BE, &wrong).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+    assert!(err.to_string().starts_with("Versioned.status: "), "{}", err);
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+#[byte_order(tag = "u8")]
+enum Maybe<T> {
+    Nothing,
+    Just(T),
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct Unit;
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+struct Wrapper(u16, Unit, Pair<u8, Maybe<u16>>);
+
+#[test]
+fn derives_generic_types() {
+    let be = ByteOrder::BE;
+    round_trip(
+        be,
+        Pair {
+            first: 1u8,
+            second: 0x0203u16,
+        },
+        &[1, 2, 3],
+    );
+    round_trip(be, Maybe::<u32>::Nothing, &[0]);
+    round_trip(be, Maybe::Just(0x0102u16), &[1, 1, 2]);
+    round_trip(be, Unit, &[]);
+    round_trip(
+        ByteOrder::LE,
+        Wrapper(
+            0x0102,
+            Unit,
+            Pair {
+                first: 3,
+                second: Maybe::Just(0x0405),
+            },
+        ),
+        &[2, 1, 3, 1, 5, 4],
+    );
+}
+
+#[derive(Debug, Decode, Encode, PartialEq)]
+#[byte_order(tag = "u8")]
+enum Shape {
+    Point,
+    Circle { radius: u16 },
+}
+
+#[test]
+fn names_fields_in_errors() {
+    let be = ByteOrder::BE;
+
+    let err = decode::<Shape>(be, &[1, 0]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(
+        err.to_string().starts_with("Shape::Circle.radius: "),
+        "{}",
+        err
+    );
+
+    let err = decode::<Pair<u8, Shape>>(be, &[1]).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(
+        err.to_string().starts_with("Pair.second: Shape: "),
+        "{}",
+        err
+    );
+
+    let err = decode::<Wrapper>(be, &[0, 1, 2, 3]).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert_eq!(
+        "Wrapper.2: Pair.second: Maybe: unknown tag 3",
+        err.to_string()
+    );
+
+    let mut buf = [0; 2];
+    let mut writer = NumberWriter::with_order(be, &mut buf[..]);
+    let err = writer.encode(&Shape::Circle { radius: 1 }).unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+    assert!(
+        err.to_string().starts_with("Shape::Circle.radius: "),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,191 @@
+//! Rounding, overflow, subnormal, and special values of the conversions
+//! between `f32` and the 16-bit floating point formats.
+
+#![cfg(feature = "std")]
+
+use byte_order::{ByteOrder, NumberReader, NumberWriter};
+
+/// `f32` values and the bits of the nearest half-precision float.
+fn f16_narrowings() -> Vec<(f32, u16)> {
+    let tiny = 2f32.powi(-24);
+    vec![
+        (1.0, 0x3C00),
+        (-2.0, 0xC000),
+        (65504.0, 0x7BFF),
+        // Below the midpoint between the largest value and the next power of
+        // two, which rounds down, and at it, which overflows to infinity.
+        (65519.0, 0x7BFF),
+        (65520.0, 0x7C00),
+        (-65520.0, 0xFC00),
+        (f32::MAX, 0x7C00),
+        // Ties round to even.
+        (1.0 + 2f32.powi(-11), 0x3C00),
+        (1.0 + 3.0 * 2f32.powi(-11), 0x3C02),
+        (2f32.powi(-14), 0x0400),
+        // Subnormals, where half of the smallest one is a tie with zero.
+        (tiny, 0x0001),
+        (tiny / 2.0, 0x0000),
+        (-tiny / 2.0, 0x8000),
+        (tiny * 0.75, 0x0001),
+        (tiny * 1.5, 0x0002),
+        (tiny * 2.5, 0x0002),
+        (tiny * 1023.0, 0x03FF),
+        // Rounding the largest subnormal up carries into the smallest normal.
+        (tiny * 1023.5, 0x0400),
+        (tiny / 4.0, 0x0000),
+        (f32::from_bits(1), 0x0000),
+        (0.0, 0x0000),
+        (-0.0, 0x8000),
+        (f32::INFINITY, 0x7C00),
+        (f32::NEG_INFINITY, 0xFC00),
+    ]
+}
+
+/// Bits of half-precision floats and the `f32` values they widen to, which
+/// are exact.
+fn f16_widenings() -> Vec<(u16, f32)> {
+    let tiny = 2f32.powi(-24);
+    vec![
+        (0x3C00, 1.0),
+        (0xC000, -2.0),
+        (0x7BFF, 65504.0),
+        (0x0400, 2f32.powi(-14)),
+        (0x0001, tiny),
+        (0x8001, -tiny),
+        (0x03FF, tiny * 1023.0),
+        (0x0000, 0.0),
+        (0x8000, -0.0),
+        (0x7C00, f32::INFINITY),
+        (0xFC00, f32::NEG_INFINITY),
+    ]
+}
+
+/// `f32` values and the bits of the nearest brain floating point number.
+fn bf16_narrowings() -> Vec<(f32, u16)> {
+    vec![
+        (1.0, 0x3F80),
+        (f32::from_bits(0x3F80_7FFF), 0x3F80),
+        // Ties round to even.
+        (f32::from_bits(0x3F80_8000), 0x3F80),
+        (f32::from_bits(0x3F81_8000), 0x3F82),
+        (f32::from_bits(0x3F80_8001), 0x3F81),
+        (f32::MAX, 0x7F80),
+        (f32::MIN, 0xFF80),
+        (f32::from_bits(1), 0x0000),
+        (-0.0, 0x8000),
+        (f32::INFINITY, 0x7F80),
+        (f32::NEG_INFINITY, 0xFF80),
+    ]
+}
+
+fn bf16_widenings() -> Vec<(u16, f32)> {
+    vec![
+        (0x3F80, 1.0),
+        (0xC2F7, -123.5),
+        (0x0001, f32::from_bits(0x0001_0000)),
+        (0x8000, -0.0),
+        (0x7F80, f32::INFINITY),
+        (0xFF80, f32::NEG_INFINITY),
+    ]
+}
+
+/// NaNs with and without a payload in the bits that survive narrowing, and
+/// with either sign.
+fn nans() -> Vec<f32> {
+    vec![
+        f32::NAN,
+        -f32::NAN,
+        f32::from_bits(0x7F80_0001),
+        f32::from_bits(0xFF80_0001),
+        f32::from_bits(0x7FBF_FFFF),
+    ]
+}
+
+fn narrow(n: f32, bf16: bool) -> u16 {
+    let mut writer = NumberWriter::with_order(ByteOrder::BE, vec![]);
+    if bf16 {
+        writer.write_f32_as_bf16(n).unwrap();
+    } else {
+        writer.write_f32_as_f16(n).unwrap();
+    }
+    let bytes = writer.into_inner();
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn widen(bits: u16, bf16: bool) -> f32 {
+    let bytes = bits.to_le_bytes();
+    let mut reader = NumberReader::with_order(ByteOrder::LE, &bytes[..]);
+    if bf16 {
+        reader.read_bf16_as_f32().unwrap()
+    } else {
+        reader.read_f16_as_f32().unwrap()
+    }
+}
+
+#[test]
+fn narrows_to_f16() {
+    for (n, bits) in f16_narrowings() {
+        assert_eq!(bits, narrow(n, false), "{:e} ({:#010X})", n, n.to_bits());
+    }
+
+    let (src, expected): (Vec<f32>, Vec<u16>) = f16_narrowings().into_iter().unzip();
+    let mut writer = NumberWriter::with_order(ByteOrder::LE, vec![]);
+    writer.write_f32_as_f16_slice(&src).unwrap();
+    let expected: Vec<u8> = expected.iter().flat_map(|n| n.to_le_bytes()).collect();
+    assert_eq!(expected, writer.into_inner());
+}
+
+#[test]
+fn widens_from_f16() {
+    for (bits, n) in f16_widenings() {
+        assert_eq!(n.to_bits(), widen(bits, false).to_bits(), "{:#06X}", bits);
+        assert_eq!(bits, narrow(n, false), "{:#06X}", bits);
+    }
+
+    let (bits, expected): (Vec<u16>, Vec<f32>) = f16_widenings().into_iter().unzip();
+    let src: Vec<u8> = bits.iter().flat_map(|n| n.to_be_bytes()).collect();
+    let mut reader = NumberReader::with_order(ByteOrder::BE, &src[..]);
+    let mut dst = vec![0.0; bits.len()];
+    reader.read_f16_as_f32_into(&mut dst).unwrap();
+    let dst: Vec<u32> = dst.iter().map(|n| n.to_bits()).collect();
+    let expected: Vec<u32> = expected.iter().map(|n| n.to_bits()).collect();
+    assert_eq!(expected, dst);
+}
+
+#[test]
+fn narrows_to_bf16() {
+    for (n, bits) in bf16_narrowings() {
+        assert_eq!(bits, narrow(n, true), "{:e} ({:#010X})", n, n.to_bits());
+    }
+}
+
+#[test]
+fn widens_from_bf16() {
+    for (bits, n) in bf16_widenings() {
+        assert_eq!(n.to_bits(), widen(bits, true).to_bits(), "{:#06X}", bits);
+        assert_eq!(bits, narrow(n, true), "{:#06X}", bits);
+    }
+}
+
+#[test]
+fn keeps_nans_quiet() {
+    for n in nans() {
+        let sign = (n.to_bits() >> 16) as u16 & 0x8000;
+
+        let bits = narrow(n, false);
+        assert_eq!(0x7C00, bits & 0x7C00, "{:#010X}", n.to_bits());
+        assert_eq!(0x0200, bits & 0x0200, "{:#010X}", n.to_bits());
+        assert_eq!(sign, bits & 0x8000, "{:#010X}", n.to_bits());
+        assert!(widen(bits, false).is_nan(), "{:#06X}", bits);
+
+        let bits = narrow(n, true);
+        assert_eq!(0x7FC0, bits & 0x7FC0, "{:#010X}", n.to_bits());
+        assert_eq!(sign, bits & 0x8000, "{:#010X}", n.to_bits());
+        assert!(widen(bits, true).is_nan(), "{:#06X}", bits);
+    }
+
+    // A signaling NaN keeps its payload when widened.
+    assert_eq!(0x7FA0_0000, widen(0x7D00, false).to_bits());
+    assert_eq!(0x7F81_0000, widen(0x7F81, true).to_bits());
+    assert!(widen(0x7C01, false).is_nan());
+}
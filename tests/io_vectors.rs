@@ -0,0 +1,1126 @@
+//! Test vectors shared by every I/O model: the synchronous `NumberReader` and
+//! `NumberWriter`, and the asynchronous readers and writers for `tokio` and
+//! `futures-io`. Each vector is read and written through every model that is
+//! enabled, so their behavior cannot drift apart.
+
+#![cfg(feature = "std")]
+
+use std::io::ErrorKind;
+
+use byte_order::varint::{self, Vlq};
+use byte_order::{ByteOrder, LengthPrefix};
+
+/// A value of one of the types that the readers and writers support, which
+/// also selects the method used to read or write it.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F32(f32),
+    F64(f64),
+    U16s(Vec<u16>),
+    I32s(Vec<i32>),
+    U128s(Vec<u128>),
+    F64s(Vec<f64>),
+    Uleb128U32(u32),
+    Uleb128U64(u64),
+    Uleb128U128(u128),
+    Sleb128I32(i32),
+    Sleb128I64(i64),
+    Sleb128I128(i128),
+    ZigzagI32(i32),
+    ZigzagI64(i64),
+    F16(f32),
+    Bf16(f32),
+    F16s(Vec<f32>),
+    Bf16s(Vec<f32>),
+    Uint(u64, usize),
+    Int(i64, usize),
+    Uint128(u128, usize),
+    Int128(i128, usize),
+    U24(u32),
+    I24(i32),
+    U48(u64),
+    I48(i64),
+    Length(usize, LengthPrefix),
+    Quic(u64),
+    Sqlite(u64),
+    CompactSize(u64),
+    Midi(u64),
+}
+
+/// A value and its encoding in a byte order.
+struct Vector {
+    order: ByteOrder,
+    bytes: Vec<u8>,
+    value: Value,
+}
+
+/// An encoding that reading a value must reject.
+struct ReadError {
+    order: ByteOrder,
+    strict: bool,
+    bytes: Vec<u8>,
+    value: Value,
+    kind: ErrorKind,
+}
+
+/// A value that writing must reject without writing anything.
+struct WriteError {
+    value: Value,
+    kind: ErrorKind,
+}
+
+fn vector(order: ByteOrder, bytes: &[u8], value: Value) -> Vector {
+    Vector {
+        order,
+        bytes: bytes.to_vec(),
+        value,
+    }
+}
+
+fn vectors() -> Vec<Vector> {
+    use ByteOrder::{BE, LE};
+    use Value::*;
+
+    vec![
+        vector(BE, &[0xA1], U8(0xA1)),
+        vector(LE, &[0xFE], I8(-2)),
+        vector(BE, &[0x12, 0x34], U16(0x1234)),
+        vector(LE, &[0x12, 0x34], U16(0x3412)),
+        vector(BE, &[0xFF, 0xFE], I16(-2)),
+        vector(LE, &[0xFE, 0xFF], I16(-2)),
+        vector(BE, &[0x12, 0x34, 0x56, 0x78], U32(0x1234_5678)),
+        vector(LE, &[0x12, 0x34, 0x56, 0x78], U32(0x7856_3412)),
+        vector(BE, &[0x80, 0, 0, 0], I32(i32::MIN)),
+        vector(LE, &[0, 0, 0, 0x80], I32(i32::MIN)),
+        vector(BE, &[1, 2, 3, 4, 5, 6, 7, 8], U64(0x0102_0304_0506_0708)),
+        vector(LE, &[1, 2, 3, 4, 5, 6, 7, 8], U64(0x0807_0605_0403_0201)),
+        vector(BE, &[0xFF; 8], I64(-1)),
+        vector(
+            BE,
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+            U128(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10),
+        ),
+        vector(
+            LE,
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+            U128(0x100F_0E0D_0C0B_0A09_0807_0605_0403_0201),
+        ),
+        vector(
+            LE,
+            &[
+                0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                0xFF, 0xFF,
+            ],
+            I128(-2),
+        ),
+        vector(BE, &[0x3F, 0xC0, 0, 0], F32(1.5)),
+        vector(LE, &[0, 0, 0xC0, 0x3F], F32(1.5)),
+        vector(BE, &[0xC0, 0x04, 0, 0, 0, 0, 0, 0], F64(-2.5)),
+        vector(LE, &[0, 0, 0, 0, 0, 0, 0x04, 0xC0], F64(-2.5)),
+        vector(BE, &[], U16s(vec![])),
+        vector(BE, &[0x12, 0x34, 0x56, 0x78], U16s(vec![0x1234, 0x5678])),
+        vector(LE, &[0x12, 0x34, 0x56, 0x78], U16s(vec![0x3412, 0x7856])),
+        vector(BE, &[0xFF, 0xFF, 0xFF, 0xFE, 0, 0, 0, 1], I32s(vec![-2, 1])),
+        vector(LE, &[0xFE, 0xFF, 0xFF, 0xFF, 1, 0, 0, 0], I32s(vec![-2, 1])),
+        // Long enough to span several of the asynchronous writers' chunks.
+        vector(BE, &u128_slice_bytes(BE), U128s((0..9).collect())),
+        vector(LE, &u128_slice_bytes(LE), U128s((0..9).collect())),
+        vector(BE, &[0x3F, 0xF8, 0, 0, 0, 0, 0, 0], F64s(vec![1.5])),
+        vector(BE, &[0x00], Uleb128U32(0)),
+        vector(BE, &[0xE5, 0x8E, 0x26], Uleb128U32(624_485)),
+        vector(LE, &[0xFF, 0xFF, 0xFF, 0xFF, 0x0F], Uleb128U32(u32::MAX)),
+        vector(
+            BE,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            Uleb128U64(u64::MAX),
+        ),
+        vector(
+            BE,
+            &[
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                0xFF, 0xFF, 0xFF, 0xFF, 0x03,
+            ],
+            Uleb128U128(u128::MAX),
+        ),
+        vector(BE, &[0x7F], Sleb128I32(-1)),
+        vector(BE, &[0xC0, 0xBB, 0x78], Sleb128I32(-123_456)),
+        vector(BE, &[0x80, 0x80, 0x80, 0x80, 0x78], Sleb128I32(i32::MIN)),
+        vector(
+            BE,
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F],
+            Sleb128I64(i64::MIN),
+        ),
+        vector(BE, &[0x3F], Sleb128I128(63)),
+        vector(BE, &[0x03], ZigzagI32(-2)),
+        vector(LE, &[0xFE, 0xFF, 0xFF, 0xFF, 0x0F], ZigzagI32(i32::MAX)),
+        vector(
+            BE,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ZigzagI64(i64::MIN),
+        ),
+        vector(BE, &[0x4A, 0x40], F16(12.5)),
+        vector(LE, &[0x40, 0x4A], F16(12.5)),
+        vector(BE, &[0x3F, 0xC0], Bf16(1.5)),
+        vector(LE, &[0xC0, 0x3F], Bf16(1.5)),
+        vector(BE, &[0x4A, 0x40, 0xBA, 0x00], F16s(vec![12.5, -0.75])),
+        vector(LE, &f16_slice_bytes(), F16s(vec![1.0; 40])),
+        vector(BE, &[0x3F, 0xC0, 0xBF, 0x40], Bf16s(vec![1.5, -0.75])),
+        vector(BE, &[0x12, 0x34, 0x56, 0x78, 0x90], Uint(0x12_3456_7890, 5)),
+        vector(LE, &[0x12, 0x34, 0x56, 0x78, 0x90], Uint(0x90_7856_3412, 5)),
+        vector(BE, &[0xFF, 0xFF, 0xFE], Int(-2, 3)),
+        vector(LE, &[0xFE, 0xFF, 0xFF], Int(-2, 3)),
+        vector(BE, &[0x01; 11], Uint128(0x01_0101_0101_0101_0101_0101, 11)),
+        vector(LE, &[0xFF; 9], Int128(-1, 9)),
+        vector(BE, &[0x12, 0x34, 0x56], U24(0x12_3456)),
+        vector(LE, &[0x12, 0x34, 0x56], U24(0x56_3412)),
+        vector(BE, &[0x80, 0x00, 0x00], I24(-0x80_0000)),
+        vector(LE, &[1, 2, 3, 4, 5, 6], U48(0x0605_0403_0201)),
+        vector(BE, &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE], I48(-2)),
+        vector(BE, &[0x03], Length(3, LengthPrefix::U8)),
+        vector(LE, &[0x03, 0x00], Length(3, LengthPrefix::U16)),
+        vector(
+            BE,
+            &[0x00, 0x00, 0x01, 0x00],
+            Length(256, LengthPrefix::U32),
+        ),
+        vector(
+            LE,
+            &[0x05, 0, 0, 0, 0, 0, 0, 0],
+            Length(5, LengthPrefix::U64),
+        ),
+        vector(BE, &[0xAC, 0x02], Length(300, LengthPrefix::Uleb128)),
+        vector(BE, &[0x25], Quic(37)),
+        vector(LE, &[0x7B, 0xBD], Quic(15_293)),
+        vector(BE, &[0x9D, 0x7F, 0x3E, 0x7D], Quic(494_878_333)),
+        vector(
+            LE,
+            &[0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C],
+            Quic(151_288_809_941_952_652),
+        ),
+        vector(BE, &[0x7F], Sqlite(0x7F)),
+        vector(LE, &[0x81, 0x00], Sqlite(0x80)),
+        vector(BE, &[0xFF; 9], Sqlite(u64::MAX)),
+        vector(BE, &[0xFC], CompactSize(252)),
+        vector(BE, &[0xFD, 0x03, 0x02], CompactSize(515)),
+        vector(LE, &[0xFE, 0x00, 0x00, 0x01, 0x00], CompactSize(0x1_0000)),
+        vector(BE, &[0xFF, 0, 0, 0, 0, 0, 0, 0, 0x01], CompactSize(1 << 56)),
+        vector(BE, &[0x00], Midi(0)),
+        vector(LE, &[0x81, 0x00], Midi(0x80)),
+        vector(BE, &[0xFF, 0xFF, 0xFF, 0x7F], Midi(0x0FFF_FFFF)),
+    ]
+}
+
+fn u128_slice_bytes(order: ByteOrder) -> Vec<u8> {
+    (0..9u128)
+        .flat_map(|n| match order {
+            ByteOrder::BE => n.to_be_bytes(),
+            ByteOrder::LE => n.to_le_bytes(),
+        })
+        .collect()
+}
+
+fn f16_slice_bytes() -> Vec<u8> {
+    [0x00, 0x3C].repeat(40)
+}
+
+fn read_errors() -> Vec<ReadError> {
+    use ByteOrder::{BE, LE};
+    use ErrorKind::{InvalidData, UnexpectedEof};
+    use Value::*;
+
+    let error = |order, strict, bytes: &[u8], value, kind| ReadError {
+        order,
+        strict,
+        bytes: bytes.to_vec(),
+        value,
+        kind,
+    };
+
+    vec![
+        error(BE, false, &[], U8(0), UnexpectedEof),
+        error(BE, false, &[0x12], U16(0), UnexpectedEof),
+        error(LE, false, &[0x12, 0x34, 0x56], U32(0), UnexpectedEof),
+        error(BE, false, &[0; 15], U128(0), UnexpectedEof),
+        error(
+            BE,
+            false,
+            &[0x12, 0x34, 0x56],
+            U16s(vec![0; 2]),
+            UnexpectedEof,
+        ),
+        error(BE, false, &[0x80, 0x80], Uleb128U32(0), UnexpectedEof),
+        error(
+            BE,
+            false,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0x1F],
+            Uleb128U32(0),
+            InvalidData,
+        ),
+        error(
+            BE,
+            false,
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x00],
+            Uleb128U32(0),
+            InvalidData,
+        ),
+        error(BE, true, &[0x81, 0x00], Uleb128U32(0), InvalidData),
+        error(BE, true, &[0xFF, 0x7F], Sleb128I32(0), InvalidData),
+        error(BE, true, &[0x83, 0x00], ZigzagI32(0), InvalidData),
+        error(BE, false, &[0xFF, 0xFF], ZigzagI64(0), UnexpectedEof),
+        error(BE, false, &[0x12, 0x34], U24(0), UnexpectedEof),
+        error(BE, false, &[0x12, 0x34], Uint(0, 3), UnexpectedEof),
+        error(
+            BE,
+            false,
+            &[0x00],
+            Length(0, LengthPrefix::U16),
+            UnexpectedEof,
+        ),
+        error(BE, false, &[0x7B], Quic(0), UnexpectedEof),
+        error(BE, true, &[0x40, 0x25], Quic(0), InvalidData),
+        error(BE, false, &[0xFF; 8], Sqlite(0), UnexpectedEof),
+        error(BE, true, &[0x80, 0x7F], Sqlite(0), InvalidData),
+        error(
+            BE,
+            false,
+            &[0xFE, 0x00, 0x00],
+            CompactSize(0),
+            UnexpectedEof,
+        ),
+        error(BE, true, &[0xFD, 0x10, 0x00], CompactSize(0), InvalidData),
+        error(BE, false, &[0x81], Midi(0), UnexpectedEof),
+        error(BE, false, &[0x80; 5], Midi(0), InvalidData),
+    ]
+}
+
+fn write_errors() -> Vec<WriteError> {
+    use ErrorKind::InvalidInput;
+    use Value::*;
+
+    let error = |value, kind| WriteError { value, kind };
+
+    vec![
+        error(U24(1 << 24), InvalidInput),
+        error(I24(1 << 23), InvalidInput),
+        error(U48(1 << 48), InvalidInput),
+        error(I48(-(1 << 47) - 1), InvalidInput),
+        error(Uint(256, 1), InvalidInput),
+        error(Int(128, 1), InvalidInput),
+        error(Uint128(1 << 64, 8), InvalidInput),
+        error(Length(256, LengthPrefix::U8), InvalidInput),
+        error(Length(65_536, LengthPrefix::U16), InvalidInput),
+        error(Quic(1 << 62), InvalidInput),
+        error(Midi(1 << 28), InvalidInput),
+    ]
+}
+
+/// Expands to a `match` which reads a value of the same kind as `$value` with
+/// `$reader`, appending `.await` to each call when it's given.
+macro_rules! read_value {
+    ($reader:ident, $value:expr $(, $await:tt)?) => {
+        match $value {
+            Value::U8(_) => $reader.read_u8()$(.$await)?.map(Value::U8),
+            Value::I8(_) => $reader.read_i8()$(.$await)?.map(Value::I8),
+            Value::U16(_) => $reader.read_u16()$(.$await)?.map(Value::U16),
+            Value::I16(_) => $reader.read_i16()$(.$await)?.map(Value::I16),
+            Value::U32(_) => $reader.read_u32()$(.$await)?.map(Value::U32),
+            Value::I32(_) => $reader.read_i32()$(.$await)?.map(Value::I32),
+            Value::U64(_) => $reader.read_u64()$(.$await)?.map(Value::U64),
+            Value::I64(_) => $reader.read_i64()$(.$await)?.map(Value::I64),
+            Value::U128(_) => $reader.read_u128()$(.$await)?.map(Value::U128),
+            Value::I128(_) => $reader.read_i128()$(.$await)?.map(Value::I128),
+            Value::F32(_) => $reader.read_f32()$(.$await)?.map(Value::F32),
+            Value::F64(_) => $reader.read_f64()$(.$await)?.map(Value::F64),
+            Value::U16s(v) => {
+                let mut dst = vec![0; v.len()];
+                $reader.read_u16_into(&mut dst)$(.$await)?.map(|_| Value::U16s(dst))
+            }
+            Value::I32s(v) => {
+                let mut dst = vec![0; v.len()];
+                $reader.read_i32_into(&mut dst)$(.$await)?.map(|_| Value::I32s(dst))
+            }
+            Value::U128s(v) => {
+                let mut dst = vec![0; v.len()];
+                $reader.read_u128_into(&mut dst)$(.$await)?.map(|_| Value::U128s(dst))
+            }
+            Value::F64s(v) => {
+                let mut dst = vec![0.0; v.len()];
+                $reader.read_f64_into(&mut dst)$(.$await)?.map(|_| Value::F64s(dst))
+            }
+            Value::Uleb128U32(_) => $reader.read_uleb128_u32()$(.$await)?.map(Value::Uleb128U32),
+            Value::Uleb128U64(_) => $reader.read_uleb128_u64()$(.$await)?.map(Value::Uleb128U64),
+            Value::Uleb128U128(_) => $reader.read_uleb128_u128()$(.$await)?.map(Value::Uleb128U128),
+            Value::Sleb128I32(_) => $reader.read_sleb128_i32()$(.$await)?.map(Value::Sleb128I32),
+            Value::Sleb128I64(_) => $reader.read_sleb128_i64()$(.$await)?.map(Value::Sleb128I64),
+            Value::Sleb128I128(_) => $reader.read_sleb128_i128()$(.$await)?.map(Value::Sleb128I128),
+            Value::ZigzagI32(_) => $reader.read_zigzag_i32()$(.$await)?.map(Value::ZigzagI32),
+            Value::ZigzagI64(_) => $reader.read_zigzag_i64()$(.$await)?.map(Value::ZigzagI64),
+            Value::F16(_) => $reader.read_f16_as_f32()$(.$await)?.map(Value::F16),
+            Value::Bf16(_) => $reader.read_bf16_as_f32()$(.$await)?.map(Value::Bf16),
+            Value::F16s(v) => {
+                let mut dst = vec![0.0; v.len()];
+                $reader.read_f16_as_f32_into(&mut dst)$(.$await)?.map(|_| Value::F16s(dst))
+            }
+            Value::Bf16s(v) => {
+                let mut dst = vec![0.0; v.len()];
+                $reader.read_bf16_as_f32_into(&mut dst)$(.$await)?.map(|_| Value::Bf16s(dst))
+            }
+            Value::Uint(_, nbytes) => $reader.read_uint(*nbytes)$(.$await)?.map(|n| Value::Uint(n, *nbytes)),
+            Value::Int(_, nbytes) => $reader.read_int(*nbytes)$(.$await)?.map(|n| Value::Int(n, *nbytes)),
+            Value::Uint128(_, nbytes) => $reader.read_uint128(*nbytes)$(.$await)?.map(|n| Value::Uint128(n, *nbytes)),
+            Value::Int128(_, nbytes) => $reader.read_int128(*nbytes)$(.$await)?.map(|n| Value::Int128(n, *nbytes)),
+            Value::U24(_) => $reader.read_u24()$(.$await)?.map(Value::U24),
+            Value::I24(_) => $reader.read_i24()$(.$await)?.map(Value::I24),
+            Value::U48(_) => $reader.read_u48()$(.$await)?.map(Value::U48),
+            Value::I48(_) => $reader.read_i48()$(.$await)?.map(Value::I48),
+            Value::Length(_, prefix) => {
+                $reader.set_length_prefix(*prefix);
+                $reader.read_length()$(.$await)?.map(|len| Value::Length(len, *prefix))
+            }
+            Value::Quic(_) => $reader.read_varint(varint::Quic)$(.$await)?.map(Value::Quic),
+            Value::Sqlite(_) => $reader.read_varint(varint::Sqlite)$(.$await)?.map(Value::Sqlite),
+            Value::CompactSize(_) => $reader.read_varint(varint::CompactSize)$(.$await)?.map(Value::CompactSize),
+            Value::Midi(_) => $reader.read_varint(Vlq::MIDI)$(.$await)?.map(Value::Midi),
+        }
+    };
+}
+
+/// Expands to a `match` which writes `$value` with `$writer`, appending
+/// `.await` to each call when it's given.
+macro_rules! write_value {
+    ($writer:ident, $value:expr $(, $await:tt)?) => {
+        match $value {
+            Value::U8(n) => $writer.write_u8(*n)$(.$await)?,
+            Value::I8(n) => $writer.write_i8(*n)$(.$await)?,
+            Value::U16(n) => $writer.write_u16(*n)$(.$await)?,
+            Value::I16(n) => $writer.write_i16(*n)$(.$await)?,
+            Value::U32(n) => $writer.write_u32(*n)$(.$await)?,
+            Value::I32(n) => $writer.write_i32(*n)$(.$await)?,
+            Value::U64(n) => $writer.write_u64(*n)$(.$await)?,
+            Value::I64(n) => $writer.write_i64(*n)$(.$await)?,
+            Value::U128(n) => $writer.write_u128(*n)$(.$await)?,
+            Value::I128(n) => $writer.write_i128(*n)$(.$await)?,
+            Value::F32(n) => $writer.write_f32(*n)$(.$await)?,
+            Value::F64(n) => $writer.write_f64(*n)$(.$await)?,
+            Value::U16s(v) => $writer.write_u16_slice(v)$(.$await)?,
+            Value::I32s(v) => $writer.write_i32_slice(v)$(.$await)?,
+            Value::U128s(v) => $writer.write_u128_slice(v)$(.$await)?,
+            Value::F64s(v) => $writer.write_f64_slice(v)$(.$await)?,
+            Value::Uleb128U32(n) => $writer.write_uleb128_u32(*n)$(.$await)?,
+            Value::Uleb128U64(n) => $writer.write_uleb128_u64(*n)$(.$await)?,
+            Value::Uleb128U128(n) => $writer.write_uleb128_u128(*n)$(.$await)?,
+            Value::Sleb128I32(n) => $writer.write_sleb128_i32(*n)$(.$await)?,
+            Value::Sleb128I64(n) => $writer.write_sleb128_i64(*n)$(.$await)?,
+            Value::Sleb128I128(n) => $writer.write_sleb128_i128(*n)$(.$await)?,
+            Value::ZigzagI32(n) => $writer.write_zigzag_i32(*n)$(.$await)?,
+            Value::ZigzagI64(n) => $writer.write_zigzag_i64(*n)$(.$await)?,
+            Value::F16(n) => $writer.write_f32_as_f16(*n)$(.$await)?,
+            Value::Bf16(n) => $writer.write_f32_as_bf16(*n)$(.$await)?,
+            Value::F16s(v) => $writer.write_f32_as_f16_slice(v)$(.$await)?,
+            Value::Bf16s(v) => $writer.write_f32_as_bf16_slice(v)$(.$await)?,
+            Value::Uint(n, nbytes) => $writer.write_uint(*n, *nbytes)$(.$await)?,
+            Value::Int(n, nbytes) => $writer.write_int(*n, *nbytes)$(.$await)?,
+            Value::Uint128(n, nbytes) => $writer.write_uint128(*n, *nbytes)$(.$await)?,
+            Value::Int128(n, nbytes) => $writer.write_int128(*n, *nbytes)$(.$await)?,
+            Value::U24(n) => $writer.write_u24(*n)$(.$await)?,
+            Value::I24(n) => $writer.write_i24(*n)$(.$await)?,
+            Value::U48(n) => $writer.write_u48(*n)$(.$await)?,
+            Value::I48(n) => $writer.write_i48(*n)$(.$await)?,
+            Value::Length(len, prefix) => {
+                $writer.set_length_prefix(*prefix);
+                $writer.write_length(*len)$(.$await)?
+            }
+            Value::Quic(n) => $writer.write_varint(varint::Quic, *n)$(.$await)?,
+            Value::Sqlite(n) => $writer.write_varint(varint::Sqlite, *n)$(.$await)?,
+            Value::CompactSize(n) => $writer.write_varint(varint::CompactSize, *n)$(.$await)?,
+            Value::Midi(n) => $writer.write_varint(Vlq::MIDI, *n)$(.$await)?,
+        }
+    };
+}
+
+/// The buffer or reader that the reader of an I/O model is created over,
+/// holding the bytes of a test.
+trait Source<'a> {
+    fn from_bytes(bytes: &'a [u8]) -> Self;
+}
+
+impl<'a> Source<'a> for &'a [u8] {
+    fn from_bytes(bytes: &'a [u8]) -> Self {
+        bytes
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Source<'_> for ::bytes::Bytes {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        ::bytes::Bytes::copy_from_slice(bytes)
+    }
+}
+
+/// Generates the tests of one I/O model, given the types of its reader and
+/// writer, and `await` for the asynchronous models. The reader is created
+/// over a [`Source`], and the writer over an empty buffer.
+macro_rules! io_model_tests {
+    ($block_on:path, $Reader:ty, $Writer:ty $(, $await:tt)?) => {
+        #[test]
+        fn reads_vectors() {
+            $block_on(async {
+                for v in super::vectors() {
+                    let src = Source::from_bytes(&v.bytes);
+                    let mut reader = <$Reader>::with_order(v.order, src);
+                    let value = read_value!(reader, &v.value $(, $await)?);
+                    assert_eq!(v.value, value.unwrap(), "reading {:02X?}", v.bytes);
+                }
+            })
+        }
+
+        #[test]
+        fn writes_vectors() {
+            $block_on(async {
+                for v in super::vectors() {
+                    let mut writer = <$Writer>::with_order(v.order, Default::default());
+                    write_value!(writer, &v.value $(, $await)?).unwrap();
+                    assert_eq!(v.bytes, &writer.into_inner()[..], "writing {:?}", v.value);
+                }
+            })
+        }
+
+        #[test]
+        fn rejects_invalid_reads() {
+            $block_on(async {
+                for e in super::read_errors() {
+                    let src = Source::from_bytes(&e.bytes);
+                    let mut reader = <$Reader>::with_order(e.order, src);
+                    reader.set_strict(e.strict);
+                    let err = read_value!(reader, &e.value $(, $await)?).unwrap_err();
+                    assert_eq!(e.kind, err.kind(), "reading {:?} from {:02X?}", e.value, e.bytes);
+                }
+            })
+        }
+
+        #[test]
+        fn rejects_invalid_writes() {
+            $block_on(async {
+                for e in super::write_errors() {
+                    let mut writer = <$Writer>::with_order(ByteOrder::BE, Default::default());
+                    let err = write_value!(writer, &e.value $(, $await)?).unwrap_err();
+                    assert_eq!(e.kind, err.kind(), "writing {:?}", e.value);
+                    assert!(writer.into_inner().is_empty(), "writing {:?}", e.value);
+                }
+            })
+        }
+    };
+}
+
+/// Runs a future that never waits, which is the case for every future the
+/// synchronous tests create.
+fn run_sync<F: std::future::Future>(future: F) -> F::Output {
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+    let mut future = std::pin::pin!(future);
+    match future.as_mut().poll(&mut cx) {
+        std::task::Poll::Ready(output) => output,
+        std::task::Poll::Pending => unreachable!("a synchronous test awaited"),
+    }
+}
+
+/// A reader and writer which returns `Poll::Pending` before moving each
+/// byte, and then moves only that one byte, to exercise the cancel safety of
+/// the asynchronous readers and writers.
+#[cfg(any(feature = "tokio", feature = "futures"))]
+struct Drip {
+    bytes: Vec<u8>,
+    pos: usize,
+    ready: bool,
+}
+
+#[cfg(any(feature = "tokio", feature = "futures"))]
+impl Drip {
+    fn new(bytes: &[u8]) -> Drip {
+        Drip {
+            bytes: bytes.to_vec(),
+            pos: 0,
+            ready: false,
+        }
+    }
+
+    /// Returns `Poll::Pending` and `Poll::Ready` in turn.
+    fn poll_step(&mut self, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        self.ready = !self.ready;
+        if self.ready {
+            cx.waker().wake_by_ref();
+            return std::task::Poll::Pending;
+        }
+        std::task::Poll::Ready(())
+    }
+
+    fn read_byte(&mut self) -> Option<u8> {
+        let byte = self.bytes.get(self.pos).copied();
+        self.pos += byte.is_some() as usize;
+        byte
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ::tokio::io::AsyncRead for Drip {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut ::tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::ready!(self.poll_step(cx));
+        if buf.remaining() > 0 {
+            if let Some(byte) = self.read_byte() {
+                buf.put_slice(&[byte]);
+            }
+        }
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ::tokio::io::AsyncWrite for Drip {
+    fn poll_write(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        std::task::ready!(self.poll_step(cx));
+        self.bytes.extend(buf.first());
+        std::task::Poll::Ready(Ok(buf.len().min(1)))
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl ::futures_io::AsyncRead for Drip {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        std::task::ready!(self.poll_step(cx));
+        match (buf.first_mut(), self.read_byte()) {
+            (Some(dst), Some(byte)) => {
+                *dst = byte;
+                std::task::Poll::Ready(Ok(1))
+            }
+            _ => std::task::Poll::Ready(Ok(0)),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl ::futures_io::AsyncWrite for Drip {
+    fn poll_write(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        std::task::ready!(self.poll_step(cx));
+        self.bytes.extend(buf.first());
+        std::task::Poll::Ready(Ok(buf.len().min(1)))
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+/// Polls `future` up to `polls` times, returning its output if it completes,
+/// and otherwise dropping it.
+#[cfg(any(feature = "tokio", feature = "futures"))]
+fn poll_times<F: std::future::Future>(future: F, polls: usize) -> Option<F::Output> {
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+    let mut future = std::pin::pin!(future);
+    (0..polls).find_map(|_| match future.as_mut().poll(&mut cx) {
+        std::task::Poll::Ready(output) => Some(output),
+        std::task::Poll::Pending => None,
+    })
+}
+
+/// Polls `future` until it completes, which a future over a [`Drip`] does
+/// without waiting on anything.
+#[cfg(any(feature = "tokio", feature = "futures"))]
+fn poll_to_end<F: std::future::Future>(future: F) -> F::Output {
+    poll_times(future, usize::MAX).unwrap()
+}
+
+/// Generates the tests of an asynchronous I/O model over a [`Drip`], given
+/// the names of its reader and writer.
+#[cfg(any(feature = "tokio", feature = "futures"))]
+macro_rules! cancel_safety_tests {
+    ($Reader:ident, $Writer:ident) => {
+        #[test]
+        fn reads_vectors_byte_by_byte() {
+            for v in super::vectors() {
+                let mut reader = $Reader::with_order(v.order, Drip::new(&v.bytes));
+                let value = poll_to_end(async { read_value!(reader, &v.value, await) });
+                assert_eq!(v.value, value.unwrap(), "reading {:02X?}", v.bytes);
+            }
+        }
+
+        #[test]
+        fn writes_vectors_byte_by_byte() {
+            for v in super::vectors() {
+                let mut writer = $Writer::with_order(v.order, Drip::new(&[]));
+                poll_to_end(async { write_value!(writer, &v.value, await) }).unwrap();
+                assert_eq!(v.bytes, writer.into_inner().bytes, "writing {:?}", v.value);
+            }
+        }
+
+        #[test]
+        fn resumes_cancelled_reads() {
+            let bytes = [0x12, 0x34, 0x56, 0x78, 0xE5, 0x8E, 0x26, 0xAB];
+            for polls in 0..2 * bytes.len() {
+                let mut reader = $Reader::with_order(ByteOrder::BE, Drip::new(&bytes));
+                let n = match poll_times(reader.read_u32(), polls) {
+                    Some(n) => n,
+                    None => poll_to_end(reader.read_u32()),
+                };
+                assert_eq!(0x1234_5678, n.unwrap(), "after {} polls", polls);
+                let n = match poll_times(reader.read_uleb128_u32(), polls) {
+                    Some(n) => n,
+                    None => poll_to_end(reader.read_uleb128_u32()),
+                };
+                assert_eq!(624_485, n.unwrap(), "after {} polls", polls);
+                assert_eq!(0xAB, poll_to_end(reader.read_u8()).unwrap());
+            }
+        }
+
+        #[test]
+        fn resumes_cancelled_writes() {
+            // Each cancelled write is either written whole, once it has been
+            // staged, or not at all.
+            let first: &[u8] = &[0x12, 0x34, 0x56, 0x78];
+            let second: &[u8] = &[0xE5, 0x8E, 0x26];
+            for polls in 0..16 {
+                let mut writer = $Writer::with_order(ByteOrder::BE, Drip::new(&[]));
+                poll_times(writer.write_u32(0x1234_5678), polls);
+                poll_times(writer.write_uleb128_u32(624_485), polls);
+                poll_to_end(writer.write_u8(0xAB)).unwrap();
+                let bytes = writer.into_inner().bytes;
+                let expected = [
+                    [first, second, &[0xAB]].concat(),
+                    [first, &[0xAB]].concat(),
+                    [second, &[0xAB]].concat(),
+                    vec![0xAB],
+                ];
+                assert!(
+                    expected.contains(&bytes),
+                    "after {} polls: {:02X?}",
+                    polls,
+                    bytes
+                );
+                if polls > 2 * (first.len() + second.len()) {
+                    assert_eq!(expected[0], bytes, "after {} polls", polls);
+                }
+            }
+        }
+    };
+}
+
+mod sync {
+    use super::*;
+    use byte_order::{DecodeError, NumberReader, NumberWriter};
+
+    io_model_tests!(super::run_sync, NumberReader<&[u8]>, NumberWriter<Vec<u8>>);
+
+    /// Reads a value of the same kind as `value` with its `try_read_*`
+    /// method, or returns `None` if it has none.
+    fn try_read_value(
+        reader: &mut NumberReader<&[u8]>,
+        value: &Value,
+    ) -> Option<std::io::Result<Option<Value>>> {
+        Some(match value {
+            Value::U8(_) => reader.try_read_u8().map(|n| n.map(Value::U8)),
+            Value::I8(_) => reader.try_read_i8().map(|n| n.map(Value::I8)),
+            Value::U16(_) => reader.try_read_u16().map(|n| n.map(Value::U16)),
+            Value::I16(_) => reader.try_read_i16().map(|n| n.map(Value::I16)),
+            Value::U32(_) => reader.try_read_u32().map(|n| n.map(Value::U32)),
+            Value::I32(_) => reader.try_read_i32().map(|n| n.map(Value::I32)),
+            Value::U64(_) => reader.try_read_u64().map(|n| n.map(Value::U64)),
+            Value::I64(_) => reader.try_read_i64().map(|n| n.map(Value::I64)),
+            Value::U128(_) => reader.try_read_u128().map(|n| n.map(Value::U128)),
+            Value::I128(_) => reader.try_read_i128().map(|n| n.map(Value::I128)),
+            Value::F32(_) => reader.try_read_f32().map(|n| n.map(Value::F32)),
+            Value::F64(_) => reader.try_read_f64().map(|n| n.map(Value::F64)),
+            Value::Uleb128U32(_) => reader
+                .try_read_uleb128_u32()
+                .map(|n| n.map(Value::Uleb128U32)),
+            Value::Uleb128U64(_) => reader
+                .try_read_uleb128_u64()
+                .map(|n| n.map(Value::Uleb128U64)),
+            Value::Uleb128U128(_) => reader
+                .try_read_uleb128_u128()
+                .map(|n| n.map(Value::Uleb128U128)),
+            Value::Sleb128I32(_) => reader
+                .try_read_sleb128_i32()
+                .map(|n| n.map(Value::Sleb128I32)),
+            Value::Sleb128I64(_) => reader
+                .try_read_sleb128_i64()
+                .map(|n| n.map(Value::Sleb128I64)),
+            Value::Sleb128I128(_) => reader
+                .try_read_sleb128_i128()
+                .map(|n| n.map(Value::Sleb128I128)),
+            Value::ZigzagI32(_) => reader
+                .try_read_zigzag_i32()
+                .map(|n| n.map(Value::ZigzagI32)),
+            Value::ZigzagI64(_) => reader
+                .try_read_zigzag_i64()
+                .map(|n| n.map(Value::ZigzagI64)),
+            _ => return None,
+        })
+    }
+
+    #[test]
+    fn try_reads_vectors() {
+        for v in super::vectors() {
+            let src: &[u8] = &v.bytes;
+            let mut reader = NumberReader::with_order(v.order, src);
+            let value = match try_read_value(&mut reader, &v.value) {
+                Some(value) => value.unwrap(),
+                None => continue,
+            };
+            assert_eq!(Some(v.value.clone()), value, "reading {:02X?}", v.bytes);
+            let end = try_read_value(&mut reader, &v.value).unwrap();
+            assert_eq!(None, end.unwrap(), "reading past {:02X?}", v.bytes);
+        }
+    }
+
+    #[test]
+    fn try_rejects_invalid_reads() {
+        for e in super::read_errors() {
+            let src: &[u8] = &e.bytes;
+            let mut reader = NumberReader::with_order(e.order, src);
+            reader.set_strict(e.strict);
+            let result = match try_read_value(&mut reader, &e.value) {
+                Some(result) => result,
+                None => continue,
+            };
+            if e.bytes.is_empty() {
+                assert_eq!(None, result.unwrap(), "reading {:?}", e.value);
+            } else {
+                let err = result.unwrap_err();
+                assert_eq!(
+                    e.kind,
+                    err.kind(),
+                    "reading {:?} from {:02X?}",
+                    e.value,
+                    e.bytes
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tracks_read_positions() {
+        for v in super::vectors() {
+            let mut src = v.bytes.clone();
+            src.push(0xAA);
+            let mut reader = NumberReader::with_order(v.order, &src[..]);
+            reader.set_track_position(true);
+            read_value!(reader, &v.value).unwrap();
+            let len = v.bytes.len() as u64;
+            assert_eq!(Some(len), reader.position(), "reading {:02X?}", v.bytes);
+
+            let mut byte = [0];
+            std::io::Read::read_exact(&mut reader, &mut byte).unwrap();
+            assert_eq!(Some(len + 1), reader.position());
+            reader.reset_position();
+            assert_eq!(Some(0), reader.position());
+        }
+
+        // Bytes consumed by a failed read are counted, with or without error
+        // context.
+        for e in super::read_errors() {
+            for &context in &[false, true] {
+                let mut reader = NumberReader::with_order(e.order, &e.bytes[..]);
+                reader.set_strict(e.strict);
+                reader.set_track_position(true);
+                reader.set_error_context(context);
+                read_value!(reader, &e.value).unwrap_err();
+                let consumed = (e.bytes.len() - reader.get_ref().len()) as u64;
+                assert_eq!(
+                    Some(consumed),
+                    reader.position(),
+                    "reading {:?} from {:02X?}",
+                    e.value,
+                    e.bytes
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn describes_invalid_reads() {
+        for e in super::read_errors() {
+            let mut src = vec![0xAA];
+            src.extend(&e.bytes);
+            let mut reader = NumberReader::with_order(e.order, &src[..]);
+            reader.set_strict(e.strict);
+            reader.set_error_context(true);
+            reader.read_u8().unwrap();
+            let err = reader
+                .with_field("value", |reader| read_value!(reader, &e.value))
+                .unwrap_err();
+            assert_eq!(
+                e.kind,
+                err.kind(),
+                "reading {:?} from {:02X?}",
+                e.value,
+                e.bytes
+            );
+
+            let err = DecodeError::from_io_error(&err).unwrap();
+            assert_eq!(1, err.offset());
+            assert_eq!(Some("value"), err.path());
+            assert_eq!(reader.position(), Some(1 + err.available() as u64));
+            if let Some(width) = err.width() {
+                assert!(err.available() <= width, "{}", err);
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn describes_invalid_derived_reads() {
+        use byte_order::Decode;
+
+        #[derive(Debug, Decode)]
+        struct Header {
+            _len: u16,
+            _flags: u32,
+        }
+
+        #[derive(Debug, Decode)]
+        struct Packet {
+            _kind: u8,
+            _header: Header,
+            #[byte_order(magic = 7)]
+            _end: u8,
+        }
+
+        let src: &[u8] = &[0x00, 0x01, 0x02];
+        let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+        reader.set_error_context(true);
+        let err = reader.decode::<Header>().unwrap_err();
+        assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+        let err = DecodeError::from_io_error(&err).unwrap();
+        assert_eq!(2, err.offset());
+        assert_eq!("u32", err.type_name());
+        assert_eq!(Some(4), err.width());
+        assert_eq!(1, err.available());
+        assert_eq!(Some("_flags"), err.path());
+        assert!(err
+            .to_string()
+            .starts_with("failed to read u32 at offset 2 in `_flags`: "));
+
+        let src: &[u8] = &[0x09, 0x00, 0x01, 0x02, 0x03];
+        let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+        reader.set_error_context(true);
+        let err = reader.decode::<Packet>().unwrap_err();
+        let err = DecodeError::from_io_error(&err).unwrap();
+        assert_eq!(3, err.offset());
+        assert_eq!("u32", err.type_name());
+        assert_eq!(2, err.available());
+        assert_eq!(Some("_header._flags"), err.path());
+
+        let src: &[u8] = &[0x09, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let mut reader = NumberReader::with_order(ByteOrder::BE, src);
+        reader.set_error_context(true);
+        let err = reader.decode::<Packet>().unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+        let err = DecodeError::from_io_error(&err).unwrap();
+        assert_eq!(7, err.offset());
+        assert_eq!("u8", err.type_name());
+        assert_eq!(Some("_end"), err.path());
+
+        let src: &[u8] = &[0x00, 0x01, 0x02];
+        let err = NumberReader::with_order(ByteOrder::BE, src)
+            .decode::<Header>()
+            .unwrap_err();
+        assert!(DecodeError::from_io_error(&err).is_none());
+        assert!(err.to_string().starts_with("Header._flags: "), "{}", err);
+    }
+
+    /// The generic `read_number` and `write_number` methods leave the
+    /// `read` and `write` methods of the I/O traits callable with method
+    /// syntax.
+    #[test]
+    fn reads_and_writes_bytes_with_io_traits() {
+        use byte_order::{SliceReader, SliceWriter, VecWriter};
+        use std::io::{Read, Write};
+
+        let mut buf = [0; 2];
+        let mut reader = NumberReader::new(&[1, 2, 3][..]);
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        let mut reader = SliceReader::new(&[1, 2, 3]);
+        assert_eq!(2, reader.read(&mut buf).unwrap());
+        assert_eq!([1, 2], buf);
+
+        let mut writer = NumberWriter::new(Vec::new());
+        assert_eq!(2, writer.write(&buf).unwrap());
+        let mut out = [0; 3];
+        let mut writer = SliceWriter::new(&mut out);
+        assert_eq!(2, writer.write(&buf).unwrap());
+        let mut writer = VecWriter::new();
+        assert_eq!(2, writer.write(&buf).unwrap());
+        assert_eq!(vec![1, 2], writer.into_inner());
+    }
+
+    #[test]
+    fn tracks_write_positions() {
+        for v in super::vectors() {
+            let mut writer = NumberWriter::with_order(v.order, Vec::new());
+            writer.set_track_position(true);
+            write_value!(writer, &v.value).unwrap();
+            let len = v.bytes.len() as u64;
+            assert_eq!(Some(len), writer.position(), "writing {:?}", v.value);
+
+            std::io::Write::write_all(&mut writer, &[0xAA]).unwrap();
+            assert_eq!(Some(len + 1), writer.position());
+
+            // A writer with another byte order continues from this position.
+            let mut nested = writer.by_order(ByteOrder::BE);
+            assert_eq!(Some(len + 1), nested.position());
+            nested.write_u16(0x1234).unwrap();
+            assert_eq!(Some(len + 3), nested.position());
+            assert_eq!(Some(len + 3), writer.position());
+            writer.reset_position();
+            assert_eq!(Some(0), writer.position());
+        }
+
+        for e in super::write_errors() {
+            let mut writer = NumberWriter::with_order(ByteOrder::BE, Vec::new());
+            writer.set_track_position(true);
+            write_value!(writer, &e.value).unwrap_err();
+            assert_eq!(Some(0), writer.position(), "writing {:?}", e.value);
+        }
+
+        // Bytes accepted by the underlying writer before a write fails are
+        // counted.
+        for v in super::vectors().into_iter().filter(|v| !v.bytes.is_empty()) {
+            let mut buf = vec![0; v.bytes.len() - 1];
+            let mut writer = NumberWriter::with_order(v.order, &mut buf[..]);
+            writer.set_track_position(true);
+            let err = write_value!(writer, &v.value).unwrap_err();
+            assert_eq!(ErrorKind::WriteZero, err.kind());
+            assert!(writer.get_ref().is_empty());
+            assert_eq!(
+                Some(v.bytes.len() as u64 - 1),
+                writer.position(),
+                "writing {:?}",
+                v.value
+            );
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio {
+    use super::*;
+    use byte_order::tokio::{AsyncNumberReader, AsyncNumberWriter};
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        ::tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    io_model_tests!(
+        block_on,
+        AsyncNumberReader<&[u8]>,
+        AsyncNumberWriter<Vec<u8>>,
+        await
+    );
+
+    cancel_safety_tests!(AsyncNumberReader, AsyncNumberWriter);
+}
+
+#[cfg(feature = "futures")]
+mod futures {
+    use super::*;
+    use byte_order::futures::{AsyncNumberReader, AsyncNumberWriter};
+
+    io_model_tests!(
+        futures_executor::block_on,
+        AsyncNumberReader<&[u8]>,
+        AsyncNumberWriter<Vec<u8>>,
+        await
+    );
+
+    cancel_safety_tests!(AsyncNumberReader, AsyncNumberWriter);
+}
+
+#[cfg(feature = "bytes")]
+mod buf {
+    use super::*;
+    use ::bytes::{Bytes, BytesMut};
+    use byte_order::{NumberBuf, NumberBufMut};
+
+    io_model_tests!(super::run_sync, NumberBuf<Bytes>, NumberBufMut<BytesMut>);
+
+    #[test]
+    fn reads_bytes_without_copying() {
+        let frame = Bytes::from(vec![0x00, 0x02, 0xAB, 0xCD, 0xEF]);
+        let mut buf = NumberBuf::with_order(ByteOrder::BE, frame.clone());
+        let len = buf.read_u16().unwrap();
+        let payload = buf.read_bytes(len.into()).unwrap();
+        assert_eq!(&[0xAB, 0xCD], &payload[..]);
+        assert_eq!(frame[2..].as_ptr(), payload.as_ptr());
+
+        let err = buf.read_bytes(2).unwrap_err();
+        assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+        assert_eq!(1, buf.remaining());
+    }
+
+    #[test]
+    fn writes_nothing_into_short_buffers() {
+        let mut dst = [0xAA; 3];
+        let mut buf = NumberBufMut::with_order(ByteOrder::BE, &mut dst[..]);
+        let err = buf.encode(&(1u8, 0x1234_5678u32)).unwrap_err();
+        assert_eq!(ErrorKind::WriteZero, err.kind());
+        let err = buf.write_u16_slice(&[1, 2]).unwrap_err();
+        assert_eq!(ErrorKind::WriteZero, err.kind());
+        let err = buf.write_uleb128_u32(u32::MAX).unwrap_err();
+        assert_eq!(ErrorKind::WriteZero, err.kind());
+        assert_eq!(3, buf.remaining_mut());
+        assert_eq!([0xAA; 3], dst);
+    }
+}
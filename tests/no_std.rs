@@ -0,0 +1,99 @@
+//! The minimal I/O traits and error type provided without the `std` feature,
+//! and their implementations for byte slices and vectors.
+
+#![cfg(not(feature = "std"))]
+
+use byte_order::io::{Error, ErrorKind, Read, Write};
+use byte_order::{ByteOrder, NumberReader, NumberWriter};
+
+#[test]
+fn reads_short_slices() {
+    let mut src: &[u8] = &[0x12, 0x34, 0x56];
+    let mut buf = [0; 2];
+    src.read_exact(&mut buf).unwrap();
+    assert_eq!([0x12, 0x34], buf);
+    assert_eq!(&[0x56], src);
+
+    let mut buf = [0; 4];
+    assert_eq!(1, src.read(&mut buf).unwrap());
+    assert_eq!(0x56, buf[0]);
+    assert_eq!(0, src.read(&mut buf).unwrap());
+
+    // A failed read empties the slice, as it does in the standard library.
+    let mut src: &[u8] = &[0x12, 0x34, 0x56];
+    let err = src.read_exact(&mut buf).unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(src.is_empty());
+
+    let mut reader = NumberReader::with_order(ByteOrder::BE, &[0x12, 0x34, 0x56][..]);
+    assert_eq!(0x1234, reader.read_u16().unwrap());
+    let err = reader.read_u16().unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert!(reader.into_inner().is_empty());
+}
+
+#[test]
+fn writes_zero_into_full_slices() {
+    let mut buf = [0; 3];
+    let mut dst = &mut buf[..];
+    dst.write_all(&[0x12, 0x34]).unwrap();
+    assert_eq!(1, dst.write(&[0x56, 0x78]).unwrap());
+    assert_eq!(0, dst.write(&[0x9A]).unwrap());
+    let err = dst.write_all(&[0x9A]).unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+    assert_eq!([0x12, 0x34, 0x56], buf);
+
+    let mut buf = [0; 3];
+    let mut writer = NumberWriter::with_order(ByteOrder::LE, &mut buf[..]);
+    writer.set_track_position(true);
+    writer.write_u16(0x1234).unwrap();
+    let err = writer.write_u16(0x5678).unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+    assert_eq!(Some(3), writer.position());
+    assert_eq!([0x34, 0x12, 0x78], buf);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn grows_vecs() {
+    let mut dst = Vec::new();
+    assert_eq!(2, dst.write(&[0x12, 0x34]).unwrap());
+    dst.write_all(&[0x56]).unwrap();
+    dst.flush().unwrap();
+    assert_eq!(vec![0x12, 0x34, 0x56], dst);
+
+    let mut writer = NumberWriter::with_order(ByteOrder::BE, dst);
+    writer.write_u32(0x789A_BCDE).unwrap();
+    writer.write_u16_slice(&[0xF012; 512]).unwrap();
+    let dst = writer.into_inner();
+    assert_eq!(3 + 4 + 1024, dst.len());
+    assert_eq!(&[0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x12], &dst[3..9]);
+}
+
+#[test]
+fn describes_errors() {
+    let err = Error::new(ErrorKind::InvalidData, "invalid magic");
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert_eq!("invalid magic", err.to_string());
+
+    let err = Error::from(ErrorKind::UnexpectedEof);
+    assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+    assert_eq!("unexpected end of file", err.to_string());
+    assert_eq!(err, Error::from(ErrorKind::UnexpectedEof));
+    assert_ne!(err, Error::new(ErrorKind::UnexpectedEof, "end"));
+
+    for &(kind, message) in &[
+        (ErrorKind::InvalidData, "invalid data"),
+        (ErrorKind::InvalidInput, "invalid input parameter"),
+        (ErrorKind::Interrupted, "operation interrupted"),
+        (ErrorKind::WriteZero, "write zero"),
+        (ErrorKind::Other, "other error"),
+    ] {
+        assert_eq!(message, Error::from(kind).to_string());
+    }
+
+    let mut reader = NumberReader::new(&[0x80, 0x00][..]);
+    reader.set_strict(true);
+    let err = reader.read_uleb128_u32().unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+}
@@ -0,0 +1,363 @@
+//! Round trips of every type of the serde data model through the serializer
+//! and deserializer, with every combination of their settings.
+
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use byte_order::io::ErrorKind;
+use byte_order::serde::{self as bo_serde, Deserializer, Error, IntEncoding, Serializer, TagWidth};
+use byte_order::{ByteOrder, LengthPrefix};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Unit;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Newtype(u32);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Tuple(i16, String);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+enum Enum {
+    Unit,
+    Newtype(i64),
+    Tuple(u8, char),
+    Struct { a: Option<u16>, b: Vec<bool> },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Integers {
+    i8: i8,
+    i16: i16,
+    i32: i32,
+    i64: i64,
+    i128: i128,
+    u8: u8,
+    u16: u16,
+    u32: u32,
+    u64: u64,
+    u128: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Everything {
+    bool: bool,
+    integers: Vec<Integers>,
+    f32: f32,
+    f64: f64,
+    char: char,
+    string: String,
+    bytes: ByteBuf,
+    none: Option<u32>,
+    some: Option<Newtype>,
+    unit: (),
+    unit_struct: Unit,
+    newtype_struct: Newtype,
+    seq: Vec<u64>,
+    tuple: (u8, i32, f64),
+    tuple_struct: Tuple,
+    map: BTreeMap<String, i32>,
+    enums: Vec<Enum>,
+}
+
+fn extremes(sign: i8) -> Vec<Integers> {
+    let small = Integers {
+        i8: sign,
+        i16: sign.into(),
+        i32: sign.into(),
+        i64: sign.into(),
+        i128: sign.into(),
+        u8: 1,
+        u16: 1,
+        u32: 1,
+        u64: 1,
+        u128: 1,
+    };
+    let large = if sign < 0 {
+        Integers {
+            i8: i8::MIN,
+            i16: i16::MIN,
+            i32: i32::MIN,
+            i64: i64::MIN,
+            i128: i128::MIN,
+            u8: 0,
+            u16: 0,
+            u32: 0,
+            u64: 0,
+            u128: 0,
+        }
+    } else {
+        Integers {
+            i8: i8::MAX,
+            i16: i16::MAX,
+            i32: i32::MAX,
+            i64: i64::MAX,
+            i128: i128::MAX,
+            u8: u8::MAX,
+            u16: u16::MAX,
+            u32: u32::MAX,
+            u64: u64::MAX,
+            u128: u128::MAX,
+        }
+    };
+    vec![small, large]
+}
+
+fn everything() -> Everything {
+    let mut integers = extremes(1);
+    integers.extend(extremes(-1));
+    Everything {
+        bool: true,
+        integers,
+        f32: -2.5,
+        f64: f64::MAX,
+        char: '\u{1F980}',
+        string: String::from("héllo"),
+        bytes: ByteBuf::from(vec![0x00, 0xFF, 0x80]),
+        none: None,
+        some: Some(Newtype(0x1234_5678)),
+        unit: (),
+        unit_struct: Unit,
+        newtype_struct: Newtype(7),
+        seq: vec![0, 127, 128, u64::MAX],
+        tuple: (0xAB, -300, 0.5),
+        tuple_struct: Tuple(-1, String::new()),
+        map: vec![(String::from("a"), 1), (String::from("b"), -2)]
+            .into_iter()
+            .collect(),
+        enums: vec![
+            Enum::Unit,
+            Enum::Newtype(-64),
+            Enum::Tuple(9, 'z'),
+            Enum::Struct {
+                a: Some(65535),
+                b: vec![true, false],
+            },
+            Enum::Struct { a: None, b: vec![] },
+        ],
+    }
+}
+
+/// The settings of a serializer and deserializer.
+#[derive(Clone, Copy, Debug)]
+struct Settings {
+    order: ByteOrder,
+    int_encoding: IntEncoding,
+    length_prefix: LengthPrefix,
+    tag_width: TagWidth,
+}
+
+impl Settings {
+    fn all() -> Vec<Settings> {
+        let mut all = vec![];
+        for &big_endian in &[true, false] {
+            for &int_encoding in &[IntEncoding::Fixed, IntEncoding::Varint] {
+                for &length_prefix in &[
+                    LengthPrefix::U8,
+                    LengthPrefix::U16,
+                    LengthPrefix::U32,
+                    LengthPrefix::U64,
+                    LengthPrefix::Uleb128,
+                ] {
+                    for &tag_width in &[
+                        TagWidth::U8,
+                        TagWidth::U16,
+                        TagWidth::U32,
+                        TagWidth::Uleb128,
+                    ] {
+                        all.push(Settings {
+                            order: if big_endian {
+                                ByteOrder::BE
+                            } else {
+                                ByteOrder::LE
+                            },
+                            int_encoding,
+                            length_prefix,
+                            tag_width,
+                        });
+                    }
+                }
+            }
+        }
+        all
+    }
+
+    fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, Error> {
+        let mut serializer = Serializer::with_order(self.order, vec![]);
+        serializer.set_int_encoding(self.int_encoding);
+        serializer.set_length_prefix(self.length_prefix);
+        serializer.set_tag_width(self.tag_width);
+        value.serialize(&mut serializer)?;
+        Ok(serializer.into_inner())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Error> {
+        let mut deserializer = Deserializer::with_order(self.order, bytes);
+        deserializer.set_int_encoding(self.int_encoding);
+        deserializer.set_length_prefix(self.length_prefix);
+        deserializer.set_tag_width(self.tag_width);
+        deserializer.set_strict(true);
+        let value = T::deserialize(&mut deserializer)?;
+        assert!(deserializer.into_inner().is_empty(), "trailing bytes");
+        Ok(value)
+    }
+}
+
+fn assert_io_error<T: std::fmt::Debug>(result: Result<T, Error>, kind: ErrorKind) {
+    match result {
+        Err(Error::Io(e)) => assert_eq!(kind, e.kind()),
+        other => panic!("expected an I/O error of kind {:?}, got {:?}", kind, other),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let value = everything();
+    for settings in Settings::all() {
+        let bytes = settings.serialize(&value).unwrap();
+        let decoded: Everything = settings.deserialize(&bytes).unwrap();
+        assert_eq!(value, decoded, "{:?}", settings);
+
+        for len in 0..bytes.len() {
+            let result = settings.deserialize::<Everything>(&bytes[..len]);
+            assert_io_error(result, ErrorKind::UnexpectedEof);
+        }
+    }
+}
+
+#[test]
+fn layout() {
+    let value = (
+        0x1234u16,
+        Some('A'),
+        String::from("hi"),
+        Enum::Tuple(0xFF, 'b'),
+        vec![-1i32],
+    );
+
+    let be = bo_serde::to_vec(ByteOrder::BE, &value).unwrap();
+    let expected: &[u8] = &[
+        0x12, 0x34, // u16
+        0x01, 0x00, 0x00, 0x00, 0x41, // Some('A')
+        0x00, 0x00, 0x00, 0x02, b'h', b'i', // "hi"
+        0x00, 0x00, 0x00, 0x02, 0xFF, 0x00, 0x00, 0x00, 0x62, // Enum::Tuple
+        0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, // vec![-1]
+    ];
+    assert_eq!(expected, &be[..]);
+    assert_eq!(value, bo_serde::from_slice(ByteOrder::BE, &be).unwrap());
+
+    let le = bo_serde::to_vec(ByteOrder::LE, &value).unwrap();
+    let expected: &[u8] = &[
+        0x34, 0x12, // u16
+        0x01, 0x41, 0x00, 0x00, 0x00, // Some('A')
+        0x02, 0x00, 0x00, 0x00, b'h', b'i', // "hi"
+        0x02, 0x00, 0x00, 0x00, 0xFF, 0x62, 0x00, 0x00, 0x00, // Enum::Tuple
+        0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, // vec![-1]
+    ];
+    assert_eq!(expected, &le[..]);
+    assert_eq!(value, bo_serde::from_slice(ByteOrder::LE, &le).unwrap());
+
+    let compact = Settings {
+        order: ByteOrder::BE,
+        int_encoding: IntEncoding::Varint,
+        length_prefix: LengthPrefix::Uleb128,
+        tag_width: TagWidth::U8,
+    };
+    let expected: &[u8] = &[
+        0xB4, 0x24, // u16
+        0x01, 0x41, // Some('A')
+        0x02, b'h', b'i', // "hi"
+        0x02, 0xFF, 0x62, // Enum::Tuple
+        0x01, 0x7F, // vec![-1]
+    ];
+    assert_eq!(expected, &compact.serialize(&value).unwrap()[..]);
+    assert_eq!(value, compact.deserialize(expected).unwrap());
+}
+
+#[test]
+fn serialize_errors() {
+    struct Unsized;
+
+    impl Serialize for Unsized {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq((0..4u8).filter(|n| n % 2 == 0))
+        }
+    }
+
+    struct Variant(u32);
+
+    impl Serialize for Variant {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_unit_variant("Variant", self.0, "V")
+        }
+    }
+
+    for settings in Settings::all() {
+        assert!(matches!(
+            settings.serialize(&Unsized),
+            Err(Error::LengthRequired)
+        ));
+
+        let result = settings.serialize(&Variant(0x1_0000));
+        match settings.tag_width {
+            TagWidth::U8 | TagWidth::U16 => assert_io_error(result, ErrorKind::InvalidInput),
+            TagWidth::U32 | TagWidth::Uleb128 => assert!(result.is_ok()),
+        }
+
+        let result = settings.serialize(&"x".repeat(256));
+        match settings.length_prefix {
+            LengthPrefix::U8 => assert_io_error(result, ErrorKind::InvalidInput),
+            _ => assert!(result.is_ok()),
+        }
+    }
+}
+
+#[test]
+fn deserialize_errors() {
+    let fixed = Settings {
+        order: ByteOrder::BE,
+        int_encoding: IntEncoding::Fixed,
+        length_prefix: LengthPrefix::U32,
+        tag_width: TagWidth::U32,
+    };
+    let varint = Settings {
+        order: ByteOrder::BE,
+        int_encoding: IntEncoding::Varint,
+        length_prefix: LengthPrefix::U32,
+        tag_width: TagWidth::U32,
+    };
+
+    assert_io_error(fixed.deserialize::<bool>(&[0x02]), ErrorKind::InvalidData);
+    assert_io_error(
+        fixed.deserialize::<char>(&[0x00, 0x00, 0xD8, 0x00]),
+        ErrorKind::InvalidData,
+    );
+    assert_io_error(
+        fixed.deserialize::<String>(&[0x00, 0x00, 0x00, 0x01, 0xFF]),
+        ErrorKind::InvalidData,
+    );
+    assert_io_error(
+        varint.deserialize::<u16>(&[0x80, 0x80, 0x04]),
+        ErrorKind::InvalidData,
+    );
+    assert_io_error(
+        varint.deserialize::<i16>(&[0x80, 0x80, 0x7D]),
+        ErrorKind::InvalidData,
+    );
+    assert_io_error(
+        varint.deserialize::<u32>(&[0x81, 0x00]),
+        ErrorKind::InvalidData,
+    );
+    assert!(matches!(
+        fixed.deserialize::<Enum>(&[0x00, 0x00, 0x00, 0x04]),
+        Err(Error::Custom(_))
+    ));
+    assert!(matches!(
+        fixed.deserialize::<IgnoredAny>(&[0x00]),
+        Err(Error::NotSelfDescribing)
+    ));
+}
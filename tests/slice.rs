@@ -0,0 +1,38 @@
+//! Writes into a fixed-size slice which must either fit completely or leave
+//! the slice untouched.
+
+use byte_order::io::ErrorKind;
+use byte_order::{ByteOrder, LengthPrefix, SliceWriter};
+
+#[test]
+fn encodes_nothing_into_short_slices() {
+    let mut buf = [0xAA; 3];
+    let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    let err = writer.encode(&(1u8, 0x1234_5678u32)).unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+    assert_eq!("buffer full", err.to_string());
+    assert_eq!(0, writer.position());
+    assert_eq!([0xAA; 3], buf);
+
+    let mut buf = [0xAA; 5];
+    let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    writer.write_u8(0xFF).unwrap();
+    let err = writer.encode(&(1u8, 0x1234_5678u32)).unwrap_err();
+    assert_eq!(ErrorKind::WriteZero, err.kind());
+    assert_eq!(1, writer.position());
+    writer.encode(&(1u8, 0x1234u16)).unwrap();
+    assert_eq!(4, writer.position());
+    assert_eq!([0xFF, 0x01, 0x12, 0x34, 0xAA], buf);
+}
+
+#[test]
+fn encodes_nothing_that_cannot_be_represented() {
+    let mut buf = [0xAA; 300];
+    let mut writer = SliceWriter::with_order(ByteOrder::BE, &mut buf);
+    writer.set_length_prefix(LengthPrefix::U8);
+    let long = [0u8; 256];
+    let err = writer.encode(&(1u8, &long[..])).unwrap_err();
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+    assert_eq!(0, writer.position());
+    assert!(buf.iter().all(|&b| b == 0xAA));
+}
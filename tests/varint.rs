@@ -0,0 +1,224 @@
+//! Vectors from the specification of every variable-length integer scheme,
+//! along with their canonical-encoding and maximum-length checks.
+
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::{Cursor, ErrorKind};
+
+use byte_order::varint::{CompactSize, Quic, Sqlite, Uleb128, VarintScheme, Vlq};
+use byte_order::{NumberReader, NumberWriter, SliceReader, SliceWriter, VecWriter};
+
+use common::boundaries_u64;
+
+fn encode<S: VarintScheme>(scheme: S, n: u64) -> Vec<u8> {
+    let mut writer = NumberWriter::new(vec![]);
+    writer.write_varint(scheme, n).unwrap();
+    writer.into_inner()
+}
+
+fn decode<S: VarintScheme>(scheme: S, bytes: &[u8], strict: bool) -> std::io::Result<u64> {
+    let mut reader = NumberReader::new(Cursor::new(bytes));
+    reader.set_strict(strict);
+    let n = reader.read_varint(scheme)?;
+    assert_eq!(
+        bytes.len() as u64,
+        reader.into_inner().position(),
+        "trailing bytes"
+    );
+    Ok(n)
+}
+
+/// Checks that `n` encodes to `bytes` and that `bytes` strictly decodes to `n`.
+fn assert_vector<S: VarintScheme + Copy>(scheme: S, n: u64, bytes: &[u8]) {
+    assert_eq!(bytes, &encode(scheme, n)[..], "{:#x}", n);
+    assert_eq!(n, decode(scheme, bytes, true).unwrap(), "{:#x}", n);
+}
+
+/// Checks that `bytes` decodes to `n` only when the reader is not strict.
+fn assert_non_canonical<S: VarintScheme + Copy>(scheme: S, n: u64, bytes: &[u8]) {
+    assert_eq!(n, decode(scheme, bytes, false).unwrap(), "{:02X?}", bytes);
+    let err = decode(scheme, bytes, true).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind(), "{:02X?}", bytes);
+}
+
+fn assert_invalid<S: VarintScheme + Copy>(scheme: S, bytes: &[u8]) {
+    for &strict in &[false, true] {
+        let err = decode(scheme, bytes, strict).unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind(), "{:02X?}", bytes);
+    }
+}
+
+/// Checks that every scheme round trips every value it can encode, that the
+/// encodings are no longer than it allows, and that every truncation of them
+/// is an unexpected end of file.
+fn assert_round_trips<S: VarintScheme + Copy>(scheme: S) {
+    for n in boundaries_u64() {
+        if n > scheme.max_value() {
+            let mut writer = NumberWriter::new(vec![]);
+            let err = writer.write_varint(scheme, n).unwrap_err();
+            assert_eq!(ErrorKind::InvalidInput, err.kind());
+            assert!(writer.into_inner().is_empty());
+            continue;
+        }
+
+        let bytes = encode(scheme, n);
+        assert!(bytes.len() <= scheme.max_len(), "{:#x}", n);
+        assert_eq!(n, decode(scheme, &bytes, true).unwrap(), "{:#x}", n);
+
+        let mut vec_writer = VecWriter::new();
+        vec_writer.write_varint(scheme, n).unwrap();
+        assert_eq!(bytes, vec_writer.into_inner());
+
+        let mut buf = [0; 16];
+        let mut slice_writer = SliceWriter::new(&mut buf);
+        slice_writer.write_varint(scheme, n).unwrap();
+        assert_eq!(bytes.len(), slice_writer.position());
+
+        let mut reader = SliceReader::new(&buf[..bytes.len()]);
+        assert_eq!(n, reader.read_varint(scheme).unwrap());
+        assert_eq!(0, reader.remaining());
+
+        for len in 0..bytes.len() {
+            let err = decode(scheme, &bytes[..len], true).unwrap_err();
+            assert_eq!(ErrorKind::UnexpectedEof, err.kind(), "{:#x}", n);
+        }
+    }
+}
+
+#[test]
+fn quic() {
+    // The sample encodings of RFC 9000, section A.1.
+    assert_vector(
+        Quic,
+        151_288_809_941_952_652,
+        &[0xC2, 0x19, 0x7C, 0x5E, 0xFF, 0x14, 0xE8, 0x8C],
+    );
+    assert_vector(Quic, 494_878_333, &[0x9D, 0x7F, 0x3E, 0x7D]);
+    assert_vector(Quic, 15293, &[0x7B, 0xBD]);
+    assert_vector(Quic, 37, &[0x25]);
+    assert_non_canonical(Quic, 37, &[0x40, 0x25]);
+
+    assert_vector(Quic, 0x3F, &[0x3F]);
+    assert_vector(Quic, 0x40, &[0x40, 0x40]);
+    assert_vector(Quic, 0x3FFF_FFFF, &[0xBF, 0xFF, 0xFF, 0xFF]);
+    assert_vector(Quic, (1 << 62) - 1, &[0xFF; 8]);
+    assert_non_canonical(Quic, 0x3FFF, &[0x80, 0x00, 0x3F, 0xFF]);
+    assert_non_canonical(
+        Quic,
+        0x3FFF_FFFF,
+        &[0xC0, 0x00, 0x00, 0x00, 0x3F, 0xFF, 0xFF, 0xFF],
+    );
+
+    assert_round_trips(Quic);
+}
+
+#[test]
+fn sqlite() {
+    assert_vector(Sqlite, 0, &[0x00]);
+    assert_vector(Sqlite, 0x7F, &[0x7F]);
+    assert_vector(Sqlite, 0x80, &[0x81, 0x00]);
+    assert_vector(Sqlite, 0x3FFF, &[0xFF, 0x7F]);
+    assert_vector(
+        Sqlite,
+        (1 << 56) - 1,
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F],
+    );
+    assert_vector(
+        Sqlite,
+        1 << 56,
+        &[0x80, 0xC0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00],
+    );
+    assert_vector(Sqlite, u64::MAX, &[0xFF; 9]);
+    assert_non_canonical(Sqlite, 0x7F, &[0x80, 0x7F]);
+    assert_non_canonical(
+        Sqlite,
+        0xFF,
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xFF],
+    );
+
+    assert_round_trips(Sqlite);
+}
+
+#[test]
+fn compact_size() {
+    assert_vector(CompactSize, 0, &[0x00]);
+    assert_vector(CompactSize, 0xFC, &[0xFC]);
+    assert_vector(CompactSize, 0xFD, &[0xFD, 0xFD, 0x00]);
+    assert_vector(CompactSize, 0xFFFF, &[0xFD, 0xFF, 0xFF]);
+    assert_vector(CompactSize, 0x1_0000, &[0xFE, 0x00, 0x00, 0x01, 0x00]);
+    assert_vector(CompactSize, 0xFFFF_FFFF, &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF]);
+    assert_vector(
+        CompactSize,
+        0x1_0000_0000,
+        &[0xFF, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00],
+    );
+    assert_vector(CompactSize, u64::MAX, &[0xFF; 9]);
+    assert_non_canonical(CompactSize, 0xFC, &[0xFD, 0xFC, 0x00]);
+    assert_non_canonical(CompactSize, 0xFFFF, &[0xFE, 0xFF, 0xFF, 0x00, 0x00]);
+    assert_non_canonical(
+        CompactSize,
+        0xFFFF_FFFF,
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00],
+    );
+
+    assert_round_trips(CompactSize);
+}
+
+#[test]
+fn vlq() {
+    // The sample quantities of the Standard MIDI Files specification.
+    let midi: &[(u64, &[u8])] = &[
+        (0x00, &[0x00]),
+        (0x40, &[0x40]),
+        (0x7F, &[0x7F]),
+        (0x80, &[0x81, 0x00]),
+        (0x2000, &[0xC0, 0x00]),
+        (0x3FFF, &[0xFF, 0x7F]),
+        (0x4000, &[0x81, 0x80, 0x00]),
+        (0x10_0000, &[0xC0, 0x80, 0x00]),
+        (0x1F_FFFF, &[0xFF, 0xFF, 0x7F]),
+        (0x20_0000, &[0x81, 0x80, 0x80, 0x00]),
+        (0x800_0000, &[0xC0, 0x80, 0x80, 0x00]),
+        (0xFFF_FFFF, &[0xFF, 0xFF, 0xFF, 0x7F]),
+    ];
+    for &(n, bytes) in midi {
+        assert_vector(Vlq::MIDI, n, bytes);
+        assert_vector(Vlq::new(), n, bytes);
+    }
+    assert_non_canonical(Vlq::MIDI, 0x7F, &[0x80, 0x7F]);
+    assert_invalid(Vlq::MIDI, &[0x81, 0x80, 0x80, 0x80, 0x00]);
+    assert_invalid(Vlq::with_max_len(2), &[0x81, 0x80, 0x00]);
+
+    let mut max = vec![0x81];
+    max.extend(&[0xFF; 8]);
+    max.push(0x7F);
+    assert_vector(Vlq::new(), u64::MAX, &max);
+    max[0] = 0x82;
+    assert_invalid(Vlq::new(), &max);
+    assert_invalid(Vlq::new(), &[0x80; 11]);
+
+    assert_round_trips(Vlq::MIDI);
+    assert_round_trips(Vlq::with_max_len(1));
+    assert_round_trips(Vlq::new());
+}
+
+#[test]
+fn uleb128() {
+    assert_vector(Uleb128, 624_485, &[0xE5, 0x8E, 0x26]);
+    assert_non_canonical(Uleb128, 1, &[0x81, 0x00]);
+
+    for n in boundaries_u64() {
+        let mut writer = NumberWriter::new(vec![]);
+        writer.write_uleb128_u64(n).unwrap();
+        assert_eq!(writer.into_inner(), encode(Uleb128, n), "{:#x}", n);
+    }
+    assert_round_trips(Uleb128);
+}
+
+#[test]
+#[should_panic]
+fn vlq_max_len() {
+    Vlq::with_max_len(11);
+}
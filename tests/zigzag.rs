@@ -0,0 +1,150 @@
+//! Boundary tests of zigzag encoding, both through the standalone functions
+//! and through the zigzag varint methods of the readers and writers.
+
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::convert::TryFrom;
+use std::io::Cursor;
+
+use byte_order::{zigzag, NumberReader, NumberWriter, SliceReader, VecWriter};
+
+use common::boundaries_u64;
+
+/// Signed values at and around every power of two, including both extremes.
+fn boundaries_i64() -> Vec<i64> {
+    let mut values = vec![i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+    for shift in 0..63 {
+        let power = 1i64 << shift;
+        values.extend(&[power - 1, power, power + 1, -power - 1, -power, -power + 1]);
+    }
+    values
+}
+
+fn boundaries_i32() -> Vec<i32> {
+    let mut values = vec![i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX - 1, i32::MAX];
+    for shift in 0..31 {
+        let power = 1i32 << shift;
+        values.extend(&[power - 1, power, power + 1, -power - 1, -power, -power + 1]);
+    }
+    values
+}
+
+/// The zigzag encoding of `n`, computed without wrapping arithmetic.
+fn reference(n: i128) -> u128 {
+    if n >= 0 {
+        2 * n as u128
+    } else {
+        2 * n.unsigned_abs() - 1
+    }
+}
+
+/// The number of bytes in the unsigned LEB128 encoding of `n`.
+fn leb128_len(n: u64) -> usize {
+    let bits = 64 - n.leading_zeros() as usize;
+    bits.max(1).div_ceil(7)
+}
+
+#[test]
+fn const_fns() {
+    const MIN: u64 = zigzag::encode_i64(i64::MIN);
+    const MAX: u64 = zigzag::encode_i64(i64::MAX);
+    const DECODED: i64 = zigzag::decode_i64(u64::MAX);
+
+    assert_eq!(u64::MAX, MIN);
+    assert_eq!(u64::MAX - 1, MAX);
+    assert_eq!(i64::MIN, DECODED);
+    assert_eq!(u32::MAX, zigzag::encode_i32(i32::MIN));
+    assert_eq!(u32::MAX - 1, zigzag::encode_i32(i32::MAX));
+}
+
+#[test]
+fn encode_i64() {
+    for n in boundaries_i64() {
+        let encoded = zigzag::encode_i64(n);
+        assert_eq!(reference(n.into()), encoded.into(), "{}", n);
+        assert_eq!(n, zigzag::decode_i64(encoded), "{}", n);
+    }
+}
+
+#[test]
+fn encode_i32() {
+    for n in boundaries_i32() {
+        let encoded = zigzag::encode_i32(n);
+        assert_eq!(reference(n.into()), encoded.into(), "{}", n);
+        assert_eq!(n, zigzag::decode_i32(encoded), "{}", n);
+        assert_eq!(u64::from(encoded), zigzag::encode_i64(n.into()), "{}", n);
+    }
+}
+
+#[test]
+fn decode_i64() {
+    for n in boundaries_u64() {
+        assert_eq!(n, zigzag::encode_i64(zigzag::decode_i64(n)), "{}", n);
+        if let Ok(n32) = u32::try_from(n) {
+            assert_eq!(n32, zigzag::encode_i32(zigzag::decode_i32(n32)), "{}", n);
+            assert_eq!(
+                i64::from(zigzag::decode_i32(n32)),
+                zigzag::decode_i64(n),
+                "{}",
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn read_write_i64() {
+    for n in boundaries_i64() {
+        let mut writer = NumberWriter::new(vec![]);
+        writer.write_zigzag_i64(n).unwrap();
+        let bytes = writer.into_inner();
+        assert_eq!(leb128_len(zigzag::encode_i64(n)), bytes.len(), "{}", n);
+
+        let mut vec_writer = VecWriter::new();
+        vec_writer.write_zigzag_i64(n);
+        assert_eq!(bytes, vec_writer.into_inner(), "{}", n);
+
+        let mut reader = NumberReader::new(Cursor::new(&bytes));
+        reader.set_strict(true);
+        assert_eq!(n, reader.read_zigzag_i64().unwrap(), "{}", n);
+
+        let mut reader = SliceReader::new(&bytes);
+        assert_eq!(n, reader.read_zigzag_i64().unwrap(), "{}", n);
+        assert_eq!(0, reader.remaining());
+    }
+}
+
+#[test]
+fn read_write_i32() {
+    for n in boundaries_i32() {
+        let mut writer = NumberWriter::new(vec![]);
+        writer.write_zigzag_i32(n).unwrap();
+        let bytes = writer.into_inner();
+        assert_eq!(
+            leb128_len(zigzag::encode_i32(n).into()),
+            bytes.len(),
+            "{}",
+            n
+        );
+
+        let mut reader = NumberReader::new(Cursor::new(&bytes));
+        reader.set_strict(true);
+        assert_eq!(n, reader.read_zigzag_i32().unwrap(), "{}", n);
+
+        let mut reader = NumberReader::new(Cursor::new(&bytes));
+        assert_eq!(i64::from(n), reader.read_zigzag_i64().unwrap(), "{}", n);
+    }
+}
+
+#[test]
+fn read_out_of_range() {
+    // The zigzag encoding of `i32::MAX + 1` does not fit in a `u32`.
+    let mut writer = NumberWriter::new(vec![]);
+    writer.write_zigzag_i64(i64::from(i32::MAX) + 1).unwrap();
+    let bytes = writer.into_inner();
+
+    let mut reader = NumberReader::new(Cursor::new(&bytes));
+    assert!(reader.read_zigzag_i32().is_err());
+}